@@ -162,3 +162,108 @@ const_error! {
     #[status(BAD_REQUEST)]
     const TEAM_FULL;
 }
+const_error! {
+    #[error("M034", "the competition has not started yet")]
+    #[status(FORBIDDEN)]
+    const COMPETITION_NOT_STARTED;
+}
+const_error! {
+    #[error("M035", "the competition has already ended")]
+    #[status(FORBIDDEN)]
+    const COMPETITION_ENDED;
+}
+const_error! {
+    #[error("M036", "no such competition")]
+    #[status(NOT_FOUND)]
+    const COMPETITION_NOT_FOUND;
+}
+const_error! {
+    #[error("M037", "competition name exists")]
+    #[status(BAD_REQUEST)]
+    const DUPLICATE_COMPETITION_NAME;
+}
+const_error! {
+    #[error("M038", "no such team")]
+    #[status(NOT_FOUND)]
+    const TEAM_NOT_FOUND;
+}
+const_error! {
+    #[error("M039", "the competition is already paused")]
+    #[status(BAD_REQUEST)]
+    const COMPETITION_ALREADY_PAUSED;
+}
+const_error! {
+    #[error("M040", "the competition is not paused")]
+    #[status(BAD_REQUEST)]
+    const COMPETITION_NOT_PAUSED;
+}
+const_error! {
+    #[error("M041", "the competition is paused")]
+    #[status(FORBIDDEN)]
+    const COMPETITION_PAUSED;
+}
+const_error! {
+    #[error("M042", "invalid answer for the kind of the problem")]
+    #[status(BAD_REQUEST)]
+    const INVALID_ANSWER;
+}
+const_error! {
+    #[error("M043", "no attempts left for this problem")]
+    #[status(FORBIDDEN)]
+    const NO_ATTEMPTS_LEFT;
+}
+const_error! {
+    #[error("M044", "the problem is not released yet")]
+    #[status(FORBIDDEN)]
+    const PROBLEM_NOT_RELEASED;
+}
+const_error! {
+    #[error("M045", "failed to export the data")]
+    #[status(INTERNAL_SERVER_ERROR)]
+    const FAILED_TO_EXPORT;
+}
+const_error! {
+    #[error("M046", "division not found")]
+    #[status(NOT_FOUND)]
+    const DIVISION_NOT_FOUND;
+}
+const_error! {
+    #[error("M047", "no such invitation")]
+    #[status(NOT_FOUND)]
+    const INVITATION_NOT_FOUND;
+}
+const_error! {
+    #[error("M048", "the invitation has expired")]
+    #[status(FORBIDDEN)]
+    const INVITATION_EXPIRED;
+}
+const_error! {
+    #[error("M049", "no such join request")]
+    #[status(NOT_FOUND)]
+    const JOIN_REQUEST_NOT_FOUND;
+}
+const_error! {
+    #[error("M050", "the join code has expired or it was used too many times")]
+    #[status(FORBIDDEN)]
+    const JOIN_CODE_EXPIRED;
+}
+const_error! {
+    #[error("M051", "failed to render the qr code")]
+    #[status(INTERNAL_SERVER_ERROR)]
+    const FAILED_TO_RENDER_QR_CODE;
+}
+const_error! {
+    #[error("M052", "join links are not configured")]
+    #[status(NOT_FOUND)]
+    const JOIN_LINK_NOT_CONFIGURED;
+}
+const_error! {
+    #[error("M053", "the minimum team size is larger than the maximum")]
+    #[status(BAD_REQUEST)]
+    const INVALID_TEAM_SIZE;
+}
+const_error! {
+    #[error("M054", "the team has too few members")]
+    #[status(FORBIDDEN)]
+    const TEAM_TOO_SMALL;
+}
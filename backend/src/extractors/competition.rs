@@ -0,0 +1,49 @@
+use crate::{
+    StateTrait,
+    error::{self, Error},
+};
+use axum::{
+    extract::{FromRequestParts, Path},
+    http::request::Parts,
+};
+use entity::competitions;
+use sea_orm::EntityTrait;
+use std::{collections::HashMap, ops::Deref};
+use uuid::Uuid;
+
+/// The competition from the `{competition}` path parameter
+pub struct Competition(competitions::Model);
+
+impl<S> FromRequestParts<S> for Competition
+where
+    S: StateTrait,
+{
+    type Rejection = Error<'static>;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        let Ok(Path(params)) =
+            Path::<HashMap<String, String>>::from_request_parts(parts, state).await
+        else {
+            return Err(error::COMPETITION_NOT_FOUND);
+        };
+
+        let Some(Ok(id)) = params.get("competition").map(|id| Uuid::parse_str(id)) else {
+            return Err(error::COMPETITION_NOT_FOUND);
+        };
+
+        let competition = competitions::Entity::find_by_id(id)
+            .one(state.db())
+            .await?
+            .ok_or(error::COMPETITION_NOT_FOUND)?;
+
+        Ok(Competition(competition))
+    }
+}
+
+impl Deref for Competition {
+    type Target = competitions::Model;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
@@ -1,5 +1,7 @@
+mod competition;
 mod json;
 mod user_id;
 
+pub use competition::*;
 pub use json::*;
 pub use user_id::*;
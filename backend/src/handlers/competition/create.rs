@@ -0,0 +1,104 @@
+use super::{validate_divisions, validate_team_size_limits, validate_tie_breakers};
+use crate::{
+    StateTrait,
+    error::{self, DatabaseError, Result},
+    extractors::{Json, ValidatedJson},
+};
+use axum::{extract::State, http::StatusCode, response::IntoResponse};
+use chrono::DateTime;
+use entity::{
+    competitions::{
+        self, Division, DivisionRule, Divisions, TieBreaker, TieBreakers,
+        constants::{DEFAULT_MAX_TEAM_SIZE, DEFAULT_MIN_TEAM_SIZE},
+        constraints::*,
+    },
+    times,
+};
+use sea_orm::{EntityTrait, Set, TransactionTrait};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use validator::Validate;
+
+#[derive(Deserialize, Validate)]
+pub struct Request {
+    #[validate(length(min = 1, max = 64))]
+    name: String,
+    #[validate(custom(function = "validate_tie_breakers"))]
+    tie_breakers: Option<Vec<TieBreaker>>,
+    #[validate(custom(function = "validate_divisions"))]
+    divisions: Option<Vec<Division>>,
+    division_rule: Option<DivisionRule>,
+    #[validate(range(min = 1, max = 100))]
+    min_team_size: Option<i32>,
+    #[validate(range(min = 1, max = 100))]
+    max_team_size: Option<i32>,
+}
+
+#[derive(Serialize)]
+pub struct Response {
+    id: Uuid,
+    name: String,
+    tie_breakers: TieBreakers,
+    divisions: Divisions,
+    division_rule: DivisionRule,
+    min_team_size: i32,
+    max_team_size: i32,
+}
+
+/// Creates a competition, its times are set to the unix epoch until they are set
+pub async fn create_competition<S: StateTrait>(
+    State(state): State<S>,
+    ValidatedJson(request): ValidatedJson<Request>,
+) -> Result<impl IntoResponse> {
+    let txn = state.db().begin().await?;
+
+    let id = Uuid::new_v4();
+    let tie_breakers = request.tie_breakers.map(TieBreakers).unwrap_or_default();
+    let divisions = request.divisions.map(Divisions).unwrap_or_default();
+    let division_rule = request.division_rule.unwrap_or_default();
+    let min_team_size = request.min_team_size.unwrap_or(DEFAULT_MIN_TEAM_SIZE);
+    let max_team_size = request.max_team_size.unwrap_or(DEFAULT_MAX_TEAM_SIZE);
+
+    validate_team_size_limits(min_team_size, max_team_size)?;
+
+    let competition = competitions::ActiveModel {
+        id: Set(id),
+        name: Set(request.name.clone()),
+        tie_breakers: Set(tie_breakers.clone()),
+        divisions: Set(divisions.clone()),
+        division_rule: Set(division_rule),
+        min_team_size: Set(min_team_size),
+        max_team_size: Set(max_team_size),
+    };
+
+    match competitions::Entity::insert(competition).exec(&txn).await {
+        Err(err) if err.unique_violation(UC_COMPETITIONS_NAME) => {
+            return Err(error::DUPLICATE_COMPETITION_NAME);
+        }
+        r => r?,
+    };
+
+    let times =
+        [times::constants::START_TIME, times::constants::END_TIME].map(|name| times::ActiveModel {
+            competition: Set(id),
+            name: Set(name.to_owned()),
+            time: Set(DateTime::UNIX_EPOCH),
+        });
+
+    times::Entity::insert_many(times).exec(&txn).await?;
+
+    txn.commit().await?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(Response {
+            id,
+            name: request.name,
+            tie_breakers,
+            divisions,
+            division_rule,
+            min_team_size,
+            max_team_size,
+        }),
+    ))
+}
@@ -0,0 +1,19 @@
+use crate::{StateTrait, error::Result, extractors::Competition};
+use axum::{extract::State, http::StatusCode};
+use entity::competitions;
+use sea_orm::EntityTrait;
+
+/// Deletes the competition with its times, problems and teams
+pub async fn delete_competition<S: StateTrait>(
+    State(state): State<S>,
+    competition: Competition,
+) -> Result<StatusCode> {
+    competitions::Entity::delete_by_id(competition.id)
+        .exec(state.db())
+        .await?;
+
+    state.problems().remove(&competition.id).await;
+    state.scoreboards().remove(&competition.id);
+
+    Ok(StatusCode::NO_CONTENT)
+}
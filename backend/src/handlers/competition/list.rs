@@ -0,0 +1,41 @@
+use crate::{StateTrait, error::Result, extractors::Json};
+use axum::extract::State;
+use entity::competitions::{self, DivisionRule, Divisions, TieBreakers};
+use sea_orm::{EntityTrait, QueryOrder};
+use serde::Serialize;
+use uuid::Uuid;
+
+#[derive(Serialize)]
+pub struct Competition {
+    id: Uuid,
+    name: String,
+    tie_breakers: TieBreakers,
+    divisions: Divisions,
+    division_rule: DivisionRule,
+    min_team_size: i32,
+    max_team_size: i32,
+}
+
+pub async fn list_competitions<S: StateTrait>(
+    State(state): State<S>,
+) -> Result<Json<Vec<Competition>>> {
+    let competitions = competitions::Entity::find()
+        .order_by_asc(competitions::Column::Name)
+        .all(state.db())
+        .await?;
+
+    Ok(Json(
+        competitions
+            .into_iter()
+            .map(|competition| Competition {
+                id: competition.id,
+                name: competition.name,
+                tie_breakers: competition.tie_breakers,
+                divisions: competition.divisions,
+                division_rule: competition.division_rule,
+                min_team_size: competition.min_team_size,
+                max_team_size: competition.max_team_size,
+            })
+            .collect(),
+    ))
+}
@@ -1,37 +1,135 @@
+mod create;
+mod delete;
+mod list;
+mod pause;
 mod solution;
 mod time;
+mod update;
 
-use crate::{StateTrait, middlewares::PermissionsLayer};
+use crate::{
+    StateTrait,
+    error::{self, Result},
+    middlewares::PermissionsLayer,
+};
 use axum::{
     Router,
     handler::Handler,
-    routing::{get, patch, post, put},
+    routing::{delete, get, patch, post, put},
 };
+use entity::competitions::{Division, TieBreaker};
+use validator::ValidationError;
 
 /// Routes for competition
 ///
-/// POST /competition/solution
+/// GET    /competition
+/// POST   /competition/{competition}/solution
 ///
 /// # Admin actions (users will get time on the socket)
-/// PUT   /competition/time
-/// PATCH /competition/time
-/// GET   /competition/time
+/// POST   /competition
+/// PATCH  /competition/{competition}
+/// DELETE /competition/{competition}
+/// POST   /competition/{competition}/pause
+/// POST   /competition/{competition}/resume
+/// PUT    /competition/{competition}/time
+/// PATCH  /competition/{competition}/time
+/// GET    /competition/{competition}/time
 pub fn routes<S: StateTrait>(state: S) -> Router<S> {
     Router::new()
-        .route("/solution", post(solution::set_solution::<S>))
+        .route("/", get(list::list_competitions::<S>))
+        .route(
+            "/",
+            post(create::create_competition::<S>.layer(PermissionsLayer::new(
+                state.clone(),
+                &["mathcompetition.admin"],
+            ))),
+        )
+        .route(
+            "/{competition}",
+            patch(update::update_competition::<S>.layer(PermissionsLayer::new(
+                state.clone(),
+                &["mathcompetition.admin"],
+            ))),
+        )
+        .route(
+            "/{competition}",
+            delete(delete::delete_competition::<S>.layer(PermissionsLayer::new(
+                state.clone(),
+                &["mathcompetition.admin"],
+            ))),
+        )
+        .route(
+            "/{competition}/pause",
+            post(pause::pause_competition::<S>.layer(PermissionsLayer::new(
+                state.clone(),
+                &["mathcompetition.admin"],
+            ))),
+        )
+        .route(
+            "/{competition}/resume",
+            post(pause::resume_competition::<S>.layer(PermissionsLayer::new(
+                state.clone(),
+                &["mathcompetition.admin"],
+            ))),
+        )
+        .route("/{competition}/solution", post(solution::set_solution::<S>))
         .route(
-            "/time",
+            "/{competition}/time",
             put(time::set_time::<S>.layer(PermissionsLayer::new(
                 state.clone(),
                 &["mathcompetition.admin"],
             ))),
         )
         .route(
-            "/time",
+            "/{competition}/time",
             patch(
                 time::set_time_patch::<S>
                     .layer(PermissionsLayer::new(state, &["mathcompetition.admin"])),
             ),
         )
-        .route("/time", get(time::get_time::<S>))
+        .route("/{competition}/time", get(time::get_time::<S>))
+}
+
+/// Every tie-breaker can be used once
+fn validate_tie_breakers(tie_breakers: &[TieBreaker]) -> std::result::Result<(), ValidationError> {
+    for (index, tie_breaker) in tie_breakers.iter().enumerate() {
+        if tie_breakers[..index].contains(tie_breaker) {
+            return Err(ValidationError::new("duplicate_tie_breaker"));
+        }
+    }
+
+    Ok(())
+}
+
+/// Every division needs a unique name and a class range that no other division overlaps
+fn validate_divisions(divisions: &[Division]) -> std::result::Result<(), ValidationError> {
+    for (index, division) in divisions.iter().enumerate() {
+        if division.name.is_empty() || division.name.chars().count() > 32 {
+            return Err(ValidationError::new("invalid_division_name"));
+        }
+
+        if division.min_class > division.max_class {
+            return Err(ValidationError::new("invalid_division_classes"));
+        }
+
+        for other in &divisions[..index] {
+            if other.name == division.name {
+                return Err(ValidationError::new("duplicate_division"));
+            }
+
+            if other.min_class <= division.max_class && division.min_class <= other.max_class {
+                return Err(ValidationError::new("overlapping_divisions"));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// The limits are checked together, because an update can change only one of them
+fn validate_team_size_limits(min_team_size: i32, max_team_size: i32) -> Result<()> {
+    if min_team_size > max_team_size {
+        return Err(error::INVALID_TEAM_SIZE);
+    }
+
+    Ok(())
 }
@@ -0,0 +1,195 @@
+use crate::{
+    StateTrait,
+    error::{self, Result},
+    extractors::Competition,
+    handlers::socket::Event,
+    utils::{get_times, topics},
+};
+use axum::{extract::State, http::StatusCode};
+use chrono::{DateTime, TimeDelta, Utc};
+use entity::{competitions, pauses, team_times, teams, times};
+use sea_orm::{
+    ColumnTrait, ConnectionTrait, DatabaseTransaction, EntityTrait, IntoActiveModel, JoinType,
+    QueryFilter, QuerySelect, RelationTrait, Set, Statement, TransactionTrait,
+};
+use uuid::Uuid;
+
+/// Starts a pause, solutions are rejected until the competition is resumed.
+///
+/// The competition can only be paused while it is running for at least one team, the time
+/// overrides of the teams are taken into account the same way as in `get_team_times`.
+pub async fn pause_competition<S: StateTrait>(
+    State(state): State<S>,
+    competition: Competition,
+) -> Result<StatusCode> {
+    let txn = lock_competition(&state, competition.id).await?;
+
+    if pauses::Entity::find_ongoing(competition.id)
+        .one(&txn)
+        .await?
+        .is_some()
+    {
+        return Err(error::COMPETITION_ALREADY_PAUSED);
+    }
+
+    let now = Utc::now();
+    check_running(&txn, competition.id, now).await?;
+
+    pauses::Entity::insert(pauses::ActiveModel {
+        id: Set(Uuid::new_v4()),
+        competition: Set(competition.id),
+        start_time: Set(now),
+        end_time: Set(None),
+    })
+    .exec(&txn)
+    .await?;
+
+    state
+        .nats()
+        .publish(
+            topics::times(&competition.id),
+            serde_json::to_vec(&Event::PauseCompetition { since: now })
+                .unwrap()
+                .into(),
+        )
+        .await?;
+
+    txn.commit().await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Returns an error if the competition is not running for any of the teams at `now`
+async fn check_running(
+    txn: &DatabaseTransaction,
+    competition_id: Uuid,
+    now: DateTime<Utc>,
+) -> Result<()> {
+    let (start_time, end_time) = get_times(txn, competition_id).await?;
+
+    let team_times = team_times::Entity::find()
+        .join(JoinType::InnerJoin, team_times::Relation::Team.def())
+        .filter(teams::Column::Competition.eq(competition_id))
+        .all(txn)
+        .await?;
+
+    let times = team_times
+        .into_iter()
+        .map(|team_time| {
+            (
+                team_time.start_time.unwrap_or(start_time),
+                team_time.end_time.unwrap_or(end_time)
+                    + TimeDelta::minutes(team_time.extra_minutes.into()),
+            )
+        })
+        .chain([(start_time, end_time)])
+        .collect::<Vec<_>>();
+
+    if times
+        .iter()
+        .any(|(start, end)| *start <= now && now <= *end)
+    {
+        return Ok(());
+    }
+
+    if times.iter().any(|(start, _)| now < *start) {
+        return Err(error::COMPETITION_NOT_STARTED);
+    }
+
+    Err(error::COMPETITION_ENDED)
+}
+
+/// Ends the pause and moves the end times forward by the length of the pause.
+///
+/// The releases of the problems are moved as well, because they are measured in running time.
+pub async fn resume_competition<S: StateTrait>(
+    State(state): State<S>,
+    competition: Competition,
+) -> Result<StatusCode> {
+    let txn = lock_competition(&state, competition.id).await?;
+
+    let pause = pauses::Entity::find_ongoing(competition.id)
+        .one(&txn)
+        .await?
+        .ok_or(error::COMPETITION_NOT_PAUSED)?;
+
+    let now = Utc::now();
+    let paused_since = pause.start_time;
+    let paused_for = now - paused_since;
+
+    let mut pause = pause.into_active_model();
+    pause.end_time = Set(Some(now));
+    pauses::Entity::update(pause).exec(&txn).await?;
+
+    let (_, end_time) = get_times(&txn, competition.id).await?;
+    let end_time = end_time + paused_for;
+
+    times::Entity::update(times::ActiveModel {
+        competition: Set(competition.id),
+        name: Set(times::constants::END_TIME.to_owned()),
+        time: Set(end_time),
+    })
+    .exec(&txn)
+    .await?;
+
+    // Teams with their own end time are moved forward as well
+    txn.execute(Statement::from_sql_and_values(
+        txn.get_database_backend(),
+        "
+        update 
+          team_times 
+        set 
+          end_time = team_times.end_time + ($1 - $2) 
+        from 
+          teams 
+        where 
+          teams.id = team_times.team 
+          and teams.competition = $3 
+          and team_times.end_time is not null
+        ",
+        [now.into(), paused_since.into(), competition.id.into()],
+    ))
+    .await?;
+
+    let nats = state.nats();
+
+    nats.publish(
+        topics::times(&competition.id),
+        serde_json::to_vec(&Event::ResumeCompetition)
+            .unwrap()
+            .into(),
+    )
+    .await?;
+
+    nats.publish(
+        topics::times(&competition.id),
+        serde_json::to_vec(&Event::UpdateTime {
+            start_time: None,
+            end_time: Some(end_time),
+        })
+        .unwrap()
+        .into(),
+    )
+    .await?;
+
+    txn.commit().await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Starts a transaction with the competition locked, so pauses are not started or ended
+/// concurrently
+async fn lock_competition<S: StateTrait>(
+    state: &S,
+    competition_id: Uuid,
+) -> Result<DatabaseTransaction> {
+    let txn = state.db().begin().await?;
+
+    competitions::Entity::find_by_id(competition_id)
+        .lock_exclusive()
+        .one(&txn)
+        .await?
+        .ok_or(error::COMPETITION_NOT_FOUND)?;
+
+    Ok(txn)
+}
@@ -1,41 +1,129 @@
 use crate::{
     StateTrait,
     error::{self, Result},
-    extractors::{Json, UserID},
+    extractors::{Competition, Json, UserID},
     handlers::socket::Event,
-    utils::topics,
+    utils::{
+        RawAnswer, get_paused_duration, get_team_times, normalize_answer, release_time, topics,
+    },
 };
 use axum::{extract::State, http::StatusCode};
-use entity::{solutions_history, teams};
-use sea_orm::{ActiveValue::Set, EntityTrait, QuerySelect, TransactionTrait};
+use chrono::Utc;
+use entity::{pauses, problems, solutions_history, teams};
+use sea_orm::{
+    ActiveValue::Set, ColumnTrait, EntityTrait, PaginatorTrait, QueryFilter, QuerySelect,
+    TransactionTrait,
+};
 use serde::Deserialize;
 use uuid::Uuid;
 
 #[derive(Debug, Deserialize)]
 pub struct Request {
     problem: Uuid,
-    solution: Option<i64>,
+    solution: Option<RawAnswer>,
 }
 
 pub async fn set_solution<S: StateTrait>(
     State(state): State<S>,
+    competition: Competition,
     user_id: UserID,
     Json(request): Json<Request>,
 ) -> Result<StatusCode> {
     let txn = state.db().begin().await?;
 
-    let team = teams::Entity::find_from_member(&user_id)
+    let team = teams::Entity::find_from_member(&competition.id, &user_id)
         .lock_exclusive()
         .one(&txn)
         .await?
         .ok_or(error::USER_NOT_IN_TEAM)?;
 
+    let (start_time, end_time) = get_team_times(&txn, competition.id, team.id).await?;
+    let now = Utc::now();
+
+    if now < start_time {
+        warn!(
+            "rejected solution before start_time: team: {}, problem: {}, solution: {:?}",
+            team.id, request.problem, request.solution
+        );
+        return Err(error::COMPETITION_NOT_STARTED);
+    }
+
+    if now > end_time + state.solution_grace_period() {
+        warn!(
+            "rejected solution after end_time: team: {}, problem: {}, solution: {:?}",
+            team.id, request.problem, request.solution
+        );
+        return Err(error::COMPETITION_ENDED);
+    }
+
+    if pauses::Entity::find_ongoing(competition.id)
+        .one(&txn)
+        .await?
+        .is_some()
+    {
+        warn!(
+            "rejected solution while paused: team: {}, problem: {}, solution: {:?}",
+            team.id, request.problem, request.solution
+        );
+        return Err(error::COMPETITION_PAUSED);
+    }
+
+    let problem = problems::Entity::find_by_id(request.problem)
+        .filter(problems::Column::Competition.eq(competition.id))
+        .one(&txn)
+        .await?
+        .ok_or(error::PROBLEM_NOT_FOUND)?;
+
+    // The pauses after the start move the release later
+    let paused_for = get_paused_duration(&txn, competition.id, start_time, now).await?;
+
+    if now < release_time(start_time + paused_for, problem.release_minutes) {
+        warn!(
+            "rejected solution before release: team: {}, problem: {}, solution: {:?}",
+            team.id, request.problem, request.solution
+        );
+        return Err(error::PROBLEM_NOT_RELEASED);
+    }
+
+    let solution = request
+        .solution
+        .as_ref()
+        .map(|solution| normalize_answer(problem.kind, solution))
+        .transpose()?;
+
+    // The team is locked, so the number of attempts can't change concurrently.
+    // The answers set by admins are not attempts of the team.
+    let remaining_attempts = match problem.max_attempts {
+        Some(max_attempts) => {
+            let attempts = solutions_history::Entity::find()
+                .filter(solutions_history::Column::Team.eq(team.id))
+                .filter(solutions_history::Column::Problem.eq(problem.id))
+                .filter(solutions_history::Column::Admin.is_null())
+                .count(&txn)
+                .await?;
+
+            let remaining = i64::from(max_attempts) - attempts as i64;
+
+            if remaining <= 0 {
+                warn!(
+                    "rejected solution without attempts left: team: {}, problem: {}, solution: {:?}",
+                    team.id, request.problem, request.solution
+                );
+                return Err(error::NO_ATTEMPTS_LEFT);
+            }
+
+            Some(remaining - 1)
+        }
+        None => None,
+    };
+
     let solution_history = solutions_history::ActiveModel {
         id: Set(Uuid::new_v4()),
         team: Set(team.id),
         problem: Set(request.problem),
-        user: Set(*user_id),
-        solution: Set(request.solution),
+        user: Set(Some(*user_id)),
+        admin: Set(None),
+        solution: Set(solution.clone()),
         created_at: Default::default(),
     };
 
@@ -46,16 +134,31 @@ pub async fn set_solution<S: StateTrait>(
     state
         .nats()
         .publish(
-            topics::team_solutions(&team.id),
+            topics::team_solutions(&competition.id, &team.id),
             serde_json::to_vec(&Event::SolutionSet {
                 problem: request.problem,
-                solution: request.solution,
+                solution,
             })
             .unwrap()
             .into(),
         )
         .await?;
 
+    if let Some(remaining) = remaining_attempts {
+        state
+            .nats()
+            .publish(
+                topics::team_solutions(&competition.id, &team.id),
+                serde_json::to_vec(&Event::RemainingAttempts {
+                    problem: request.problem,
+                    remaining,
+                })
+                .unwrap()
+                .into(),
+            )
+            .await?;
+    }
+
     txn.commit().await?;
 
     Ok(StatusCode::NO_CONTENT)
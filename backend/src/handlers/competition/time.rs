@@ -1,14 +1,14 @@
 use crate::{
     StateTrait,
     error::{self, Result},
-    extractors::Json,
+    extractors::{Competition, Json},
     handlers::socket::Event,
-    utils::topics,
+    utils::{get_times, topics},
 };
 use axum::{extract::State, http::StatusCode};
 use chrono::DateTime;
 use entity::times;
-use sea_orm::{ColumnTrait, Condition, EntityTrait, QueryFilter, Set, TransactionTrait};
+use sea_orm::{EntityTrait, Set, TransactionTrait};
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Deserialize)]
@@ -19,6 +19,7 @@ pub struct Request {
 
 pub async fn set_time_patch<S: StateTrait>(
     State(state): State<S>,
+    competition: Competition,
     Json(req): Json<Request>,
 ) -> Result<StatusCode> {
     let txn = state.db().begin().await?;
@@ -30,6 +31,7 @@ pub async fn set_time_patch<S: StateTrait>(
         };
 
         let model = times::ActiveModel {
+            competition: Set(competition.id),
             name: Set(times::constants::START_TIME.to_owned()),
             time: Set(time),
         };
@@ -48,6 +50,7 @@ pub async fn set_time_patch<S: StateTrait>(
         };
 
         let model = times::ActiveModel {
+            competition: Set(competition.id),
             name: Set(times::constants::END_TIME.to_owned()),
             time: Set(time),
         };
@@ -62,7 +65,7 @@ pub async fn set_time_patch<S: StateTrait>(
     state
         .nats()
         .publish(
-            topics::times(),
+            topics::times(&competition.id),
             serde_json::to_vec(&Event::UpdateTime {
                 start_time,
                 end_time,
@@ -85,10 +88,12 @@ pub struct PutRequest {
 
 pub async fn set_time<S: StateTrait>(
     state: State<S>,
+    competition: Competition,
     Json(req): Json<PutRequest>,
 ) -> Result<StatusCode> {
     set_time_patch(
         state,
+        competition,
         Json(Request {
             start_time: Some(req.start_time),
             end_time: Some(req.end_time),
@@ -103,26 +108,14 @@ pub struct GetResponse {
     end_time: i64,
 }
 
-pub async fn get_time<S: StateTrait>(State(state): State<S>) -> Result<Json<GetResponse>> {
-    let res = times::Entity::find()
-        .filter(
-            Condition::any()
-                .add(times::Column::Name.eq("start_time"))
-                .add(times::Column::Name.eq("end_time")),
-        )
-        .all(state.db())
-        .await?;
-
-    if res.len() != 2 {
-        error!("start_time or end_time is not found in the database");
-        return Err(error::INTERNAL);
-    }
-
-    let start_time = res.iter().find(|i| i.name == "start_time").unwrap();
-    let end_time = res.iter().find(|i| i.name == "end_time").unwrap();
+pub async fn get_time<S: StateTrait>(
+    State(state): State<S>,
+    competition: Competition,
+) -> Result<Json<GetResponse>> {
+    let (start_time, end_time) = get_times(state.db(), competition.id).await?;
 
     Ok(Json(GetResponse {
-        start_time: start_time.time.timestamp(),
-        end_time: end_time.time.timestamp(),
+        start_time: start_time.timestamp(),
+        end_time: end_time.timestamp(),
     }))
 }
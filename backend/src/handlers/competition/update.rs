@@ -0,0 +1,71 @@
+use super::{validate_divisions, validate_team_size_limits, validate_tie_breakers};
+use crate::{
+    StateTrait,
+    error::{self, DatabaseError, Result},
+    extractors::{Competition, ValidatedJson},
+    utils::set_option,
+};
+use axum::{extract::State, http::StatusCode};
+use entity::competitions::{
+    self, Division, DivisionRule, Divisions, TieBreaker, TieBreakers, constraints::*,
+};
+use sea_orm::{EntityTrait, Set};
+use serde::Deserialize;
+use validator::Validate;
+
+#[derive(Deserialize, Validate)]
+pub struct Request {
+    #[validate(length(min = 1, max = 64))]
+    name: Option<String>,
+    #[validate(custom(function = "validate_tie_breakers"))]
+    tie_breakers: Option<Vec<TieBreaker>>,
+    #[validate(custom(function = "validate_divisions"))]
+    divisions: Option<Vec<Division>>,
+    division_rule: Option<DivisionRule>,
+    #[validate(range(min = 1, max = 100))]
+    min_team_size: Option<i32>,
+    #[validate(range(min = 1, max = 100))]
+    max_team_size: Option<i32>,
+}
+
+pub async fn update_competition<S: StateTrait>(
+    State(state): State<S>,
+    competition: Competition,
+    ValidatedJson(request): ValidatedJson<Request>,
+) -> Result<StatusCode> {
+    // This is necessary because the ORM would generate a wrong sql statement
+    if request.name.is_none()
+        && request.tie_breakers.is_none()
+        && request.divisions.is_none()
+        && request.division_rule.is_none()
+        && request.min_team_size.is_none()
+        && request.max_team_size.is_none()
+    {
+        return Ok(StatusCode::NO_CONTENT);
+    }
+
+    // Teams that break the new limits are not changed, they are listed in the team size report
+    validate_team_size_limits(
+        request.min_team_size.unwrap_or(competition.min_team_size),
+        request.max_team_size.unwrap_or(competition.max_team_size),
+    )?;
+
+    let model = competitions::ActiveModel {
+        id: Set(competition.id),
+        name: set_option(request.name),
+        tie_breakers: set_option(request.tie_breakers.map(TieBreakers)),
+        divisions: set_option(request.divisions.map(Divisions)),
+        division_rule: set_option(request.division_rule),
+        min_team_size: set_option(request.min_team_size),
+        max_team_size: set_option(request.max_team_size),
+    };
+
+    match competitions::Entity::update(model).exec(state.db()).await {
+        Err(err) if err.unique_violation(UC_COMPETITIONS_NAME) => {
+            return Err(error::DUPLICATE_COMPETITION_NAME);
+        }
+        r => r?,
+    };
+
+    Ok(StatusCode::NO_CONTENT)
+}
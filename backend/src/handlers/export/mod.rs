@@ -0,0 +1,55 @@
+mod results;
+mod solutions;
+mod teams;
+mod users;
+
+use crate::{StateTrait, middlewares::PermissionsLayer, utils::ExportFormat};
+use axum::{Router, handler::Handler, routing::get};
+use serde::Deserialize;
+
+/// Routes for exporting data as spreadsheets, nested under `/competition/{competition}`.
+/// The file format is selected with the `format` query parameter, `csv` or `xlsx`.
+/// The results can be limited to one division with the `division` query parameter.
+///
+/// # Admin actions
+/// GET /export/results
+/// GET /export/solutions
+/// GET /export/teams
+/// GET /export/users
+pub fn routes<S: StateTrait>(state: S) -> Router<S> {
+    Router::new()
+        .route(
+            "/results",
+            get(results::export_results::<S>.layer(PermissionsLayer::new(
+                state.clone(),
+                &["mathcompetition.admin"],
+            ))),
+        )
+        .route(
+            "/solutions",
+            get(
+                solutions::export_solutions::<S>.layer(PermissionsLayer::new(
+                    state.clone(),
+                    &["mathcompetition.admin"],
+                )),
+            ),
+        )
+        .route(
+            "/teams",
+            get(teams::export_teams::<S>.layer(PermissionsLayer::new(
+                state.clone(),
+                &["mathcompetition.admin"],
+            ))),
+        )
+        .route(
+            "/users",
+            get(users::export_users::<S>
+                .layer(PermissionsLayer::new(state, &["mathcompetition.admin"]))),
+        )
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ExportQuery {
+    #[serde(default)]
+    format: ExportFormat,
+}
@@ -0,0 +1,77 @@
+use crate::{
+    StateTrait,
+    error::Result,
+    extractors::Competition,
+    handlers::stats::scoreboard,
+    utils::{ExportFormat, Table, get_user_name},
+};
+use axum::{
+    extract::{Query, State},
+    response::Response,
+};
+use chrono::Utc;
+use entity::users;
+use sea_orm::QueryOrder;
+use serde::Deserialize;
+
+const HEADERS: &[&str] = &[
+    "rank",
+    "team_id",
+    "team",
+    "points",
+    "solved",
+    "division",
+    "member_id",
+    "member",
+    "school",
+    "class",
+];
+
+#[derive(Debug, Deserialize)]
+pub struct ResultsQuery {
+    #[serde(default)]
+    format: ExportFormat,
+    /// Only rank the teams in this division
+    division: Option<String>,
+}
+
+/// Exports the current scoreboard, every member of a team is in a separate row
+pub async fn export_results<S: StateTrait>(
+    State(state): State<S>,
+    competition: Competition,
+    Query(query): Query<ResultsQuery>,
+) -> Result<Response> {
+    let scoreboard = scoreboard::calculate(
+        state.db(),
+        competition.id,
+        Utc::now(),
+        query.division.as_deref(),
+    )
+    .await?;
+
+    let mut table = Table::new("results", HEADERS);
+
+    for entry in scoreboard {
+        let members = users::Entity::find_in_team(&entry.team_id)
+            .order_by_asc(users::Column::Id)
+            .all(state.db())
+            .await?;
+
+        for member in members {
+            table.push(vec![
+                entry.rank.into(),
+                entry.team_id.into(),
+                entry.name.as_str().into(),
+                entry.points.into(),
+                entry.solved.into(),
+                entry.division.as_deref().into(),
+                member.id.into(),
+                get_user_name(&state, member.id).await?.into(),
+                member.school.into(),
+                (member.class as i16).into(),
+            ]);
+        }
+    }
+
+    table.into_response(query.format)
+}
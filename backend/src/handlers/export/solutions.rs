@@ -0,0 +1,85 @@
+use super::ExportQuery;
+use crate::{StateTrait, error::Result, extractors::Competition, utils::Table};
+use axum::{
+    extract::{Query, State},
+    response::Response,
+};
+use chrono::{DateTime, Utc};
+use sea_orm::{ConnectionTrait, FromQueryResult, Statement};
+use uuid::Uuid;
+
+const HEADERS: &[&str] = &[
+    "id",
+    "created_at",
+    "team_id",
+    "team",
+    "problem_id",
+    "user_id",
+    "admin_id",
+    "solution",
+];
+
+#[derive(FromQueryResult)]
+struct Row {
+    id: Uuid,
+    created_at: DateTime<Utc>,
+    team_id: Uuid,
+    team_name: String,
+    problem: Uuid,
+    user: Option<Uuid>,
+    admin: Option<Uuid>,
+    solution: Option<String>,
+}
+
+/// Exports every answer of the ranked teams, in the order they were submitted
+pub async fn export_solutions<S: StateTrait>(
+    State(state): State<S>,
+    competition: Competition,
+    Query(query): Query<ExportQuery>,
+) -> Result<Response> {
+    const SQL: &str = r#"
+        select 
+          solutions_history.id, 
+          solutions_history.created_at::timestamptz as created_at, 
+          teams.id as team_id, 
+          teams.name as team_name, 
+          solutions_history.problem, 
+          solutions_history."user", 
+          solutions_history.admin, 
+          solutions_history.solution 
+        from 
+          solutions_history 
+          inner join teams on teams.id = solutions_history.team 
+        where 
+          teams.competition = $1 
+          and teams.exclusion is null 
+        order by 
+          solutions_history.created_at, 
+          solutions_history.id;
+        "#;
+
+    let rows = Row::find_by_statement(Statement::from_sql_and_values(
+        state.db().get_database_backend(),
+        SQL,
+        [competition.id.into()],
+    ))
+    .all(state.db())
+    .await?;
+
+    let mut table = Table::new("solutions", HEADERS);
+
+    for row in rows {
+        table.push(vec![
+            row.id.into(),
+            row.created_at.into(),
+            row.team_id.into(),
+            row.team_name.into(),
+            row.problem.into(),
+            row.user.into(),
+            row.admin.into(),
+            row.solution.into(),
+        ]);
+    }
+
+    table.into_response(query.format)
+}
@@ -0,0 +1,63 @@
+use super::ExportQuery;
+use crate::{StateTrait, error::Result, extractors::Competition, utils::Table};
+use axum::{
+    extract::{Query, State},
+    response::Response,
+};
+use entity::{
+    team_members,
+    teams::{self, Exclusion},
+};
+use sea_orm::{ColumnTrait, EntityTrait, PaginatorTrait, QueryFilter, QueryOrder};
+
+const HEADERS: &[&str] = &[
+    "team_id",
+    "name",
+    "owner",
+    "co_owner",
+    "locked",
+    "join_code",
+    "members",
+    "exclusion",
+    "exclusion_reason",
+];
+
+/// Exports every team registered to the competition, excluded teams too
+pub async fn export_teams<S: StateTrait>(
+    State(state): State<S>,
+    competition: Competition,
+    Query(query): Query<ExportQuery>,
+) -> Result<Response> {
+    let teams = teams::Entity::find_in_competition(&competition.id)
+        .order_by_asc(teams::Column::Name)
+        .all(state.db())
+        .await?;
+
+    let mut table = Table::new("teams", HEADERS);
+
+    for team in teams {
+        let members = team_members::Entity::find()
+            .filter(team_members::Column::TeamId.eq(team.id))
+            .count(state.db())
+            .await?;
+
+        table.push(vec![
+            team.id.into(),
+            team.name.into(),
+            team.owner.into(),
+            team.co_owner.into(),
+            team.locked.into(),
+            team.join_code.into(),
+            members.into(),
+            team.exclusion
+                .map(|exclusion| match exclusion {
+                    Exclusion::Disqualified => "DISQUALIFIED",
+                    Exclusion::HorsConcours => "HORS_CONCOURS",
+                })
+                .into(),
+            team.exclusion_reason.into(),
+        ]);
+    }
+
+    table.into_response(query.format)
+}
@@ -0,0 +1,72 @@
+use super::ExportQuery;
+use crate::{
+    StateTrait,
+    error::Result,
+    extractors::Competition,
+    utils::{Table, get_user_name},
+};
+use axum::{
+    extract::{Query, State},
+    response::Response,
+};
+use sea_orm::{ConnectionTrait, FromQueryResult, Statement};
+use uuid::Uuid;
+
+const HEADERS: &[&str] = &["user_id", "name", "school", "class", "team_id", "team"];
+
+#[derive(FromQueryResult)]
+struct Row {
+    id: Uuid,
+    school: String,
+    class: i16,
+    team_id: Option<Uuid>,
+    team_name: Option<String>,
+}
+
+/// Exports every registered user, with their team in the competition if they have one
+pub async fn export_users<S: StateTrait>(
+    State(state): State<S>,
+    competition: Competition,
+    Query(query): Query<ExportQuery>,
+) -> Result<Response> {
+    const SQL: &str = "
+        select 
+          users.id, 
+          users.school, 
+          users.class, 
+          teams.id as team_id, 
+          teams.name as team_name 
+        from 
+          users 
+          left join team_members on team_members.user_id = users.id 
+          and team_members.competition = $1 
+          left join teams on teams.id = team_members.team_id 
+        order by 
+          users.school, 
+          users.class, 
+          users.id;
+        ";
+
+    let rows = Row::find_by_statement(Statement::from_sql_and_values(
+        state.db().get_database_backend(),
+        SQL,
+        [competition.id.into()],
+    ))
+    .all(state.db())
+    .await?;
+
+    let mut table = Table::new("users", HEADERS);
+
+    for row in rows {
+        table.push(vec![
+            row.id.into(),
+            get_user_name(&state, row.id).await?.into(),
+            row.school.into(),
+            row.class.into(),
+            row.team_id.into(),
+            row.team_name.into(),
+        ]);
+    }
+
+    table.into_response(query.format)
+}
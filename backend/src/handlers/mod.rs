@@ -1,15 +1,16 @@
 mod competition;
+mod export;
 mod problem;
 mod register;
+mod scoreboard;
 pub mod socket;
-mod stats;
+pub(crate) mod stats;
 mod team;
 
-use crate::{middlewares::PermissionsLayer, state::StateTrait};
+use crate::state::StateTrait;
 use axum::{
     Router,
     extract::State,
-    handler::Handler,
     http::StatusCode,
     routing::{get, post},
 };
@@ -21,16 +22,16 @@ pub fn routes<S: StateTrait>(state: S) -> Router<S> {
             "/v1",
             Router::new()
                 .route("/register", post(register::register::<S>))
-                .nest("/team", team::routes::<S>(state.clone()))
-                .nest("/problem", problem::routes::<S>(state.clone()))
                 .nest("/competition", competition::routes::<S>(state.clone()))
-                .route("/ws", get(socket::ws_handler::<S>))
-                .route(
-                    "/stats",
-                    post(
-                        stats::get_stats::<S>
-                            .layer(PermissionsLayer::new(state, &["mathcompetition.admin"])),
-                    ),
+                .nest(
+                    "/competition/{competition}",
+                    Router::new()
+                        .nest("/team", team::routes::<S>(state.clone()))
+                        .nest("/problem", problem::routes::<S>(state.clone()))
+                        .nest("/export", export::routes::<S>(state.clone()))
+                        .route("/ws", get(socket::ws_handler::<S>))
+                        .route("/scoreboard", get(scoreboard::ws_handler::<S>))
+                        .nest("/stats", stats::routes::<S>(state)),
                 ),
         )
         .route("/livez", get(liveness::<S>))
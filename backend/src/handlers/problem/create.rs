@@ -1,45 +1,181 @@
-use crate::{StateTrait, error::Result, extractors::Json};
+use crate::{
+    StateTrait,
+    error::{self, Result},
+    extractors::{Competition, Json, ValidatedJson},
+    utils::{RawAnswer, normalize_answer},
+};
 use axum::{extract::State, http::StatusCode, response::IntoResponse};
-use entity::problems;
-use sea_orm::{EntityTrait, Set};
+use entity::{
+    accepted_solutions,
+    problems::{self, AnswerKind, constants::*},
+};
+use sea_orm::{ColumnTrait, ConnectionTrait, EntityTrait, QueryFilter, Set, TransactionTrait};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
+use validator::Validate;
 
-#[derive(Deserialize)]
+#[derive(Deserialize, Validate)]
 pub struct Request {
     body: String,
-    solution: i64,
+    #[serde(default)]
+    kind: AnswerKind,
+    solution: RawAnswer,
+    /// Answers that are correct besides `solution`
+    #[serde(default)]
+    #[validate(length(max = 32))]
+    accepted_solutions: Vec<RawAnswer>,
+    #[validate(range(min = 0.0))]
+    tolerance: Option<f64>,
     image: Option<String>,
+    #[serde(default = "default_points")]
+    #[validate(range(min = 0))]
+    points: i32,
+    #[serde(default = "default_wrong_points")]
+    #[validate(range(max = 0))]
+    wrong_points: i32,
+    #[validate(range(min = 1))]
+    max_attempts: Option<i32>,
+    #[serde(default = "default_attempt_penalty")]
+    #[validate(range(max = 0))]
+    attempt_penalty: i32,
+    #[serde(default)]
+    #[validate(range(min = 0))]
+    release_minutes: i32,
 }
 
 #[derive(Serialize)]
 pub struct Response {
     id: Uuid,
     body: String,
-    solution: i64,
+    kind: AnswerKind,
+    solution: String,
+    accepted_solutions: Vec<String>,
+    tolerance: Option<f64>,
     image: Option<String>,
+    points: i32,
+    wrong_points: i32,
+    max_attempts: Option<i32>,
+    attempt_penalty: i32,
+    release_minutes: i32,
+}
+
+pub(super) const fn default_points() -> i32 {
+    DEFAULT_POINTS
+}
+
+pub(super) const fn default_wrong_points() -> i32 {
+    DEFAULT_WRONG_POINTS
+}
+
+pub(super) const fn default_attempt_penalty() -> i32 {
+    DEFAULT_ATTEMPT_PENALTY
+}
+
+/// Normalizes the solution of a problem, the tolerance is only allowed for decimal answers
+pub(super) fn normalize_solution(
+    kind: AnswerKind,
+    solution: &RawAnswer,
+    tolerance: Option<f64>,
+) -> Result<String> {
+    if tolerance.is_some() && kind != AnswerKind::Decimal {
+        return Err(error::JSON_VALIDATE_INVALID);
+    }
+
+    normalize_answer(kind, solution)
+}
+
+/// Normalizes the accepted answers of a problem, duplicates and the main solution are removed
+pub(super) fn normalize_accepted_solutions(
+    kind: AnswerKind,
+    solution: &str,
+    accepted_solutions: &[RawAnswer],
+) -> Result<Vec<String>> {
+    let mut normalized = accepted_solutions
+        .iter()
+        .map(|answer| normalize_answer(kind, answer))
+        .collect::<Result<Vec<_>>>()?;
+
+    normalized.retain(|answer| answer != solution);
+    normalized.sort_unstable();
+    normalized.dedup();
+
+    Ok(normalized)
+}
+
+/// Replaces the accepted answers of a problem
+pub(super) async fn set_accepted_solutions(
+    db: &impl ConnectionTrait,
+    problem_id: Uuid,
+    accepted_solutions: &[String],
+) -> Result<()> {
+    accepted_solutions::Entity::delete_many()
+        .filter(accepted_solutions::Column::Problem.eq(problem_id))
+        .exec(db)
+        .await?;
+
+    if accepted_solutions.is_empty() {
+        return Ok(());
+    }
+
+    accepted_solutions::Entity::insert_many(accepted_solutions.iter().map(|solution| {
+        accepted_solutions::ActiveModel {
+            problem: Set(problem_id),
+            solution: Set(solution.clone()),
+        }
+    }))
+    .exec(db)
+    .await?;
+
+    Ok(())
 }
 
 pub async fn create_problem<S: StateTrait>(
     State(state): State<S>,
-    Json(request): Json<Request>,
+    competition: Competition,
+    ValidatedJson(request): ValidatedJson<Request>,
 ) -> Result<impl IntoResponse> {
+    let solution = normalize_solution(request.kind, &request.solution, request.tolerance)?;
+    let accepted_solutions =
+        normalize_accepted_solutions(request.kind, &solution, &request.accepted_solutions)?;
+
     let problem = problems::ActiveModel {
         id: Set(Uuid::new_v4()),
+        competition: Set(competition.id),
         body: Set(request.body.clone()),
-        solution: Set(request.solution),
+        kind: Set(request.kind),
+        solution: Set(solution.clone()),
+        tolerance: Set(request.tolerance),
         image: Set(request.image.clone()),
+        points: Set(request.points),
+        wrong_points: Set(request.wrong_points),
+        max_attempts: Set(request.max_attempts),
+        attempt_penalty: Set(request.attempt_penalty),
+        release_minutes: Set(request.release_minutes),
     };
 
-    let res = problems::Entity::insert(problem).exec(state.db()).await?;
+    let txn = state.db().begin().await?;
+
+    let res = problems::Entity::insert(problem).exec(&txn).await?;
+
+    set_accepted_solutions(&txn, res.last_insert_id, &accepted_solutions).await?;
+
+    txn.commit().await?;
 
     Ok((
         StatusCode::CREATED,
         Json(Response {
             id: res.last_insert_id,
             body: request.body,
-            solution: request.solution,
+            kind: request.kind,
+            solution,
+            accepted_solutions,
+            tolerance: request.tolerance,
             image: request.image,
+            points: request.points,
+            wrong_points: request.wrong_points,
+            max_attempts: request.max_attempts,
+            attempt_penalty: request.attempt_penalty,
+            release_minutes: request.release_minutes,
         }),
     ))
 }
@@ -1,6 +1,7 @@
 use crate::{
     StateTrait,
     error::{self, Result},
+    extractors::Competition,
     handlers::socket::Event,
     utils::topics,
 };
@@ -9,22 +10,27 @@ use axum::{
     http::StatusCode,
 };
 use entity::{problems, problems_order};
-use sea_orm::{EntityTrait, TransactionTrait};
+use sea_orm::{ColumnTrait, EntityTrait, QueryFilter, TransactionTrait};
 use uuid::Uuid;
 
 pub async fn delete_problem<S: StateTrait>(
     State(state): State<S>,
-    Path(id): Path<Uuid>,
+    competition: Competition,
+    Path((_, id)): Path<(Uuid, Uuid)>,
 ) -> Result<StatusCode> {
     let txn = state.db().begin().await?;
 
     let res = problems_order::Entity::find_by_id(id).one(&txn).await?;
 
     if res.is_some() {
-        super::order::delete_problem(&txn, id).await?;
+        super::order::delete_problem(&txn, competition.id, id).await?;
     }
 
-    let res = problems::Entity::delete_by_id(id).exec(&txn).await?;
+    let res = problems::Entity::delete_many()
+        .filter(problems::Column::Id.eq(id))
+        .filter(problems::Column::Competition.eq(competition.id))
+        .exec(&txn)
+        .await?;
 
     if res.rows_affected == 0 {
         return Err(error::PROBLEM_NOT_FOUND);
@@ -33,7 +39,7 @@ pub async fn delete_problem<S: StateTrait>(
     state
         .nats()
         .publish(
-            topics::problems(),
+            topics::problems(&competition.id),
             serde_json::to_vec(&Event::DeleteProblem { id })
                 .unwrap()
                 .into(),
@@ -1,33 +1,90 @@
 use crate::{
     StateTrait,
     error::{self, Result},
-    extractors::Json,
+    extractors::{Competition, Json},
 };
 use axum::extract::{Path, State};
-use entity::problems;
-use sea_orm::{EntityTrait, FromQueryResult};
+use entity::{
+    accepted_solutions,
+    problems::{self, AnswerKind},
+};
+use sea_orm::{ColumnTrait, ConnectionTrait, EntityTrait, QueryFilter, QueryOrder};
 use serde::Serialize;
+use std::collections::HashMap;
 use uuid::Uuid;
 
-#[derive(Serialize, FromQueryResult)]
+#[derive(Serialize)]
 pub struct Response {
     id: Uuid,
     body: String,
-    solution: i64,
+    kind: AnswerKind,
+    solution: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    accepted_solutions: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tolerance: Option<f64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     image: Option<String>,
+    points: i32,
+    wrong_points: i32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_attempts: Option<i32>,
+    attempt_penalty: i32,
+    release_minutes: i32,
+}
+
+impl Response {
+    fn new(problem: problems::Model, accepted_solutions: Vec<String>) -> Self {
+        Self {
+            id: problem.id,
+            body: problem.body,
+            kind: problem.kind,
+            solution: problem.solution,
+            accepted_solutions,
+            tolerance: problem.tolerance,
+            image: problem.image,
+            points: problem.points,
+            wrong_points: problem.wrong_points,
+            max_attempts: problem.max_attempts,
+            attempt_penalty: problem.attempt_penalty,
+            release_minutes: problem.release_minutes,
+        }
+    }
+}
+
+/// Returns the accepted answers of every problem
+async fn find_accepted_solutions(
+    db: &impl ConnectionTrait,
+    problem_ids: impl IntoIterator<Item = Uuid>,
+) -> Result<HashMap<Uuid, Vec<String>>> {
+    let res = accepted_solutions::Entity::find()
+        .filter(accepted_solutions::Column::Problem.is_in(problem_ids))
+        .order_by_asc(accepted_solutions::Column::Solution)
+        .all(db)
+        .await?;
+
+    let mut map = HashMap::<Uuid, Vec<String>>::new();
+
+    for accepted in res {
+        map.entry(accepted.problem)
+            .or_default()
+            .push(accepted.solution);
+    }
+
+    Ok(map)
 }
 
 pub async fn get_problem<S: StateTrait>(
     State(state): State<S>,
-    Path(id): Path<String>,
+    competition: Competition,
+    Path((_, id)): Path<(String, String)>,
 ) -> Result<Json<Response>> {
     let Ok(uuid) = Uuid::parse_str(&id) else {
         return Err(error::PROBLEM_NOT_FOUND);
     };
 
     let res = problems::Entity::find_by_id(uuid)
-        .into_model::<Response>()
+        .filter(problems::Column::Competition.eq(competition.id))
         .one(state.db())
         .await?;
 
@@ -35,14 +92,31 @@ pub async fn get_problem<S: StateTrait>(
         return Err(error::PROBLEM_NOT_FOUND);
     };
 
-    Ok(Json(problem))
+    let mut accepted = find_accepted_solutions(state.db(), [problem.id]).await?;
+    let accepted = accepted.remove(&problem.id).unwrap_or_default();
+
+    Ok(Json(Response::new(problem, accepted)))
 }
 
-pub async fn list_problems<S: StateTrait>(State(state): State<S>) -> Result<Json<Vec<Response>>> {
+pub async fn list_problems<S: StateTrait>(
+    State(state): State<S>,
+    competition: Competition,
+) -> Result<Json<Vec<Response>>> {
     let res = problems::Entity::find()
-        .into_model::<Response>()
+        .filter(problems::Column::Competition.eq(competition.id))
         .all(state.db())
         .await?;
 
-    Ok(Json(res))
+    let mut accepted =
+        find_accepted_solutions(state.db(), res.iter().map(|problem| problem.id)).await?;
+
+    let problems = res
+        .into_iter()
+        .map(|problem| {
+            let accepted = accepted.remove(&problem.id).unwrap_or_default();
+            Response::new(problem, accepted)
+        })
+        .collect();
+
+    Ok(Json(problems))
 }
@@ -2,6 +2,7 @@ mod create;
 mod delete;
 mod list;
 mod order;
+mod release;
 mod update;
 
 use crate::{StateTrait, middlewares::PermissionsLayer};
@@ -10,7 +11,7 @@ use axum::{
     routing::{delete, get, patch, post, put},
 };
 
-/// Routes for problem management
+/// Routes for problem management, nested under `/competition/{competition}`
 ///
 /// GET    /problem
 /// GET    /problem/{id}
@@ -20,6 +21,8 @@ use axum::{
 /// DELETE /problem/{id}
 ///
 /// POST   /problem/order
+///
+/// POST   /problem/release
 pub fn routes<S: StateTrait>(state: S) -> Router<S> {
     Router::new()
         .route(
@@ -71,6 +74,13 @@ pub fn routes<S: StateTrait>(state: S) -> Router<S> {
                 &["mathcompetition.problems"],
             )),
         )
+        .route(
+            "/release",
+            post(release::set_release::<S>).layer(PermissionsLayer::new(
+                state.clone(),
+                &["mathcompetition.problems"],
+            )),
+        )
         .route(
             "/order",
             get(order::get::<S>).layer(PermissionsLayer::new(state, &["mathcompetition.problems"])),
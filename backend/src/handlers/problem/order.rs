@@ -1,7 +1,7 @@
 use crate::{
     StateTrait,
     error::{self, DatabaseError, Result},
-    extractors::Json,
+    extractors::{Competition, Json},
     handlers::socket::Event,
     utils::{execute_str, sort_linked, topics},
 };
@@ -31,14 +31,22 @@ pub enum Request {
 
 pub async fn change<S: StateTrait>(
     State(state): State<S>,
+    competition: Competition,
     Json(request): Json<Request>,
 ) -> Result<StatusCode> {
     let txn = state.db().begin().await?;
 
     match request {
         Request::Insert { before, id } => {
+            problems::Entity::find_by_id(id)
+                .filter(problems::Column::Competition.eq(competition.id))
+                .one(&txn)
+                .await?
+                .ok_or(error::PROBLEM_NOT_FOUND)?;
+
             if let Some(before) = before {
                 let after = problems_order::Entity::find()
+                    .filter(problems_order::Column::Competition.eq(competition.id))
                     .filter(problems_order::Column::Next.eq(before))
                     .lock_exclusive()
                     .one(&txn)
@@ -52,6 +60,7 @@ pub async fn change<S: StateTrait>(
 
                 let res = problems_order::Entity::insert(problems_order::ActiveModel {
                     id: Set(id),
+                    competition: Set(competition.id),
                     next: Set(Some(before)),
                 })
                 .exec(&txn)
@@ -72,6 +81,7 @@ pub async fn change<S: StateTrait>(
                     problems_order::Entity::update(problems_order::ActiveModel {
                         id: Set(after.id),
                         next: Set(Some(id)),
+                        ..Default::default()
                     })
                     .exec(&txn)
                     .await?;
@@ -82,12 +92,13 @@ pub async fn change<S: StateTrait>(
                 state
                     .nats()
                     .publish(
-                        topics::problems(),
+                        topics::problems(&competition.id),
                         serde_json::to_vec(&Event::InsertProblem {
                             before: Some(before),
                             id: res.id,
                             body: res.body,
                             image: res.image,
+                            release_minutes: Some(res.release_minutes),
                         })
                         .unwrap()
                         .into(),
@@ -99,6 +110,7 @@ pub async fn change<S: StateTrait>(
                 // Insert problem to the end of the list
 
                 let before = problems_order::Entity::find()
+                    .filter(problems_order::Column::Competition.eq(competition.id))
                     .filter(problems_order::Column::Next.is_null())
                     .lock_exclusive()
                     .one(&txn)
@@ -112,6 +124,7 @@ pub async fn change<S: StateTrait>(
 
                 let res = problems_order::Entity::insert(problems_order::ActiveModel {
                     id: Set(id),
+                    competition: Set(competition.id),
                     next: NotSet,
                 })
                 .exec(&txn)
@@ -132,6 +145,7 @@ pub async fn change<S: StateTrait>(
                     problems_order::Entity::update(problems_order::ActiveModel {
                         id: Set(before.id),
                         next: Set(Some(id)),
+                        ..Default::default()
                     })
                     .exec(&txn)
                     .await?;
@@ -142,12 +156,13 @@ pub async fn change<S: StateTrait>(
                 state
                     .nats()
                     .publish(
-                        topics::problems(),
+                        topics::problems(&competition.id),
                         serde_json::to_vec(&Event::InsertProblem {
                             before: None,
                             id: res.id,
                             body: res.body,
                             image: res.image,
+                            release_minutes: Some(res.release_minutes),
                         })
                         .unwrap()
                         .into(),
@@ -158,12 +173,12 @@ pub async fn change<S: StateTrait>(
             }
         }
         Request::Delete { id } => {
-            delete_problem(&txn, id).await?;
+            delete_problem(&txn, competition.id, id).await?;
 
             state
                 .nats()
                 .publish(
-                    topics::problems(),
+                    topics::problems(&competition.id),
                     serde_json::to_vec(&Event::DeleteProblem { id })
                         .unwrap()
                         .into(),
@@ -176,6 +191,7 @@ pub async fn change<S: StateTrait>(
             trace!("swapping: {id1}, {id2}");
 
             let res = problems_order::Entity::find()
+                .filter(problems_order::Column::Competition.eq(competition.id))
                 .filter(
                     Condition::any()
                         .add(problems_order::Column::Id.eq(id1))
@@ -260,7 +276,7 @@ pub async fn change<S: StateTrait>(
             state
                 .nats()
                 .publish(
-                    topics::problems(),
+                    topics::problems(&competition.id),
                     serde_json::to_vec(&Event::SwapProblems { id1, id2 })
                         .unwrap()
                         .into(),
@@ -274,8 +290,14 @@ pub async fn change<S: StateTrait>(
     Ok(StatusCode::NO_CONTENT)
 }
 
-pub async fn get<S: StateTrait>(State(state): State<S>) -> Result<Json<Vec<Uuid>>> {
-    let res = problems_order::Entity::find().all(state.db()).await?;
+pub async fn get<S: StateTrait>(
+    State(state): State<S>,
+    competition: Competition,
+) -> Result<Json<Vec<Uuid>>> {
+    let res = problems_order::Entity::find()
+        .filter(problems_order::Column::Competition.eq(competition.id))
+        .all(state.db())
+        .await?;
 
     debug!("res: {res:?}");
 
@@ -289,11 +311,12 @@ pub async fn get<S: StateTrait>(State(state): State<S>) -> Result<Json<Vec<Uuid>
     Ok(Json(ids))
 }
 
-pub(super) async fn delete_problem<T>(txn: &T, id: Uuid) -> Result<()>
+pub(super) async fn delete_problem<T>(txn: &T, competition_id: Uuid, id: Uuid) -> Result<()>
 where
     T: TransactionTrait + ConnectionTrait,
 {
     let res = problems_order::Entity::find()
+        .filter(problems_order::Column::Competition.eq(competition_id))
         .filter(
             Condition::any()
                 .add(problems_order::Column::Id.eq(id))
@@ -324,6 +347,7 @@ where
         problems_order::Entity::update(problems_order::ActiveModel {
             id: Set(before.id),
             next: Set(to_delete.next),
+            ..Default::default()
         })
         .exec(txn)
         .await?;
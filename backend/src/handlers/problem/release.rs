@@ -0,0 +1,64 @@
+use crate::{
+    StateTrait,
+    error::{self, Result},
+    extractors::{Competition, ValidatedJson},
+    handlers::socket::Event,
+    utils::topics,
+};
+use axum::{extract::State, http::StatusCode};
+use entity::problems;
+use sea_orm::{ColumnTrait, EntityTrait, QueryFilter, TransactionTrait, sea_query::Expr};
+use serde::Deserialize;
+use uuid::Uuid;
+use validator::Validate;
+
+#[derive(Deserialize, Validate)]
+pub struct Request {
+    #[validate(length(min = 1))]
+    problems: Vec<Uuid>,
+    #[validate(range(min = 0))]
+    release_minutes: i32,
+}
+
+/// Sets the release time of a group of problems at once
+pub async fn set_release<S: StateTrait>(
+    State(state): State<S>,
+    competition: Competition,
+    ValidatedJson(mut request): ValidatedJson<Request>,
+) -> Result<StatusCode> {
+    request.problems.sort_unstable();
+    request.problems.dedup();
+
+    let txn = state.db().begin().await?;
+
+    let res = problems::Entity::update_many()
+        .col_expr(
+            problems::Column::ReleaseMinutes,
+            Expr::value(request.release_minutes),
+        )
+        .filter(problems::Column::Id.is_in(request.problems.iter().copied()))
+        .filter(problems::Column::Competition.eq(competition.id))
+        .exec(&txn)
+        .await?;
+
+    if res.rows_affected != request.problems.len() as u64 {
+        return Err(error::PROBLEM_NOT_FOUND);
+    }
+
+    state
+        .nats()
+        .publish(
+            topics::problems(&competition.id),
+            serde_json::to_vec(&Event::ReleaseProblems {
+                ids: request.problems,
+                release_minutes: request.release_minutes,
+            })
+            .unwrap()
+            .into(),
+        )
+        .await?;
+
+    txn.commit().await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
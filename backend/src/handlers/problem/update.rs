@@ -1,31 +1,72 @@
 use crate::{
     StateTrait,
     error::{self, Result},
-    extractors::Json,
-    handlers::socket::Event,
-    utils::{set_option, topics},
+    extractors::{Competition, Json, ValidatedJson},
+    handlers::{
+        problem::create::{
+            default_attempt_penalty, default_points, default_wrong_points,
+            normalize_accepted_solutions, normalize_solution, set_accepted_solutions,
+        },
+        socket::Event,
+    },
+    utils::{RawAnswer, set_option, topics},
 };
 use axum::{extract::State, http::StatusCode};
-use entity::problems;
-use sea_orm::{DbErr, EntityTrait, Set, TransactionTrait};
+use entity::{
+    accepted_solutions,
+    problems::{self, AnswerKind},
+};
+use sea_orm::{
+    ActiveValue::NotSet, ColumnTrait, DbErr, EntityTrait, QueryFilter, QuerySelect, Set,
+    TransactionTrait,
+};
 use serde::Deserialize;
 use uuid::Uuid;
+use validator::Validate;
 
-#[derive(Deserialize)]
+#[derive(Deserialize, Validate)]
 pub struct Request {
     id: Uuid,
     body: Option<String>,
-    solution: Option<i64>,
+    kind: Option<AnswerKind>,
+    solution: Option<RawAnswer>,
+    #[validate(length(max = 32))]
+    accepted_solutions: Option<Vec<RawAnswer>>,
+    #[serde(default, with = "::serde_with::rust::double_option")]
+    tolerance: Option<Option<f64>>,
     #[serde(default, with = "::serde_with::rust::double_option")]
     image: Option<Option<String>>,
+    #[validate(range(min = 0))]
+    points: Option<i32>,
+    #[validate(range(max = 0))]
+    wrong_points: Option<i32>,
+    #[serde(default, with = "::serde_with::rust::double_option")]
+    #[validate(range(min = 1))]
+    max_attempts: Option<Option<i32>>,
+    #[validate(range(max = 0))]
+    attempt_penalty: Option<i32>,
+    #[validate(range(min = 0))]
+    release_minutes: Option<i32>,
 }
 
 pub async fn update_problem<S: StateTrait>(
     State(state): State<S>,
-    Json(request): Json<Request>,
+    competition: Competition,
+    ValidatedJson(request): ValidatedJson<Request>,
 ) -> Result<StatusCode> {
     // This is necessary because the ORM would generate a wrong sql statement
-    if request.body.is_none() && request.solution.is_none() && request.image.is_none() {
+    if request.body.is_none()
+        && request.kind.is_none()
+        && request.solution.is_none()
+        && request.accepted_solutions.is_none()
+        && request.tolerance.is_none()
+        && request.image.is_none()
+        && request.points.is_none()
+        && request.wrong_points.is_none()
+        && request.max_attempts.is_none()
+        && request.attempt_penalty.is_none()
+        && request.release_minutes.is_none()
+    {
         return Ok(StatusCode::NO_CONTENT);
     }
 
@@ -38,27 +79,114 @@ pub async fn update_problem<S: StateTrait>(
 
     let txn = state.db().begin().await?;
 
+    // Changing any of these changes which answers are correct
+    let answers_changed = request.kind.is_some()
+        || request.solution.is_some()
+        || request.accepted_solutions.is_some()
+        || request.tolerance.is_some();
+
+    let rescore = answers_changed
+        || request.points.is_some()
+        || request.wrong_points.is_some()
+        || request.attempt_penalty.is_some();
+
+    // The solutions have to be valid for the new kind and tolerance, even if only one of them changes
+    let (kind, solution, tolerance, accepted) = if answers_changed {
+        let problem = problems::Entity::find_by_id(request.id)
+            .filter(problems::Column::Competition.eq(competition.id))
+            .lock_exclusive()
+            .one(&txn)
+            .await?
+            .ok_or(error::PROBLEM_NOT_FOUND)?;
+
+        let kind = request.kind.unwrap_or(problem.kind);
+        let tolerance = request.tolerance.unwrap_or(problem.tolerance);
+        let solution = request
+            .solution
+            .unwrap_or(RawAnswer::Text(problem.solution));
+
+        if tolerance.is_some_and(|tolerance| tolerance < 0.0) {
+            return Err(error::JSON_VALIDATE_INVALID);
+        }
+
+        let solution = normalize_solution(kind, &solution, tolerance)?;
+
+        let accepted = match request.accepted_solutions {
+            Some(accepted) => accepted,
+            None => accepted_solutions::Entity::find()
+                .filter(accepted_solutions::Column::Problem.eq(request.id))
+                .all(&txn)
+                .await?
+                .into_iter()
+                .map(|accepted| RawAnswer::Text(accepted.solution))
+                .collect(),
+        };
+
+        let accepted = normalize_accepted_solutions(kind, &solution, &accepted)?;
+
+        (Set(kind), Set(solution), Set(tolerance), Some(accepted))
+    } else {
+        (NotSet, NotSet, NotSet, None)
+    };
+
     let active_model = problems::ActiveModel {
         id: Set(request.id),
         body: set_option(request.body),
-        solution: set_option(request.solution),
+        kind,
+        solution,
+        tolerance,
         image: set_option(request.image),
+        points: set_option(request.points),
+        wrong_points: set_option(request.wrong_points),
+        max_attempts: set_option(request.max_attempts),
+        attempt_penalty: set_option(request.attempt_penalty),
+        release_minutes: set_option(request.release_minutes),
+        ..Default::default()
     };
 
-    let res = problems::Entity::update(active_model).exec(&txn).await;
+    let res = problems::Entity::update(active_model)
+        .filter(problems::Column::Competition.eq(competition.id))
+        .exec(&txn)
+        .await;
 
     match res {
         Err(DbErr::RecordNotUpdated) => return Err(error::PROBLEM_NOT_FOUND),
         e => e?,
     };
 
+    if let Some(accepted) = accepted {
+        set_accepted_solutions(&txn, request.id, &accepted).await?;
+    }
+
     state
         .nats()
-        .publish(topics::problems(), payload.into())
+        .publish(topics::problems(&competition.id), payload.into())
         .await?;
 
+    if let Some(release_minutes) = request.release_minutes {
+        let payload = serde_json::to_vec(&Event::ReleaseProblems {
+            ids: vec![request.id],
+            release_minutes,
+        })
+        .unwrap();
+
+        state
+            .nats()
+            .publish(topics::problems(&competition.id), payload.into())
+            .await?;
+    }
+
     txn.commit().await?;
 
+    if rescore {
+        let payload = serde_json::to_vec(&Event::RescoreProblem { id: request.id }).unwrap();
+
+        state
+            .nats()
+            .publish(topics::rescoring(&competition.id), payload.into())
+            .await?;
+    }
+
     Ok(StatusCode::NO_CONTENT)
 }
 
@@ -66,22 +194,47 @@ pub async fn update_problem<S: StateTrait>(
 pub struct PutRequest {
     id: Uuid,
     body: String,
-    solution: i64,
+    #[serde(default)]
+    kind: AnswerKind,
+    solution: RawAnswer,
+    #[serde(default)]
+    accepted_solutions: Vec<RawAnswer>,
+    tolerance: Option<f64>,
     image: Option<String>,
+    #[serde(default = "default_points")]
+    points: i32,
+    #[serde(default = "default_wrong_points")]
+    wrong_points: i32,
+    max_attempts: Option<i32>,
+    #[serde(default = "default_attempt_penalty")]
+    attempt_penalty: i32,
+    #[serde(default)]
+    release_minutes: i32,
 }
 
 pub async fn put<S: StateTrait>(
     state: State<S>,
+    competition: Competition,
     Json(request): Json<PutRequest>,
 ) -> Result<StatusCode> {
-    update_problem(
-        state,
-        Json(Request {
-            id: request.id,
-            body: Some(request.body),
-            solution: Some(request.solution),
-            image: Some(request.image),
-        }),
-    )
-    .await
+    let request = Request {
+        id: request.id,
+        body: Some(request.body),
+        kind: Some(request.kind),
+        solution: Some(request.solution),
+        accepted_solutions: Some(request.accepted_solutions),
+        tolerance: Some(request.tolerance),
+        image: Some(request.image),
+        points: Some(request.points),
+        wrong_points: Some(request.wrong_points),
+        max_attempts: Some(request.max_attempts),
+        attempt_penalty: Some(request.attempt_penalty),
+        release_minutes: Some(request.release_minutes),
+    };
+
+    request
+        .validate()
+        .map_err(|_| error::JSON_VALIDATE_INVALID)?;
+
+    update_problem(state, competition, ValidatedJson(request)).await
 }
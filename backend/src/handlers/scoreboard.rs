@@ -0,0 +1,172 @@
+use crate::{
+    Result, StateTrait, error,
+    extractors::Competition,
+    handlers::socket::Event,
+    middlewares::has_permissions,
+    utils::{get_times, topics},
+};
+use axum::{
+    extract::{
+        State,
+        ws::{CloseFrame, Message, WebSocket, WebSocketUpgrade, close_code},
+    },
+    response::IntoResponse,
+};
+use chrono::Utc;
+use futures::StreamExt;
+use serde::Deserialize;
+use std::time::Duration;
+use tokio::time::{self, MissedTickBehavior};
+use uuid::Uuid;
+
+/// How often the scoreboard is recalculated at most
+const UPDATE_INTERVAL: Duration = Duration::from_secs(1);
+
+#[derive(Deserialize)]
+struct TokenJSON {
+    token: String,
+}
+
+/// Read-only scoreboard feed for projecting during the competition.
+///
+/// The public feed is frozen `SCOREBOARD_FREEZE` seconds before `end_time`. Admins can send
+/// `{"token": "..."}` on the socket to get the live standings and a `RESCORE_PROBLEM` event
+/// whenever the correct answers of a problem change.
+pub async fn ws_handler<S: StateTrait>(
+    State(state): State<S>,
+    competition: Competition,
+    ws: WebSocketUpgrade,
+) -> impl IntoResponse {
+    ws.on_upgrade(move |mut socket: WebSocket| async move {
+        if let Err(err) = scoreboard_handler(state, competition.id, &mut socket).await {
+            // it's okay to ignore the error here
+            let _ = socket
+                .send(Message::Close(Some(CloseFrame {
+                    code: close_code::ERROR,
+                    reason: err.to_bytes().try_into().unwrap(),
+                })))
+                .await;
+
+            socket.next().await;
+            warn!("scoreboard socket ended with error: {:?}", err);
+        }
+    })
+}
+
+async fn scoreboard_handler<S: StateTrait>(
+    state: S,
+    competition_id: Uuid,
+    socket: &mut WebSocket,
+) -> Result<()> {
+    let nats = state.nats();
+    let mut solutions = nats
+        .subscribe(topics::all_team_solutions(&competition_id))
+        .await?;
+    let mut times = nats.subscribe(topics::times(&competition_id)).await?;
+    let mut rescoring = nats.subscribe(topics::rescoring(&competition_id)).await?;
+
+    let (_, mut end_time) = get_times(state.db(), competition_id).await?;
+
+    let mut interval = time::interval(UPDATE_INTERVAL);
+    interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+    let mut is_admin = false;
+    let mut dirty = true;
+    // The latest change this socket knows about, an older cached scoreboard is not used
+    let mut changed_at = Utc::now();
+    let mut sent_frozen = false;
+
+    loop {
+        tokio::select! {
+            _ = interval.tick() => {
+                let freeze_time = end_time - state.scoreboard_freeze();
+                let frozen = !is_admin && Utc::now() >= freeze_time;
+
+                if (frozen && !sent_frozen) || (!frozen && dirty) {
+                    let frozen_at = frozen.then_some(freeze_time);
+                    let payload = state
+                        .scoreboards()
+                        .get(state.db(), competition_id, frozen_at, changed_at)
+                        .await?;
+
+                    socket.send(Message::Text(payload)).await.map_err(|err| {
+                        error!("websocket error: {:?}", err);
+                        error::WEBSOCKET_ERROR
+                    })?;
+
+                    sent_frozen = frozen;
+                    dirty = false;
+                }
+            }
+            Some(_) = solutions.next() => {
+                dirty = true;
+                changed_at = Utc::now();
+            }
+            Some(message) = times.next() => {
+                if let Ok(Event::UpdateTime { end_time: Some(new_end_time), .. }) =
+                    serde_json::from_slice(&message.payload)
+                {
+                    end_time = new_end_time;
+                    sent_frozen = false;
+                    dirty = true;
+                    changed_at = Utc::now();
+                }
+            }
+            Some(message) = rescoring.next() => {
+                // Even the frozen scoreboard has to reflect the new answers
+                sent_frozen = false;
+                dirty = true;
+                changed_at = Utc::now();
+
+                if is_admin {
+                    let payload = String::from_utf8_lossy(&message.payload).into_owned();
+
+                    socket.send(Message::Text(payload.into())).await.map_err(|err| {
+                        error!("websocket error: {:?}", err);
+                        error::WEBSOCKET_ERROR
+                    })?;
+                }
+            }
+            message = socket.next() => {
+                match message {
+                    Some(Ok(Message::Text(text))) => {
+                        is_admin = authenticate_admin(&state, &text).await?;
+                        sent_frozen = false;
+                        dirty = true;
+                    }
+                    Some(Ok(Message::Close(_))) | None => break Ok(()),
+                    Some(Ok(_)) => {
+                        warn!("got non-text message on scoreboard websocket");
+                    }
+                    Some(Err(err)) => {
+                        error!("websocket error: {:?}", err);
+                        return Err(error::WEBSOCKET_ERROR)
+                    }
+                }
+            }
+        }
+    }
+}
+
+async fn authenticate_admin<S: StateTrait>(state: &S, message: &str) -> Result<bool> {
+    let token_json: TokenJSON =
+        serde_json::from_str(message).map_err(|_| error::JWT_INVALID_TOKEN)?;
+
+    let claims = state
+        .jwt()
+        .get_claims(&token_json.token)
+        .await
+        .map_err(|_| error::JWT_INVALID_TOKEN)?;
+
+    if !has_permissions(
+        state.iam_app().token(),
+        &claims.sub,
+        &["mathcompetition.admin"],
+    )
+    .await
+    {
+        return Err(error::NOT_ENOUGH_PERMISSIONS);
+    }
+
+    Ok(true)
+}
@@ -1,7 +1,10 @@
 use crate::{
     Result, StateTrait, error,
-    extractors::UserID,
-    utils::{ProblemStream, topics},
+    extractors::{Competition, UserID},
+    handlers::stats::scoreboard,
+    utils::{
+        LockedProblems, ProblemStream, get_paused_duration, get_team_times, get_user_name, topics,
+    },
 };
 use axum::{
     extract::{
@@ -11,16 +14,24 @@ use axum::{
     response::IntoResponse,
 };
 use chrono::{DateTime, Utc};
-use entity::times;
 use entity::{
-    solutions_history, teams,
+    pauses, solutions_history, team_invitations, team_join_requests,
+    teams::{self, Exclusion},
     users::{self, Class},
 };
 use futures::{Stream, StreamExt};
-use sea_orm::{ColumnTrait, Condition, EntityTrait, QueryFilter, QueryOrder, QuerySelect};
+use sea_orm::{
+    ColumnTrait, ConnectionTrait, EntityTrait, FromQueryResult, QueryFilter, QueryOrder,
+    QuerySelect, Statement,
+};
 use serde::{Deserialize, Serialize};
-use std::{error::Error as _, mem::MaybeUninit, pin::pin, time::Duration};
-use tokio::time::{self, sleep};
+use std::{
+    error::Error as _,
+    mem::MaybeUninit,
+    pin::{Pin, pin},
+    time::Duration,
+};
+use tokio::time::{self, Sleep, sleep};
 use tokio_tungstenite::tungstenite::error::Error as TungsteniteError;
 use tracing::Instrument;
 use uuid::Uuid;
@@ -40,6 +51,22 @@ pub struct Member {
     rank: Rank,
 }
 
+/// A pending invitation of the team
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Invitation {
+    user: Uuid,
+    name: String,
+    expires_at: DateTime<Utc>,
+}
+
+/// A user waiting for the approval of the owner or the co-owner
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct JoinRequest {
+    user: Uuid,
+    name: String,
+    created_at: DateTime<Utc>,
+}
+
 #[serde_with::skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(tag = "event", content = "data", rename_all = "SCREAMING_SNAKE_CASE")]
@@ -57,7 +84,12 @@ pub enum Event {
         name: String,
         code: String,
         locked: bool,
+        require_approval: bool,
         members: Vec<Member>,
+        invitations: Vec<Invitation>,
+        join_requests: Vec<JoinRequest>,
+        exclusion: Option<Exclusion>,
+        exclusion_reason: Option<String>,
     },
     UpdateTeam {
         name: Option<String>,
@@ -65,22 +97,74 @@ pub enum Event {
         #[serde(default, with = "::serde_with::rust::double_option")]
         co_owner: Option<Option<Uuid>>,
         locked: Option<bool>,
+        require_approval: Option<bool>,
         code: Option<String>,
     },
     DisbandTeam,
+    /// A user was invited into the team, it is sent again when the invitation is renewed
+    InviteUser {
+        user: Uuid,
+        name: String,
+        expires_at: DateTime<Utc>,
+    },
+    /// The invitation of a user was accepted, declined or cancelled
+    DeleteInvitation {
+        user: Uuid,
+    },
+    /// Sent to the invited user, it is sent again when the invitation is renewed
+    ReceiveInvitation {
+        id: Uuid,
+        team: Uuid,
+        team_name: String,
+        invited_by: Uuid,
+        invited_by_name: String,
+        expires_at: DateTime<Utc>,
+    },
+    /// Sent to the invited user when the invitation was accepted, declined or cancelled
+    RemoveInvitation {
+        id: Uuid,
+    },
+    /// A user joined with the code, but the team needs to approve it
+    RequestJoin {
+        user: Uuid,
+        name: String,
+        created_at: DateTime<Utc>,
+    },
+    /// The join request of a user was approved or rejected
+    DeleteJoinRequest {
+        user: Uuid,
+    },
+    /// The team was left out of the rankings, or ranked again if `exclusion` is null
+    UpdateExclusion {
+        team: Uuid,
+        exclusion: Option<Exclusion>,
+        reason: Option<String>,
+    },
     UpdateTime {
         start_time: Option<DateTime<Utc>>,
         end_time: Option<DateTime<Utc>>,
     },
+    PauseCompetition {
+        since: DateTime<Utc>,
+    },
+    ResumeCompetition,
     SolutionSet {
         problem: Uuid,
-        solution: Option<i64>,
+        /// The normalized answer
+        solution: Option<String>,
+    },
+    /// How many more times the team can change its answer, only for problems with a limit
+    RemainingAttempts {
+        problem: Uuid,
+        remaining: i64,
     },
     InsertProblem {
         before: Option<Uuid>,
         id: Uuid,
         body: String,
         image: Option<String>,
+        /// Only used internally, not sent to the teams
+        release_minutes: Option<i32>,
     },
     DeleteProblem {
         id: Uuid,
@@ -94,14 +178,28 @@ pub enum Event {
         body: Option<String>,
         image: Option<Option<String>>,
     },
+    /// Only used internally, the teams get `InsertProblem` when a problem is released
+    ReleaseProblems {
+        ids: Vec<Uuid>,
+        release_minutes: i32,
+    },
+    /// The correct answers of a problem changed, so stats and scoreboards were recalculated
+    RescoreProblem {
+        id: Uuid,
+    },
+    UpdateScoreboard {
+        frozen: bool,
+        scoreboard: Vec<scoreboard::Entry>,
+    },
 }
 
 pub async fn ws_handler<S: StateTrait>(
     State(state): State<S>,
+    competition: Competition,
     ws: WebSocketUpgrade,
 ) -> impl IntoResponse {
-    ws.on_upgrade(|mut socket: WebSocket| async move {
-        if let Err(err) = socket_handler(state, &mut socket).await {
+    ws.on_upgrade(move |mut socket: WebSocket| async move {
+        if let Err(err) = socket_handler(state, competition.id, &mut socket).await {
             // it's okay to ignore the error here
             let _ = socket
                 .send(Message::Close(Some(CloseFrame {
@@ -118,12 +216,17 @@ pub async fn ws_handler<S: StateTrait>(
     })
 }
 
-async fn socket_handler<S: StateTrait>(state: S, socket: &mut WebSocket) -> Result<()> {
-    let (team, members, user_id) = socket_auth(&state, socket).await?;
+async fn socket_handler<S: StateTrait>(
+    state: S,
+    competition_id: Uuid,
+    socket: &mut WebSocket,
+) -> Result<()> {
+    let (team, members, invitations, join_requests, user_id) =
+        socket_auth(&state, competition_id, socket).await?;
     let claims_span = info_span!("claims", user_id = user_id.to_string());
 
     async move {
-        let mut consumer = std::pin::pin!(create_consumer(&state, &team.id).await?);
+        let mut consumer = std::pin::pin!(create_consumer(&state, &competition_id, &team.id).await?);
 
         socket
             .send(Message::Text(
@@ -132,7 +235,12 @@ async fn socket_handler<S: StateTrait>(state: S, socket: &mut WebSocket) -> Resu
                     name: team.name,
                     code: team.join_code,
                     locked: team.locked,
+                    require_approval: team.require_approval,
                     members,
+                    invitations,
+                    join_requests,
+                    exclusion: team.exclusion,
+                    exclusion_reason: team.exclusion_reason,
                 })
                 .unwrap()
                 .into(),
@@ -143,7 +251,29 @@ async fn socket_handler<S: StateTrait>(state: S, socket: &mut WebSocket) -> Resu
                 error::WEBSOCKET_ERROR
             })?;
 
-        let start_time = send_times(&state, socket).await?;
+        let mut start_time = send_times(&state, competition_id, team.id, socket).await?;
+
+        let ongoing_pause = pauses::Entity::find_ongoing(competition_id)
+            .one(state.db())
+            .await?;
+        // Nothing is released while the competition is paused
+        let mut is_paused = ongoing_pause.is_some();
+
+        if let Some(pause) = ongoing_pause {
+            socket
+                .send(Message::Text(
+                    serde_json::to_string(&Event::PauseCompetition {
+                        since: pause.start_time,
+                    })
+                    .unwrap()
+                    .into(),
+                ))
+                .await
+                .map_err(|err| {
+                    error!("websocket error: {:?}", err);
+                    error::WEBSOCKET_ERROR
+                })?;
+        }
 
         let mut has_sent_initial_problems = false;
 
@@ -156,38 +286,87 @@ async fn socket_handler<S: StateTrait>(state: S, socket: &mut WebSocket) -> Resu
         let problems = state.problems();
         let mut problems_stream = ProblemStream::new_empty();
 
+        let mut locked_problems =
+            LockedProblems::new(release_start(&state, competition_id, start_time).await?);
+        let mut next_release = None;
+        let mut sleep_until_release = pin!(sleep(Duration::ZERO));
+
         loop {
             tokio::select! {
                 _ = &mut sleep_until_start, if !has_sent_initial_problems => {
-                    let (mut initial_problems, new_problems_stream) = problems.stream().await;
+                    let (initial_problems, new_problems_stream) = problems.stream(competition_id).await;
                     problems_stream = new_problems_stream;
 
-                    while let Some(event) = initial_problems.next().await {
-                        let payload = serde_json::to_string(&event).unwrap();
-                        if let Err(err) = socket.send(Message::Text(payload.into())).await {
-                            let tungstenite_error = err.source().unwrap().downcast_ref::<TungsteniteError>().unwrap();
-                            error!("websocket error: {:?}", tungstenite_error);
-                            return Err(error::WEBSOCKET_ERROR)
-                        }
-                    }
+                    let events = locked_problems.initial(&initial_problems, Utc::now());
+                    send_events(socket, &events).await?;
 
                     send_answers(&state, socket, team.id).await?;
+                    send_remaining_attempts(&state, socket, competition_id, team.id).await?;
+
+                    next_release = locked_problems.next_release(&initial_problems);
+                    reset_sleep(sleep_until_release.as_mut(), next_release);
 
                     has_sent_initial_problems = true;
                 }
+                _ = &mut sleep_until_release, if has_sent_initial_problems && next_release.is_some() && !is_paused => {
+                    let current_problems = problems.snapshot(competition_id).await;
+
+                    let events = locked_problems.release(&current_problems, Utc::now());
+                    send_events(socket, &events).await?;
+
+                    next_release = locked_problems.next_release(&current_problems);
+                    reset_sleep(sleep_until_release.as_mut(), next_release);
+                }
                 problems_event = problems_stream.next(), if has_sent_initial_problems => {
                     let Some(problems_event) = problems_event else {
                         continue
                     };
 
-                    let payload = serde_json::to_string(&problems_event).unwrap();
-                    if let Err(err) = socket.send(Message::Text(payload.into())).await {
-                        let tungstenite_error = err.source().unwrap().downcast_ref::<TungsteniteError>().unwrap();
-                        error!("websocket error: {:?}", tungstenite_error);
-                        break Err(error::WEBSOCKET_ERROR)
-                    }
+                    let current_problems = problems.snapshot(competition_id).await;
+
+                    let events = locked_problems.filter(problems_event, &current_problems, Utc::now());
+                    send_events(socket, &events).await?;
+
+                    next_release = locked_problems.next_release(&current_problems);
+                    reset_sleep(sleep_until_release.as_mut(), next_release);
                 }
                 Some(event) = consumer.next() => {
+                    match event {
+                        Event::PauseCompetition { .. } => is_paused = true,
+                        // The pause moved the releases, the event is still forwarded to the team
+                        Event::ResumeCompetition => {
+                            is_paused = false;
+                            locked_problems.set_start_time(release_start(&state, competition_id, start_time).await?);
+
+                            if has_sent_initial_problems {
+                                let current_problems = problems.snapshot(competition_id).await;
+                                next_release = locked_problems.next_release(&current_problems);
+                                reset_sleep(sleep_until_release.as_mut(), next_release);
+                            }
+                        }
+                        _ => {}
+                    }
+
+                    // The competition times might be overridden for this team
+                    if matches!(event, Event::UpdateTime { .. }) {
+                        start_time = send_times(&state, competition_id, team.id, socket).await?;
+                        locked_problems.set_start_time(release_start(&state, competition_id, start_time).await?);
+
+                        if has_sent_initial_problems {
+                            let current_problems = problems.snapshot(competition_id).await;
+
+                            let events = locked_problems.release(&current_problems, Utc::now());
+                            send_events(socket, &events).await?;
+
+                            next_release = locked_problems.next_release(&current_problems);
+                            reset_sleep(sleep_until_release.as_mut(), next_release);
+                        } else {
+                            reset_sleep(sleep_until_start.as_mut(), Some(start_time));
+                        }
+
+                        continue
+                    }
+
                     let event_text = serde_json::to_string(&event).unwrap();
 
                     if matches!(event, Event::DisbandTeam)
@@ -228,29 +407,58 @@ async fn socket_handler<S: StateTrait>(state: S, socket: &mut WebSocket) -> Resu
         .await
 }
 
-async fn send_times<S: StateTrait>(state: &S, socket: &mut WebSocket) -> Result<DateTime<Utc>> {
-    let res = times::Entity::find()
-        .filter(
-            Condition::any()
-                .add(times::Column::Name.eq("start_time"))
-                .add(times::Column::Name.eq("end_time")),
-        )
-        .all(state.db())
-        .await?;
+/// Returns the start time the releases of a team are measured from, the pauses since
+/// `start_time` move it later
+async fn release_start<S: StateTrait>(
+    state: &S,
+    competition_id: Uuid,
+    start_time: DateTime<Utc>,
+) -> Result<DateTime<Utc>> {
+    let now = Utc::now();
+    let paused_for = get_paused_duration(state.db(), competition_id, start_time, now).await?;
 
-    if res.len() != 2 {
-        error!("start_time or end_time is not found in the database");
-        return Err(error::INTERNAL);
+    Ok(start_time + paused_for)
+}
+
+/// Resets the timer to fire at `deadline`, it's left alone if there is nothing to wait for
+fn reset_sleep(sleep: Pin<&mut Sleep>, deadline: Option<DateTime<Utc>>) {
+    if let Some(deadline) = deadline {
+        let duration = (deadline - Utc::now()).to_std().unwrap_or(Duration::ZERO);
+        sleep.reset(time::Instant::now() + duration);
+    }
+}
+
+async fn send_events(socket: &mut WebSocket, events: &[Event]) -> Result<()> {
+    for event in events {
+        let payload = serde_json::to_string(event).unwrap();
+        if let Err(err) = socket.send(Message::Text(payload.into())).await {
+            let tungstenite_error = err
+                .source()
+                .unwrap()
+                .downcast_ref::<TungsteniteError>()
+                .unwrap();
+            error!("websocket error: {:?}", tungstenite_error);
+            return Err(error::WEBSOCKET_ERROR);
+        }
     }
 
-    let start_time = res.iter().find(|i| i.name == "start_time").unwrap();
-    let end_time = res.iter().find(|i| i.name == "end_time").unwrap();
+    Ok(())
+}
+
+/// Sends the effective times of the team
+async fn send_times<S: StateTrait>(
+    state: &S,
+    competition_id: Uuid,
+    team_id: Uuid,
+    socket: &mut WebSocket,
+) -> Result<DateTime<Utc>> {
+    let (start_time, end_time) = get_team_times(state.db(), competition_id, team_id).await?;
 
     socket
         .send(Message::Text(
             serde_json::to_string(&Event::UpdateTime {
-                start_time: Some(start_time.time),
-                end_time: Some(end_time.time),
+                start_time: Some(start_time),
+                end_time: Some(end_time),
             })
             .unwrap()
             .into(),
@@ -261,7 +469,7 @@ async fn send_times<S: StateTrait>(state: &S, socket: &mut WebSocket) -> Result<
             error::WEBSOCKET_ERROR
         })?;
 
-    Ok(start_time.time)
+    Ok(start_time)
 }
 
 async fn send_answers<S: StateTrait>(
@@ -297,14 +505,84 @@ async fn send_answers<S: StateTrait>(
     Ok(())
 }
 
-type TeamInfo = (teams::Model, Vec<Member>, UserID);
+async fn send_remaining_attempts<S: StateTrait>(
+    state: &S,
+    socket: &mut WebSocket,
+    competition_id: Uuid,
+    team_id: Uuid,
+) -> Result<()> {
+    const SQL: &str = "
+        select 
+          problems.id as problem, 
+          greatest(
+            problems.max_attempts - count(solutions_history.id), 
+            0
+          ) as remaining 
+        from 
+          problems 
+          left join solutions_history on solutions_history.problem = problems.id 
+          and solutions_history.team = $1 
+          and solutions_history.admin is null 
+        where 
+          problems.competition = $2 
+          and problems.max_attempts is not null 
+        group by 
+          problems.id;
+    ";
+
+    #[derive(FromQueryResult)]
+    struct Row {
+        problem: Uuid,
+        remaining: i64,
+    }
+
+    let db = state.db();
+
+    let res = Row::find_by_statement(Statement::from_sql_and_values(
+        db.get_database_backend(),
+        SQL,
+        [team_id.into(), competition_id.into()],
+    ))
+    .all(db)
+    .await?;
+
+    for row in res {
+        let payload = serde_json::to_string(&Event::RemainingAttempts {
+            problem: row.problem,
+            remaining: row.remaining,
+        })
+        .unwrap();
+
+        socket
+            .send(Message::Text(payload.into()))
+            .await
+            .map_err(|err| {
+                error!("websocket error: {:?}", err);
+                error::WEBSOCKET_ERROR
+            })?;
+    }
+
+    Ok(())
+}
+
+type TeamInfo = (
+    teams::Model,
+    Vec<Member>,
+    Vec<Invitation>,
+    Vec<JoinRequest>,
+    UserID,
+);
 
 #[derive(Serialize, Deserialize)]
 struct TokenJSON {
     token: String,
 }
 
-async fn socket_auth<S: StateTrait>(state: &S, socket: &mut WebSocket) -> Result<TeamInfo> {
+/// Waits for the `{"token": "..."}` message of a registered user
+pub(crate) async fn authenticate<S: StateTrait>(
+    state: &S,
+    socket: &mut WebSocket,
+) -> Result<UserID> {
     let message = {
         let timeout = time::sleep(Duration::from_secs(10));
         tokio::pin!(timeout);
@@ -351,12 +629,25 @@ async fn socket_auth<S: StateTrait>(state: &S, socket: &mut WebSocket) -> Result
 
     let user_id = UserID::parse_str(&claims.sub)?;
 
-    let user = users::Entity::find_by_id(*user_id)
+    if users::Entity::find_by_id(*user_id)
         .one(state.db())
         .await?
-        .ok_or(error::USER_NOT_REGISTERED)?;
+        .is_none()
+    {
+        return Err(error::USER_NOT_REGISTERED);
+    }
+
+    Ok(user_id)
+}
+
+async fn socket_auth<S: StateTrait>(
+    state: &S,
+    competition_id: Uuid,
+    socket: &mut WebSocket,
+) -> Result<TeamInfo> {
+    let user_id = authenticate(state, socket).await?;
 
-    let result = teams::Entity::find_from_member(&user.id)
+    let result = teams::Entity::find_from_member(&competition_id, &user_id)
         .one(state.db())
         .await?
         .ok_or(error::USER_NOT_IN_TEAM)?;
@@ -396,19 +687,55 @@ async fn socket_auth<S: StateTrait>(state: &S, socket: &mut WebSocket) -> Result
         })
     }
 
-    Ok((result, members, user_id))
+    let raw_invitations = team_invitations::Entity::find()
+        .filter(team_invitations::Column::Team.eq(result.id))
+        .filter(team_invitations::Column::ExpiresAt.gt(Utc::now()))
+        .order_by_asc(team_invitations::Column::ExpiresAt)
+        .all(state.db())
+        .await?;
+
+    let mut invitations = Vec::with_capacity(raw_invitations.len());
+
+    for invitation in raw_invitations {
+        invitations.push(Invitation {
+            user: invitation.user,
+            name: get_user_name(state, invitation.user).await?,
+            expires_at: invitation.expires_at,
+        });
+    }
+
+    let raw_join_requests = team_join_requests::Entity::find()
+        .filter(team_join_requests::Column::Team.eq(result.id))
+        .order_by_asc(team_join_requests::Column::CreatedAt)
+        .all(state.db())
+        .await?;
+
+    let mut join_requests = Vec::with_capacity(raw_join_requests.len());
+
+    for join_request in raw_join_requests {
+        join_requests.push(JoinRequest {
+            user: join_request.user,
+            name: get_user_name(state, join_request.user).await?,
+            created_at: join_request.created_at,
+        });
+    }
+
+    Ok((result, members, invitations, join_requests, user_id))
 }
 
 async fn create_consumer<'a, 'b, S: StateTrait>(
     state: &'a S,
+    competition_id: &'a Uuid,
     team_id: &'a Uuid,
 ) -> Result<impl Stream<Item = Event> + 'b> {
     let nats = state.nats();
 
     let combined = futures::stream::select_all([
-        nats.subscribe(topics::team_info(team_id)).await?,
-        nats.subscribe(topics::team_solutions(team_id)).await?,
-        nats.subscribe(topics::times()).await?,
+        nats.subscribe(topics::team_info(competition_id, team_id))
+            .await?,
+        nats.subscribe(topics::team_solutions(competition_id, team_id))
+            .await?,
+        nats.subscribe(topics::times(competition_id)).await?,
     ]);
 
     Ok(combined.filter_map(|message| async move {
@@ -1,6 +1,13 @@
-use crate::{StateTrait, error::Result, extractors::Json};
+use super::{IS_CORRECT, LATEST_SOLUTIONS};
+use crate::{
+    StateTrait,
+    error::Result,
+    extractors::{Competition, Json},
+    utils::get_division_teams,
+};
 use axum::extract::State;
 use chrono::{DateTime, Utc};
+use const_format::formatcp;
 use sea_orm::{ConnectionTrait, FromQueryResult, Statement};
 use serde::{Deserialize, Serialize};
 use std::collections::BTreeMap;
@@ -9,6 +16,8 @@ use uuid::Uuid;
 #[derive(Debug, Deserialize)]
 pub struct Request {
     timestamp: DateTime<Utc>,
+    /// Only the teams in this division
+    division: Option<String>,
 }
 
 #[derive(Debug, Serialize)]
@@ -22,9 +31,11 @@ pub type Response = Vec<TeamData>;
 
 pub async fn get_stats<S: StateTrait>(
     State(state): State<S>,
+    competition: Competition,
     Json(request): Json<Request>,
 ) -> Result<Json<Response>> {
-    static SQL: &str = "
+    const SQL: &str = formatcp!(
+        "
         select 
           id, 
           bools, 
@@ -44,17 +55,11 @@ pub async fn get_stats<S: StateTrait>(
             from 
               (
                 select 
-                  distinct on (team, problem) team, 
-                  solutions_history.solution = problems.solution as correct 
-                from 
-                  solutions_history 
-                  inner join problems on problems.id = solutions_history.problem 
-                where 
-                  created_at < $1
-                order by 
                   team, 
-                  problem, 
-                  created_at desc
+                  {IS_CORRECT} as correct 
+                from 
+                  ({LATEST_SOLUTIONS}) as latest 
+                  inner join problems on problems.id = latest.problem 
               ) 
             where
               correct is not null
@@ -63,17 +68,24 @@ pub async fn get_stats<S: StateTrait>(
               correct
           ) as correct on correct.team = teams.id 
           and correct.correct = bools
+        where
+          teams.competition = $2
+          and teams.exclusion is null
         order by
           id,
           bools;
-    ";
+        "
+    );
 
     let db = state.db();
 
     let res = db
         .query_all(Statement {
             sql: SQL.to_owned(),
-            values: Some(sea_orm::Values(vec![request.timestamp.into()])),
+            values: Some(sea_orm::Values(vec![
+                request.timestamp.into(),
+                competition.id.into(),
+            ])),
             db_backend: db.get_database_backend(),
         })
         .await?;
@@ -188,6 +200,11 @@ pub async fn get_stats<S: StateTrait>(
     //     .and_where(teams::Column::Locked.eq(true))
     //     .to_owned();
 
+    if let Some(division) = &request.division {
+        let teams = get_division_teams(db, &competition, division).await?;
+        map.retain(|team_id, _| teams.contains(team_id));
+    }
+
     let teams = map.into_values().collect();
 
     Ok(Json(teams))
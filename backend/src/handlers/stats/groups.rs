@@ -0,0 +1,214 @@
+use super::scoreboard::{self, Entry};
+use crate::{
+    StateTrait,
+    error::Result,
+    extractors::{Competition, Json},
+};
+use axum::extract::State;
+use chrono::{DateTime, Utc};
+use entity::{
+    team_members, teams,
+    users::{self, Class},
+};
+use sea_orm::{
+    ColumnTrait, ConnectionTrait, EntityTrait, FromQueryResult, JoinType, QueryFilter, QuerySelect,
+    RelationTrait,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use uuid::Uuid;
+
+#[derive(Debug, Deserialize)]
+pub struct Request {
+    timestamp: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct BestTeam {
+    pub team_id: Uuid,
+    pub name: String,
+    pub rank: u64,
+    pub points: i64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct GroupData {
+    /// Teams with at least one member in the group
+    pub teams: usize,
+    pub students: usize,
+    /// Average number of correct answers of the teams
+    pub average_correct: f64,
+    pub best_team: Option<BestTeam>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct SchoolData {
+    pub school: String,
+    #[serde(flatten)]
+    pub data: GroupData,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ClassData {
+    pub class: Class,
+    #[serde(flatten)]
+    pub data: GroupData,
+}
+
+#[derive(Debug, Serialize)]
+pub struct DivisionData {
+    pub division: String,
+    #[serde(flatten)]
+    pub data: GroupData,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Response {
+    pub schools: Vec<SchoolData>,
+    pub classes: Vec<ClassData>,
+    /// Empty if the competition has no divisions
+    pub divisions: Vec<DivisionData>,
+}
+
+/// Results grouped by the school, the class and the division of the students.
+///
+/// A team with members from several schools or classes is counted in each of them, but only in
+/// its own division.
+pub async fn get_group_stats<S: StateTrait>(
+    State(state): State<S>,
+    competition: Competition,
+    Json(request): Json<Request>,
+) -> Result<Json<Response>> {
+    let timestamp = request.timestamp.unwrap_or_else(Utc::now);
+
+    Ok(Json(
+        calculate(state.db(), competition.id, timestamp, None).await?,
+    ))
+}
+
+/// Calculates the group stats from the scoreboard at `timestamp`.
+///
+/// With a `division` only the teams in it are counted, and they are ranked among themselves.
+pub async fn calculate(
+    db: &impl ConnectionTrait,
+    competition_id: Uuid,
+    timestamp: DateTime<Utc>,
+    division: Option<&str>,
+) -> Result<Response> {
+    #[derive(FromQueryResult)]
+    struct Member {
+        team_id: Uuid,
+        school: String,
+        class: Class,
+    }
+
+    let scoreboard = scoreboard::calculate(db, competition_id, timestamp, division).await?;
+
+    let members = team_members::Entity::find()
+        .select_only()
+        .column(team_members::Column::TeamId)
+        .column(users::Column::School)
+        .column(users::Column::Class)
+        .join(JoinType::InnerJoin, team_members::Relation::User.def())
+        .join(JoinType::InnerJoin, team_members::Relation::Team.def())
+        .filter(team_members::Column::Competition.eq(competition_id))
+        .filter(teams::Column::Exclusion.is_null())
+        .into_model::<Member>()
+        .all(db)
+        .await?;
+
+    let positions = scoreboard
+        .iter()
+        .enumerate()
+        .map(|(position, entry)| (entry.team_id, position))
+        .collect::<HashMap<_, _>>();
+
+    let mut schools = BTreeMap::<String, Group>::new();
+    let mut classes = BTreeMap::<Class, Group>::new();
+    let mut divisions = BTreeMap::<String, Group>::new();
+
+    for member in members {
+        // The scoreboard only has the teams of the division
+        if division.is_some() && !positions.contains_key(&member.team_id) {
+            continue;
+        }
+
+        let division = positions
+            .get(&member.team_id)
+            .and_then(|&position| scoreboard[position].division.clone());
+
+        if let Some(division) = division {
+            divisions.entry(division).or_default().add(member.team_id);
+        }
+
+        schools
+            .entry(member.school)
+            .or_default()
+            .add(member.team_id);
+        classes.entry(member.class).or_default().add(member.team_id);
+    }
+
+    Ok(Response {
+        schools: schools
+            .into_iter()
+            .map(|(school, group)| SchoolData {
+                school,
+                data: group.summarize(&scoreboard, &positions),
+            })
+            .collect(),
+        classes: classes
+            .into_iter()
+            .map(|(class, group)| ClassData {
+                class,
+                data: group.summarize(&scoreboard, &positions),
+            })
+            .collect(),
+        divisions: divisions
+            .into_iter()
+            .map(|(division, group)| DivisionData {
+                division,
+                data: group.summarize(&scoreboard, &positions),
+            })
+            .collect(),
+    })
+}
+
+#[derive(Default)]
+struct Group {
+    teams: HashSet<Uuid>,
+    students: usize,
+}
+
+impl Group {
+    fn add(&mut self, team_id: Uuid) {
+        self.teams.insert(team_id);
+        self.students += 1;
+    }
+
+    fn summarize(&self, scoreboard: &[Entry], positions: &HashMap<Uuid, usize>) -> GroupData {
+        let entries = self
+            .teams
+            .iter()
+            .filter_map(|team_id| positions.get(team_id))
+            .map(|&position| &scoreboard[position]);
+
+        let correct = entries.clone().map(|entry| entry.solved).sum::<i64>();
+
+        // The scoreboard is sorted, so the first position is the best
+        let best_team = entries
+            .min_by_key(|entry| positions[&entry.team_id])
+            .map(|entry| BestTeam {
+                team_id: entry.team_id,
+                name: entry.name.clone(),
+                rank: entry.rank,
+                points: entry.points,
+            });
+
+        GroupData {
+            teams: self.teams.len(),
+            students: self.students,
+            average_correct: correct as f64 / self.teams.len() as f64,
+            best_team,
+        }
+    }
+}
@@ -0,0 +1,199 @@
+use crate::{
+    StateTrait,
+    error::Result,
+    extractors::{Competition, Json, ValidatedJson},
+    utils::get_user_name,
+};
+use axum::extract::State;
+use chrono::{DateTime, Utc};
+use const_format::formatcp;
+use sea_orm::{ConnectionTrait, FromQueryResult, Statement, Value};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use uuid::Uuid;
+use validator::Validate;
+
+#[derive(Debug, Default, Clone, Copy, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum Order {
+    Asc,
+    #[default]
+    Desc,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct Request {
+    team: Option<Uuid>,
+    problem: Option<Uuid>,
+    user: Option<Uuid>,
+    /// Only the answers submitted at or after this time
+    from: Option<DateTime<Utc>>,
+    /// Only the answers submitted before this time
+    to: Option<DateTime<Utc>>,
+    /// The newest answers are first by default
+    #[serde(default)]
+    order: Order,
+    #[serde(default)]
+    page: u32,
+    #[serde(default = "default_per_page")]
+    #[validate(range(min = 1, max = 500))]
+    per_page: u32,
+}
+
+fn default_per_page() -> u32 {
+    50
+}
+
+#[derive(Debug, Serialize, FromQueryResult)]
+pub struct Entry {
+    id: Uuid,
+    created_at: DateTime<Utc>,
+    team_id: Uuid,
+    team_name: String,
+    problem: Uuid,
+    /// `None` if an admin set the answer
+    user: Option<Uuid>,
+    #[sea_orm(skip)]
+    user_name: Option<String>,
+    admin: Option<Uuid>,
+    #[sea_orm(skip)]
+    admin_name: Option<String>,
+    /// `None` if the answer was cleared
+    solution: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Response {
+    /// The number of answers matching the filters on every page
+    total: i64,
+    entries: Vec<Entry>,
+}
+
+#[derive(FromQueryResult)]
+struct Count {
+    total: i64,
+}
+
+/// Every answer change of the teams, including the excluded ones, with the member or the admin
+/// who made it.
+///
+/// The filters are optional, the answers are sorted by the time they were submitted.
+pub async fn get_history<S: StateTrait>(
+    State(state): State<S>,
+    competition: Competition,
+    ValidatedJson(request): ValidatedJson<Request>,
+) -> Result<Json<Response>> {
+    const FILTER: &str = r#"
+        from
+          solutions_history
+          inner join teams on teams.id = solutions_history.team
+        where
+          teams.competition = $1
+          and (
+            $2::uuid is null
+            or solutions_history.team = $2
+          )
+          and (
+            $3::uuid is null
+            or solutions_history.problem = $3
+          )
+          and (
+            $4::uuid is null
+            or solutions_history."user" = $4
+          )
+          and (
+            $5::timestamptz is null
+            or solutions_history.created_at::timestamptz >= $5
+          )
+          and (
+            $6::timestamptz is null
+            or solutions_history.created_at::timestamptz < $6
+          )
+        "#;
+
+    const COUNT_SQL: &str = formatcp!("select count(*) as total {FILTER}");
+
+    const SELECT_SQL: &str = formatcp!(
+        r#"
+        select
+          solutions_history.id,
+          solutions_history.created_at::timestamptz as created_at,
+          teams.id as team_id,
+          teams.name as team_name,
+          solutions_history.problem,
+          solutions_history."user",
+          solutions_history.admin,
+          solutions_history.solution
+        {FILTER}
+        "#
+    );
+
+    let db = state.db();
+
+    let filters: [Value; 6] = [
+        competition.id.into(),
+        request.team.into(),
+        request.problem.into(),
+        request.user.into(),
+        request.from.into(),
+        request.to.into(),
+    ];
+
+    let total = Count::find_by_statement(Statement::from_sql_and_values(
+        db.get_database_backend(),
+        COUNT_SQL,
+        filters.clone(),
+    ))
+    .one(db)
+    .await?
+    .map_or(0, |count| count.total);
+
+    let order = match request.order {
+        Order::Asc => "asc",
+        Order::Desc => "desc",
+    };
+
+    let mut values = filters.to_vec();
+    values.push(i64::from(request.per_page).into());
+    values.push((i64::from(request.page) * i64::from(request.per_page)).into());
+
+    let mut entries = Entry::find_by_statement(Statement::from_sql_and_values(
+        db.get_database_backend(),
+        format!(
+            "{SELECT_SQL} order by solutions_history.created_at {order}, \
+             solutions_history.id {order} limit $7 offset $8"
+        ),
+        values,
+    ))
+    .all(db)
+    .await?;
+
+    // A member usually has many answers, so every name is only requested once
+    let mut names = HashMap::<Uuid, String>::new();
+
+    for entry in &mut entries {
+        entry.user_name = find_name(&state, &mut names, entry.user).await?;
+        entry.admin_name = find_name(&state, &mut names, entry.admin).await?;
+    }
+
+    Ok(Json(Response { total, entries }))
+}
+
+async fn find_name<S: StateTrait>(
+    state: &S,
+    names: &mut HashMap<Uuid, String>,
+    user_id: Option<Uuid>,
+) -> Result<Option<String>> {
+    let Some(user_id) = user_id else {
+        return Ok(None);
+    };
+
+    if let Some(name) = names.get(&user_id) {
+        return Ok(Some(name.clone()));
+    }
+
+    let name = get_user_name(state, user_id).await?;
+    names.insert(user_id, name.clone());
+
+    Ok(Some(name))
+}
@@ -0,0 +1,142 @@
+mod get;
+mod groups;
+mod history;
+mod problems;
+mod report;
+pub mod scoreboard;
+mod timeline;
+
+use crate::{StateTrait, middlewares::PermissionsLayer};
+use axum::{
+    Router,
+    handler::Handler,
+    routing::{get, post},
+};
+
+/// Routes for statistics, nested under `/competition/{competition}`.
+/// The report is a page that can be opened in a browser, its `timestamp` and `division` are
+/// query parameters.
+///
+/// # Admin actions
+/// POST /stats
+/// POST /stats/groups
+/// POST /stats/history
+/// POST /stats/problems
+/// GET  /stats/report
+/// POST /stats/scoreboard
+/// POST /stats/timeline
+pub fn routes<S: StateTrait>(state: S) -> Router<S> {
+    Router::new()
+        .route(
+            "/",
+            post(get::get_stats::<S>.layer(PermissionsLayer::new(
+                state.clone(),
+                &["mathcompetition.admin"],
+            ))),
+        )
+        .route(
+            "/groups",
+            post(groups::get_group_stats::<S>.layer(PermissionsLayer::new(
+                state.clone(),
+                &["mathcompetition.admin"],
+            ))),
+        )
+        .route(
+            "/history",
+            post(history::get_history::<S>.layer(PermissionsLayer::new(
+                state.clone(),
+                &["mathcompetition.admin"],
+            ))),
+        )
+        .route(
+            "/problems",
+            post(
+                problems::get_problem_stats::<S>.layer(PermissionsLayer::new(
+                    state.clone(),
+                    &["mathcompetition.admin"],
+                )),
+            ),
+        )
+        .route(
+            "/report",
+            get(report::get_report::<S>.layer(PermissionsLayer::new(
+                state.clone(),
+                &["mathcompetition.admin"],
+            ))),
+        )
+        .route(
+            "/scoreboard",
+            post(scoreboard::get_scoreboard::<S>.layer(PermissionsLayer::new(
+                state.clone(),
+                &["mathcompetition.admin"],
+            ))),
+        )
+        .route(
+            "/timeline",
+            post(
+                timeline::get_timeline::<S>
+                    .layer(PermissionsLayer::new(state, &["mathcompetition.admin"])),
+            ),
+        )
+}
+
+/// Whether `latest.solution` is a correct answer for `problems`, null if there is no answer.
+///
+/// Besides the main solution every answer in `accepted_solutions` is correct too. Decimals are
+/// compared numerically with the tolerance, every other kind is normalized so they can be
+/// compared as text.
+const IS_CORRECT: &str = r"
+    (
+      case 
+        when latest.solution is null then null 
+        else exists (
+          select 
+            1 
+          from 
+            (
+              select 
+                problems.solution 
+              union all 
+              select 
+                accepted_solutions.solution 
+              from 
+                accepted_solutions 
+              where 
+                accepted_solutions.problem = problems.id
+            ) as accepted(solution) 
+          where 
+            case 
+              when problems.kind = 'decimal' then (
+                case 
+                  when latest.solution ~ '^-?[0-9]+(\.[0-9]+)?$' 
+                  and accepted.solution ~ '^-?[0-9]+(\.[0-9]+)?$' then abs(
+                    latest.solution::numeric - accepted.solution::numeric
+                  ) <= coalesce(problems.tolerance, 0)::numeric 
+                  else false 
+                end
+              ) 
+              else latest.solution = accepted.solution 
+            end
+        ) 
+      end
+    )
+";
+
+/// Latest answer of every team for every problem submitted before `$1`.
+///
+/// Columns: `team`, `problem`, `solution`, `created_at`
+const LATEST_SOLUTIONS: &str = "
+    select 
+      distinct on (team, problem) team, 
+      problem, 
+      solution, 
+      created_at 
+    from 
+      solutions_history 
+    where 
+      created_at < $1 
+    order by 
+      team, 
+      problem, 
+      created_at desc
+";
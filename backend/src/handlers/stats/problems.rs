@@ -0,0 +1,183 @@
+use super::{IS_CORRECT, LATEST_SOLUTIONS};
+use crate::{
+    StateTrait,
+    error::Result,
+    extractors::{Competition, Json},
+    utils::{get_division_teams, sort_linked},
+};
+use axum::extract::State;
+use chrono::{DateTime, Utc};
+use const_format::formatcp;
+use entity::{problems_order, times::constants::START_TIME};
+use sea_orm::{ColumnTrait, ConnectionTrait, EntityTrait, FromQueryResult, QueryFilter, Statement};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use uuid::Uuid;
+
+#[derive(Debug, Deserialize)]
+pub struct Request {
+    timestamp: Option<DateTime<Utc>>,
+    /// Only count the answers of the teams in this division
+    division: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ProblemData {
+    pub problem_id: Uuid,
+    /// Teams whose latest answer is not empty
+    pub answered: i64,
+    /// Teams whose latest answer is correct
+    pub correct: i64,
+    /// `correct / answered`, null if nobody answered
+    pub success_rate: Option<f64>,
+    /// Median of the seconds between the start time of the teams and their first correct answer
+    pub median_solve_seconds: Option<f64>,
+}
+
+pub type Response = Vec<ProblemData>;
+
+/// Difficulty of every problem in the order of the competition
+pub async fn get_problem_stats<S: StateTrait>(
+    State(state): State<S>,
+    competition: Competition,
+    Json(request): Json<Request>,
+) -> Result<Json<Response>> {
+    let timestamp = request.timestamp.unwrap_or_else(Utc::now);
+
+    let teams = match &request.division {
+        Some(division) => Some(get_division_teams(state.db(), &competition, division).await?),
+        None => None,
+    };
+
+    Ok(Json(
+        calculate(state.db(), competition.id, timestamp, teams.as_ref()).await?,
+    ))
+}
+
+/// Calculates the difficulty of the problems from the answers submitted before `timestamp`, only
+/// the answers of `teams` are counted if it's set
+pub async fn calculate(
+    db: &impl ConnectionTrait,
+    competition_id: Uuid,
+    timestamp: DateTime<Utc>,
+    teams: Option<&HashSet<Uuid>>,
+) -> Result<Response> {
+    const SQL: &str = formatcp!(
+        "
+        select 
+          problems.id, 
+          coalesce(answers.answered, 0) as answered, 
+          coalesce(answers.correct, 0) as correct, 
+          solve_times.median 
+        from 
+          problems 
+          left join (
+            select 
+              latest.problem, 
+              count(*) filter (
+                where 
+                  latest.solution is not null
+              ) as answered, 
+              count(*) filter (
+                where 
+                  {IS_CORRECT}
+              ) as correct 
+            from 
+              ({LATEST_SOLUTIONS}) as latest 
+              inner join problems on problems.id = latest.problem 
+              inner join teams on teams.id = latest.team 
+            where 
+              teams.exclusion is null 
+              and (
+                $3::jsonb is null 
+                or $3::jsonb ? teams.id::text
+              ) 
+            group by 
+              latest.problem
+          ) as answers on answers.problem = problems.id 
+          left join (
+            select 
+              first_correct.problem, 
+              percentile_cont(0.5) within group (
+                order by 
+                  extract(
+                    epoch 
+                    from 
+                      first_correct.created_at - coalesce(team_times.start_time, times.time)
+                  )::double precision
+              ) as median 
+            from 
+              (
+                select 
+                  latest.team, 
+                  latest.problem, 
+                  min(latest.created_at) as created_at 
+                from 
+                  solutions_history as latest 
+                  inner join problems on problems.id = latest.problem 
+                  inner join teams on teams.id = latest.team 
+                where 
+                  latest.created_at < $1 
+                  and teams.exclusion is null 
+                  and (
+                    $3::jsonb is null 
+                    or $3::jsonb ? teams.id::text
+                  ) 
+                  and {IS_CORRECT} 
+                group by 
+                  latest.team, 
+                  latest.problem
+              ) as first_correct 
+              left join team_times on team_times.team = first_correct.team 
+              inner join times on times.competition = $2 
+              and times.name = '{START_TIME}' 
+            group by 
+              first_correct.problem
+          ) as solve_times on solve_times.problem = problems.id 
+        where 
+          problems.competition = $2;
+        "
+    );
+
+    #[derive(FromQueryResult)]
+    struct Row {
+        id: Uuid,
+        answered: i64,
+        correct: i64,
+        median: Option<f64>,
+    }
+
+    let mut rows = Row::find_by_statement(Statement::from_sql_and_values(
+        db.get_database_backend(),
+        SQL,
+        [
+            timestamp.into(),
+            competition_id.into(),
+            teams.map(|teams| serde_json::json!(teams)).into(),
+        ],
+    ))
+    .all(db)
+    .await?
+    .into_iter()
+    .map(|row| (row.id, row))
+    .collect::<HashMap<_, _>>();
+
+    let order = problems_order::Entity::find()
+        .filter(problems_order::Column::Competition.eq(competition_id))
+        .all(db)
+        .await?;
+
+    let stats = sort_linked(order)
+        .into_iter()
+        .filter_map(|item| rows.remove(&item.id))
+        .map(|row| ProblemData {
+            problem_id: row.id,
+            answered: row.answered,
+            correct: row.correct,
+            success_rate: (row.answered > 0).then(|| row.correct as f64 / row.answered as f64),
+            median_solve_seconds: row.median,
+        })
+        .collect();
+
+    Ok(stats)
+}
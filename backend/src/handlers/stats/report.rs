@@ -0,0 +1,233 @@
+use super::{
+    groups::{self, GroupData},
+    problems, scoreboard,
+};
+use crate::{
+    StateTrait,
+    error::Result,
+    extractors::Competition,
+    utils::{get_division_teams, get_user_name},
+};
+use axum::{
+    extract::{Query, State},
+    response::Html,
+};
+use chrono::{DateTime, Utc};
+use entity::users;
+use serde::Deserialize;
+use std::fmt::Write;
+
+const STYLE: &str = "
+    body { font-family: sans-serif; margin: 2em; }
+    table { border-collapse: collapse; width: 100%; margin-bottom: 2em; }
+    th, td { border: 1px solid #999; padding: 0.3em 0.6em; text-align: left; }
+    th { background: #eee; }
+    td.number { text-align: right; }
+    @media print {
+      body { margin: 0; }
+      section { break-before: page; }
+      section:first-of-type { break-before: auto; }
+      tr { break-inside: avoid; }
+    }
+";
+
+#[derive(Debug, Deserialize)]
+pub struct ReportQuery {
+    timestamp: Option<DateTime<Utc>>,
+    /// Only the teams in this division
+    division: Option<String>,
+}
+
+/// A printable report with the ranking, the group stats and the problem stats.
+///
+/// The page has no external resources, so it can be saved as it is. Every section starts on a new
+/// page when it is printed, so browsers can turn it into a PDF. The parameters are in the query,
+/// so the report can be opened as a link.
+pub async fn get_report<S: StateTrait>(
+    State(state): State<S>,
+    competition: Competition,
+    Query(query): Query<ReportQuery>,
+) -> Result<Html<String>> {
+    let db = state.db();
+    let timestamp = query.timestamp.unwrap_or_else(Utc::now);
+
+    let division = query.division.as_deref();
+    let teams = match division {
+        Some(division) => Some(get_division_teams(db, &competition, division).await?),
+        None => None,
+    };
+
+    let scoreboard = scoreboard::calculate(db, competition.id, timestamp, division).await?;
+    let groups = groups::calculate(db, competition.id, timestamp, division).await?;
+    let problems = problems::calculate(db, competition.id, timestamp, teams.as_ref()).await?;
+
+    let mut html = String::new();
+    let title = match division {
+        Some(division) => escape(&format!("{} - {division}", competition.name)),
+        None => escape(&competition.name),
+    };
+
+    // Writing to a string can't fail
+    let _ = write!(
+        html,
+        "<!DOCTYPE html><html><head><meta charset=\"utf-8\"><title>{title}</title>\
+         <style>{STYLE}</style></head><body><h1>{title}</h1><p>{}</p>",
+        timestamp.format("%Y-%m-%d %H:%M UTC"),
+    );
+
+    html.push_str(
+        "<section><h2>Ranking</h2><table><tr><th>Rank</th><th>Team</th><th>Points</th>\
+         <th>Solved</th><th>Members</th></tr>",
+    );
+
+    for entry in &scoreboard {
+        let members = users::Entity::find_in_team(&entry.team_id).all(db).await?;
+
+        let mut names = Vec::with_capacity(members.len());
+
+        for member in members {
+            let name = get_user_name(&state, member.id).await?;
+            names.push(format!(
+                "{} ({}, {})",
+                escape(&name),
+                escape(&member.school),
+                member.class as i16
+            ));
+        }
+
+        let _ = write!(
+            html,
+            "<tr><td class=\"number\">{}</td><td>{}</td><td class=\"number\">{}</td>\
+             <td class=\"number\">{}</td><td>{}</td></tr>",
+            entry.rank,
+            escape(&entry.name),
+            entry.points,
+            entry.solved,
+            names.join("<br>"),
+        );
+    }
+
+    html.push_str("</table></section>");
+
+    html.push_str("<section><h2>Schools</h2>");
+    group_table(
+        &mut html,
+        "School",
+        groups
+            .schools
+            .iter()
+            .map(|school| (escape(&school.school), &school.data)),
+    );
+    html.push_str("<h2>Classes</h2>");
+    group_table(
+        &mut html,
+        "Class",
+        groups
+            .classes
+            .iter()
+            .map(|class| ((class.class.clone() as i16).to_string(), &class.data)),
+    );
+
+    if !groups.divisions.is_empty() {
+        html.push_str("<h2>Divisions</h2>");
+        group_table(
+            &mut html,
+            "Division",
+            groups
+                .divisions
+                .iter()
+                .map(|division| (escape(&division.division), &division.data)),
+        );
+    }
+
+    html.push_str("</section>");
+
+    html.push_str(
+        "<section><h2>Problems</h2><table><tr><th>#</th><th>Problem</th><th>Answered</th>\
+         <th>Correct</th><th>Success rate</th><th>Median solve time</th></tr>",
+    );
+
+    for (index, problem) in problems.iter().enumerate() {
+        let success_rate = problem
+            .success_rate
+            .map(|rate| format!("{:.0}%", rate * 100.0))
+            .unwrap_or_default();
+
+        let solve_time = problem
+            .median_solve_seconds
+            .map(|seconds| {
+                let seconds = seconds.round() as i64;
+                format!("{}:{:02}", seconds / 60, seconds % 60)
+            })
+            .unwrap_or_default();
+
+        let _ = write!(
+            html,
+            "<tr><td class=\"number\">{}</td><td>{}</td><td class=\"number\">{}</td>\
+             <td class=\"number\">{}</td><td class=\"number\">{success_rate}</td>\
+             <td class=\"number\">{solve_time}</td></tr>",
+            index + 1,
+            problem.problem_id,
+            problem.answered,
+            problem.correct,
+        );
+    }
+
+    html.push_str("</table></section></body></html>");
+
+    Ok(Html(html))
+}
+
+fn group_table<'a>(
+    html: &mut String,
+    header: &str,
+    groups: impl Iterator<Item = (String, &'a GroupData)>,
+) {
+    let _ = write!(
+        html,
+        "<table><tr><th>{header}</th><th>Teams</th><th>Students</th><th>Average correct</th>\
+         <th>Best team</th></tr>"
+    );
+
+    for (name, data) in groups {
+        let best_team = data
+            .best_team
+            .as_ref()
+            .map(|team| format!("{} ({}.)", escape(&team.name), team.rank))
+            .unwrap_or_default();
+
+        let _ = write!(
+            html,
+            "<tr><td>{name}</td><td class=\"number\">{}</td><td class=\"number\">{}</td>\
+             <td class=\"number\">{:.2}</td><td>{best_team}</td></tr>",
+            data.teams, data.students, data.average_correct,
+        );
+    }
+
+    html.push_str("</table>");
+}
+
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+
+    for char in text.chars() {
+        match char {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            char => escaped.push(char),
+        }
+    }
+
+    escaped
+}
+
+#[test]
+fn escape_html() {
+    assert_eq!(
+        escape("<b>Tom & \"Jerry\"</b>"),
+        "&lt;b&gt;Tom &amp; &quot;Jerry&quot;&lt;/b&gt;"
+    );
+}
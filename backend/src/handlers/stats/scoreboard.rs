@@ -0,0 +1,301 @@
+use super::{IS_CORRECT, LATEST_SOLUTIONS};
+use crate::{
+    StateTrait,
+    error::{self, Result},
+    extractors::{Competition, Json},
+    utils::get_team_divisions,
+};
+use axum::extract::State;
+use chrono::{DateTime, Utc};
+use const_format::formatcp;
+use entity::competitions::{self, TieBreaker};
+use sea_orm::{ConnectionTrait, EntityTrait, FromQueryResult, Statement};
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use uuid::Uuid;
+
+#[derive(Debug, Deserialize)]
+pub struct Request {
+    timestamp: Option<DateTime<Utc>>,
+    /// Only rank the teams in this division
+    division: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Entry {
+    pub rank: u64,
+    pub team_id: Uuid,
+    pub name: String,
+    pub points: i64,
+    pub solved: i64,
+    pub wrong_answers: i64,
+    /// When the last problem was made correct
+    pub last_correct_at: Option<DateTime<Utc>>,
+    /// The tie-breaker that put the team after the previous one with the same points
+    pub tie_break: Option<TieBreaker>,
+    pub division: Option<String>,
+}
+
+pub type Response = Vec<Entry>;
+
+pub async fn get_scoreboard<S: StateTrait>(
+    State(state): State<S>,
+    competition: Competition,
+    Json(request): Json<Request>,
+) -> Result<Json<Response>> {
+    let timestamp = request.timestamp.unwrap_or_else(Utc::now);
+
+    Ok(Json(
+        calculate(
+            state.db(),
+            competition.id,
+            timestamp,
+            request.division.as_deref(),
+        )
+        .await?,
+    ))
+}
+
+/// Calculates the ranked scoreboard of a competition from the answers submitted before `timestamp`.
+/// Every wrong answer a team submitted costs the `attempt_penalty` of the problem, the answers set
+/// by admins are not penalised.
+///
+/// Teams with the same amount of points are ordered by the tie-breakers of the competition, the
+/// deciding one is in `tie_break`. Teams that are equal by every tie-breaker share the same rank.
+///
+/// With a `division` only the teams in it are ranked.
+pub async fn calculate(
+    db: &impl ConnectionTrait,
+    competition_id: Uuid,
+    timestamp: DateTime<Utc>,
+    division: Option<&str>,
+) -> Result<Response> {
+    // A problem was made correct by the last answer that is correct while the previous one wasn't
+    const SQL: &str = formatcp!(
+        "
+        select 
+          teams.id, 
+          teams.name, 
+          coalesce(
+            sum(
+              case 
+                when {IS_CORRECT} then problems.points 
+                when latest.solution is not null then problems.wrong_points 
+                else 0 
+              end
+            ), 
+            0
+          ) + coalesce(penalties.penalty, 0) as points, 
+          count(*) filter (
+            where 
+              {IS_CORRECT}
+          ) as solved, 
+          coalesce(penalties.wrong_answers, 0) as wrong_answers, 
+          corrections.last_correct_at 
+        from 
+          teams 
+          left join ({LATEST_SOLUTIONS}) as latest on latest.team = teams.id 
+          left join problems on problems.id = latest.problem 
+          left join (
+            select 
+              latest.team, 
+              sum(problems.attempt_penalty) as penalty, 
+              count(*) as wrong_answers 
+            from 
+              solutions_history as latest 
+              inner join problems on problems.id = latest.problem 
+            where 
+              latest.created_at < $1 
+              and latest.admin is null 
+              and not {IS_CORRECT} 
+            group by 
+              latest.team
+          ) as penalties on penalties.team = teams.id 
+          left join (
+            select 
+              team, 
+              (
+                max(made_correct_at) filter (
+                  where 
+                    is_correct
+                )
+              )::timestamptz as last_correct_at 
+            from 
+              (
+                select 
+                  team, 
+                  problem, 
+                  max(created_at) filter (
+                    where 
+                      correct 
+                      and was_correct is not true
+                  ) as made_correct_at, 
+                  bool_or(correct) filter (
+                    where 
+                      recency = 1
+                  ) as is_correct 
+                from 
+                  (
+                    select 
+                      team, 
+                      problem, 
+                      created_at, 
+                      correct, 
+                      lag(correct) over (
+                        partition by team, 
+                        problem 
+                        order by 
+                          created_at
+                      ) as was_correct, 
+                      row_number() over (
+                        partition by team, 
+                        problem 
+                        order by 
+                          created_at desc
+                      ) as recency 
+                    from 
+                      (
+                        select 
+                          latest.team, 
+                          latest.problem, 
+                          latest.created_at, 
+                          {IS_CORRECT} as correct 
+                        from 
+                          solutions_history as latest 
+                          inner join problems on problems.id = latest.problem 
+                        where 
+                          latest.created_at < $1
+                      ) as graded
+                  ) as answers 
+                group by 
+                  team, 
+                  problem
+              ) as made_correct 
+            group by 
+              team
+          ) as corrections on corrections.team = teams.id 
+        where 
+          teams.competition = $2 
+          and teams.exclusion is null 
+        group by 
+          teams.id, 
+          teams.name, 
+          penalties.penalty, 
+          penalties.wrong_answers, 
+          corrections.last_correct_at 
+        order by 
+          points desc, 
+          solved desc, 
+          teams.name;
+        "
+    );
+
+    #[derive(FromQueryResult)]
+    struct Row {
+        id: Uuid,
+        name: String,
+        points: i64,
+        solved: i64,
+        wrong_answers: i64,
+        last_correct_at: Option<DateTime<Utc>>,
+    }
+
+    let competition = competitions::Entity::find_by_id(competition_id)
+        .one(db)
+        .await?;
+
+    let (tie_breakers, mut divisions) = match &competition {
+        Some(competition) => (
+            competition.tie_breakers.clone(),
+            get_team_divisions(db, competition).await?,
+        ),
+        None => Default::default(),
+    };
+
+    if let Some(division) = division {
+        let exists = competition.as_ref().is_some_and(|competition| {
+            competition
+                .divisions
+                .0
+                .iter()
+                .any(|other| other.name == division)
+        });
+
+        if !exists {
+            return Err(error::DIVISION_NOT_FOUND);
+        }
+    }
+
+    let res = Row::find_by_statement(Statement::from_sql_and_values(
+        db.get_database_backend(),
+        SQL,
+        [timestamp.into(), competition_id.into()],
+    ))
+    .all(db)
+    .await?;
+
+    let mut scoreboard = res
+        .into_iter()
+        .map(|row| (divisions.remove(&row.id), row))
+        .filter(|(team_division, _)| {
+            division.is_none_or(|division| team_division.as_deref() == Some(division))
+        })
+        .map(|(division, row)| Entry {
+            rank: 0,
+            team_id: row.id,
+            name: row.name,
+            points: row.points,
+            solved: row.solved,
+            wrong_answers: row.wrong_answers,
+            last_correct_at: row.last_correct_at,
+            tie_break: None,
+            division,
+        })
+        .collect::<Vec<_>>();
+
+    // The sort is stable, so equal teams keep the order of the query
+    scoreboard.sort_by(|a, b| {
+        b.points.cmp(&a.points).then_with(|| {
+            tie_breakers
+                .0
+                .iter()
+                .map(|&tie_breaker| compare(tie_breaker, a, b))
+                .find(|ordering| ordering.is_ne())
+                .unwrap_or(Ordering::Equal)
+        })
+    });
+
+    for index in 0..scoreboard.len() {
+        let (rank, tie_break) = match index.checked_sub(1).map(|last| &scoreboard[last]) {
+            Some(last) if last.points == scoreboard[index].points => {
+                let tie_break =
+                    tie_breakers.0.iter().copied().find(|&tie_breaker| {
+                        compare(tie_breaker, last, &scoreboard[index]).is_ne()
+                    });
+
+                match tie_break {
+                    Some(tie_break) => (index as u64 + 1, Some(tie_break)),
+                    None => (last.rank, None),
+                }
+            }
+            _ => (index as u64 + 1, None),
+        };
+
+        scoreboard[index].rank = rank;
+        scoreboard[index].tie_break = tie_break;
+    }
+
+    Ok(scoreboard)
+}
+
+/// Orders two teams by a tie-breaker, the better one is less
+fn compare(tie_breaker: TieBreaker, a: &Entry, b: &Entry) -> Ordering {
+    match tie_breaker {
+        // Teams without a correct answer are after every other team
+        TieBreaker::LastCorrectTime => match (a.last_correct_at, b.last_correct_at) {
+            (Some(a), Some(b)) => a.cmp(&b),
+            (a, b) => b.is_some().cmp(&a.is_some()),
+        },
+        TieBreaker::WrongAnswers => a.wrong_answers.cmp(&b.wrong_answers),
+    }
+}
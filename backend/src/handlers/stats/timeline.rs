@@ -0,0 +1,166 @@
+use super::IS_CORRECT;
+use crate::{
+    StateTrait,
+    error::{self, Result},
+    extractors::{Competition, Json, ValidatedJson},
+    utils::get_division_teams,
+};
+use axum::extract::State;
+use chrono::{DateTime, Utc};
+use const_format::formatcp;
+use sea_orm::{ConnectionTrait, FromQueryResult, Statement};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use validator::Validate;
+
+/// Largest number of buckets in one request
+const MAX_BUCKETS: i64 = 10_000;
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct Request {
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+    #[validate(range(min = 1))]
+    bucket_seconds: i64,
+    /// Only the teams in this division
+    division: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct TeamData {
+    team_id: Uuid,
+    correct: i64,
+    wrong: i64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Bucket {
+    timestamp: DateTime<Utc>,
+    teams: Vec<TeamData>,
+}
+
+pub type Response = Vec<Bucket>;
+
+/// The same as `POST /stats` for every `bucket_seconds` between `start` and `end`
+pub async fn get_timeline<S: StateTrait>(
+    State(state): State<S>,
+    competition: Competition,
+    ValidatedJson(request): ValidatedJson<Request>,
+) -> Result<Json<Response>> {
+    // Every answer is valid from its creation until the team changes it
+    const SQL: &str = formatcp!(
+        "
+        select 
+          buckets.bucket, 
+          teams.id, 
+          count(*) filter (
+            where 
+              {IS_CORRECT}
+          ) as correct, 
+          count(*) filter (
+            where 
+              not {IS_CORRECT}
+          ) as wrong 
+        from 
+          generate_series(
+            $1::timestamptz, $2::timestamptz, $3 * interval '1 second'
+          ) as buckets(bucket) 
+          cross join teams 
+          left join (
+            select 
+              team, 
+              problem, 
+              solution, 
+              created_at, 
+              lead(created_at) over (
+                partition by team, 
+                problem 
+                order by 
+                  created_at
+              ) as replaced_at 
+            from 
+              solutions_history 
+            where 
+              created_at < $2
+          ) as latest on latest.team = teams.id 
+          and latest.created_at < buckets.bucket 
+          and (
+            latest.replaced_at is null 
+            or latest.replaced_at >= buckets.bucket
+          ) 
+          left join problems on problems.id = latest.problem 
+        where 
+          teams.competition = $4 
+          and teams.exclusion is null 
+        group by 
+          buckets.bucket, 
+          teams.id 
+        order by 
+          buckets.bucket, 
+          teams.id;
+        "
+    );
+
+    if request.end < request.start
+        || (request.end - request.start).num_seconds() / request.bucket_seconds >= MAX_BUCKETS
+    {
+        return Err(error::JSON_VALIDATE_INVALID);
+    }
+
+    #[derive(FromQueryResult)]
+    struct Row {
+        bucket: DateTime<Utc>,
+        id: Uuid,
+        correct: i64,
+        wrong: i64,
+    }
+
+    let db = state.db();
+
+    let rows = Row::find_by_statement(Statement::from_sql_and_values(
+        db.get_database_backend(),
+        SQL,
+        [
+            request.start.into(),
+            request.end.into(),
+            request.bucket_seconds.into(),
+            competition.id.into(),
+        ],
+    ))
+    .all(db)
+    .await?;
+
+    let teams = match &request.division {
+        Some(division) => Some(get_division_teams(db, &competition, division).await?),
+        None => None,
+    };
+
+    let mut buckets = Vec::<Bucket>::new();
+
+    for row in rows {
+        if buckets
+            .last()
+            .is_none_or(|bucket| bucket.timestamp != row.bucket)
+        {
+            buckets.push(Bucket {
+                timestamp: row.bucket,
+                teams: Vec::new(),
+            });
+        }
+
+        if teams.as_ref().is_some_and(|teams| !teams.contains(&row.id)) {
+            continue;
+        }
+
+        // There is always a bucket, it was pushed above
+        if let Some(bucket) = buckets.last_mut() {
+            bucket.teams.push(TeamData {
+                team_id: row.id,
+                correct: row.correct,
+                wrong: row.wrong,
+            });
+        }
+    }
+
+    Ok(Json(buckets))
+}
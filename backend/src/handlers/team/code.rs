@@ -1,21 +1,37 @@
+use super::join_codes::is_taken;
 use crate::{
     StateTrait,
     error::{self, DatabaseError, Result},
-    extractors::UserID,
+    extractors::{Competition, UserID},
     handlers::socket::Event,
     utils::{self, topics},
 };
-use axum::{extract::State, http::StatusCode};
+use axum::{
+    extract::{Query, State},
+    http::StatusCode,
+    response::Response,
+};
 use entity::teams::{self, constrains::*};
 use sea_orm::{EntityTrait, IntoActiveModel, QuerySelect, Set, TransactionTrait};
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+pub struct QrCodeQuery {
+    #[serde(default)]
+    format: utils::QrCodeFormat,
+    /// Encode a link to the frontend with the code instead of the bare code
+    #[serde(default)]
+    link: bool,
+}
 
 pub async fn regenerate_code<S: StateTrait>(
     State(state): State<S>,
+    competition: Competition,
     user_id: UserID,
 ) -> Result<StatusCode> {
     let txn = state.db().begin().await?;
 
-    let team = teams::Entity::find_from_member(&user_id)
+    let team = teams::Entity::find_from_member(&competition.id, &user_id)
         .lock_exclusive()
         .one(&txn)
         .await?
@@ -29,12 +45,16 @@ pub async fn regenerate_code<S: StateTrait>(
         return Err(error::LOCKED_TEAM);
     }
 
-    let topic = topics::team_info(&team.id);
+    let topic = topics::team_info(&competition.id, &team.id);
     let model = team.into_active_model();
 
     for _ in 0..16 {
         let new_code = utils::generate_join_code(&mut state.rng());
 
+        if is_taken(&txn, &new_code).await? {
+            continue;
+        }
+
         let mut model = model.clone();
         model.join_code = Set(new_code.clone());
 
@@ -50,6 +70,7 @@ pub async fn regenerate_code<S: StateTrait>(
             owner: None,
             co_owner: None,
             locked: None,
+            require_approval: None,
             code: Some(new_code),
         })
         .unwrap();
@@ -63,3 +84,27 @@ pub async fn regenerate_code<S: StateTrait>(
 
     Err(error::FAILED_TO_GENERATE_JOIN_CODE)
 }
+
+/// Renders the current join code of the team as a QR code, for scanning it at on-site events
+pub async fn get_code_qr<S: StateTrait>(
+    State(state): State<S>,
+    competition: Competition,
+    user_id: UserID,
+    Query(query): Query<QrCodeQuery>,
+) -> Result<Response> {
+    let team = teams::Entity::find_from_member(&competition.id, &user_id)
+        .one(state.db())
+        .await?
+        .ok_or(error::USER_NOT_IN_TEAM)?;
+
+    let data = if query.link {
+        state
+            .join_link()
+            .ok_or(error::JOIN_LINK_NOT_CONFIGURED)?
+            .replace("{code}", &team.join_code)
+    } else {
+        team.join_code
+    };
+
+    utils::render_qr_code(&data, query.format)
+}
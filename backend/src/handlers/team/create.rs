@@ -1,7 +1,8 @@
+use super::{join::delete_pending, join_codes::is_taken};
 use crate::{
     StateTrait,
     error::{self, DatabaseError, Result},
-    extractors::{UserID, ValidatedJson},
+    extractors::{Competition, UserID, ValidatedJson},
     utils::generate_join_code,
 };
 use axum::{extract::State, http::StatusCode};
@@ -23,6 +24,7 @@ pub struct Request {
 
 pub async fn create_team<S: StateTrait>(
     State(state): State<S>,
+    competition: Competition,
     user_id: UserID,
     ValidatedJson(request): ValidatedJson<Request>,
 ) -> Result<StatusCode> {
@@ -40,6 +42,7 @@ pub async fn create_team<S: StateTrait>(
 
     let team = teams::ActiveModel {
         id: Set(Uuid::new_v4()),
+        competition: Set(competition.id),
         name: Set(request.name),
         owner: Set(user.id),
         locked: Set(false),
@@ -47,9 +50,15 @@ pub async fn create_team<S: StateTrait>(
     };
 
     for _ in 0..16 {
+        let join_code = generate_join_code(&mut state.rng());
+
+        if is_taken(&txn, &join_code).await? {
+            continue;
+        }
+
         let team_model = {
             let mut model = team.clone();
-            model.join_code = Set(generate_join_code(&mut state.rng()));
+            model.join_code = Set(join_code);
             model
         };
 
@@ -64,6 +73,7 @@ pub async fn create_team<S: StateTrait>(
         let team_member_model = team_members::ActiveModel {
             user_id: Set(user.id),
             team_id: Set(result.last_insert_id),
+            competition: Set(competition.id),
         };
 
         match team_members::Entity::insert(team_member_model)
@@ -76,6 +86,8 @@ pub async fn create_team<S: StateTrait>(
             r => r?,
         };
 
+        delete_pending(&state, &txn, competition.id, user.id).await?;
+
         txn.commit().await?;
 
         return Ok(StatusCode::CREATED);
@@ -1,21 +1,23 @@
+use super::invitation::publish_deleted;
 use crate::{
     StateTrait,
     error::{self, Result},
-    extractors::UserID,
+    extractors::{Competition, UserID},
     handlers::socket::Event,
     utils::topics,
 };
 use axum::{extract::State, http::StatusCode};
-use entity::{team_members, teams};
+use entity::{team_invitations, team_members, teams};
 use sea_orm::{ColumnTrait, EntityTrait, QueryFilter, QuerySelect, TransactionTrait};
 
 pub async fn disband_team<S: StateTrait>(
     State(state): State<S>,
+    competition: Competition,
     user_id: UserID,
 ) -> Result<StatusCode> {
     let txn = state.db().begin().await?;
 
-    let team = teams::Entity::find_from_member(&user_id)
+    let team = teams::Entity::find_from_member(&competition.id, &user_id)
         .lock_exclusive()
         .one(&txn)
         .await?
@@ -29,6 +31,12 @@ pub async fn disband_team<S: StateTrait>(
         return Err(error::LOCKED_TEAM);
     }
 
+    // The invitations are deleted with the team, the invited users have to be told about it
+    let invitations = team_invitations::Entity::find()
+        .filter(team_invitations::Column::Team.eq(team.id))
+        .all(&txn)
+        .await?;
+
     team_members::Entity::delete_many()
         .filter(team_members::Column::TeamId.eq(team.id))
         .exec(&txn)
@@ -39,11 +47,15 @@ pub async fn disband_team<S: StateTrait>(
     state
         .nats()
         .publish(
-            topics::team_info(&team.id),
+            topics::team_info(&competition.id, &team.id),
             serde_json::to_vec(&Event::DisbandTeam).unwrap().into(),
         )
         .await?;
 
+    for invitation in &invitations {
+        publish_deleted(&state, competition.id, invitation).await?;
+    }
+
     txn.commit().await?;
     Ok(StatusCode::NO_CONTENT)
 }
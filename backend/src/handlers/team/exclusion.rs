@@ -0,0 +1,107 @@
+use super::time::find_team;
+use crate::{
+    StateTrait,
+    error::Result,
+    extractors::{Competition, ValidatedJson},
+    handlers::socket::Event,
+    utils::topics,
+};
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+};
+use entity::teams::Exclusion;
+use sea_orm::{ActiveModelTrait, IntoActiveModel, Set, TransactionTrait};
+use serde::Deserialize;
+use uuid::Uuid;
+use validator::Validate;
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct Request {
+    kind: Exclusion,
+    #[validate(length(min = 1, max = 1024))]
+    reason: String,
+}
+
+/// Leaves a team out of the rankings, its answers are kept
+pub async fn set_exclusion<S: StateTrait>(
+    State(state): State<S>,
+    competition: Competition,
+    Path((_, team_id)): Path<(Uuid, Uuid)>,
+    ValidatedJson(request): ValidatedJson<Request>,
+) -> Result<StatusCode> {
+    let txn = state.db().begin().await?;
+
+    let mut team = find_team(&txn, &competition.id, team_id)
+        .await?
+        .into_active_model();
+    team.exclusion = Set(Some(request.kind));
+    team.exclusion_reason = Set(Some(request.reason.clone()));
+    team.update(&txn).await?;
+
+    txn.commit().await?;
+
+    publish_exclusion(
+        &state,
+        competition.id,
+        team_id,
+        Some(request.kind),
+        Some(request.reason),
+    )
+    .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Ranks an excluded team again
+pub async fn delete_exclusion<S: StateTrait>(
+    State(state): State<S>,
+    competition: Competition,
+    Path((_, team_id)): Path<(Uuid, Uuid)>,
+) -> Result<StatusCode> {
+    let txn = state.db().begin().await?;
+
+    let mut team = find_team(&txn, &competition.id, team_id)
+        .await?
+        .into_active_model();
+    team.exclusion = Set(None);
+    team.exclusion_reason = Set(None);
+    team.update(&txn).await?;
+
+    txn.commit().await?;
+
+    publish_exclusion(&state, competition.id, team_id, None, None).await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Tells the team about the change, and the scoreboards to leave it out or include it again
+async fn publish_exclusion<S: StateTrait>(
+    state: &S,
+    competition_id: Uuid,
+    team_id: Uuid,
+    exclusion: Option<Exclusion>,
+    reason: Option<String>,
+) -> Result<()> {
+    let payload = serde_json::to_vec(&Event::UpdateExclusion {
+        team: team_id,
+        exclusion,
+        reason,
+    })
+    .unwrap();
+
+    state
+        .nats()
+        .publish(
+            topics::team_info(&competition_id, &team_id),
+            payload.clone().into(),
+        )
+        .await?;
+
+    state
+        .nats()
+        .publish(topics::rescoring(&competition_id), payload.into())
+        .await?;
+
+    Ok(())
+}
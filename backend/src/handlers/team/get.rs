@@ -1,7 +1,14 @@
-use crate::{StateTrait, error::Result, extractors::Json};
+use crate::{
+    StateTrait,
+    error::Result,
+    extractors::{Competition, Json},
+};
 use axum::extract::State;
-use entity::{teams, users};
-use sea_orm::{EntityTrait, FromQueryResult, TransactionTrait};
+use entity::{
+    teams::{self, Exclusion},
+    users,
+};
+use sea_orm::{FromQueryResult, TransactionTrait};
 use serde::Serialize;
 use uuid::Uuid;
 
@@ -19,16 +26,24 @@ pub struct Team {
     owner: Uuid,
     co_owner: Option<Uuid>,
     locked: bool,
+    require_approval: bool,
     join_code: String,
     members: Vec<Member>,
+    exclusion: Option<Exclusion>,
+    exclusion_reason: Option<String>,
 }
 
 pub type Response = Json<Vec<Team>>;
 
-pub async fn get_all_teams<S: StateTrait>(State(state): State<S>) -> Result<Response> {
+pub async fn get_all_teams<S: StateTrait>(
+    State(state): State<S>,
+    competition: Competition,
+) -> Result<Response> {
     let txn = state.db().begin().await?;
 
-    let teams = teams::Entity::find().all(&txn).await?;
+    let teams = teams::Entity::find_in_competition(&competition.id)
+        .all(&txn)
+        .await?;
 
     let mut response = Vec::with_capacity(teams.len());
 
@@ -44,8 +59,11 @@ pub async fn get_all_teams<S: StateTrait>(State(state): State<S>) -> Result<Resp
             owner: team.owner,
             co_owner: team.co_owner,
             locked: team.locked,
+            require_approval: team.require_approval,
             join_code: team.join_code,
             members,
+            exclusion: team.exclusion,
+            exclusion_reason: team.exclusion_reason,
         })
     }
 
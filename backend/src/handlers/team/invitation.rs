@@ -0,0 +1,376 @@
+use super::join::{add_member, ensure_team_not_full, find_coowned_team};
+use crate::{
+    StateTrait,
+    error::{self, Result},
+    extractors::{Competition, Json, UserID},
+    handlers::socket::{Event, authenticate},
+    utils::{get_user_name, topics},
+};
+use axum::{
+    extract::{
+        Path, State,
+        ws::{CloseFrame, Message, WebSocket, WebSocketUpgrade, close_code},
+    },
+    http::StatusCode,
+    response::IntoResponse,
+};
+use chrono::{DateTime, Duration, Utc};
+use entity::{team_invitations, teams, users};
+use futures::StreamExt;
+use sea_orm::{
+    ColumnTrait, EntityTrait, FromQueryResult, JoinType, QueryFilter, QueryOrder, QuerySelect,
+    RelationTrait, Set, TransactionTrait, sea_query::OnConflict,
+};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// How long an invitation can be accepted
+const INVITATION_DAYS: i64 = 3;
+
+#[derive(Deserialize)]
+pub struct Request {
+    user: Uuid,
+}
+
+#[derive(Debug, Serialize, FromQueryResult)]
+pub struct Invitation {
+    id: Uuid,
+    team: Uuid,
+    team_name: String,
+    invited_by: Uuid,
+    #[sea_orm(skip)]
+    invited_by_name: String,
+    expires_at: DateTime<Utc>,
+}
+
+/// Invites a registered user into the team, or renews the invitation if there is one
+pub async fn invite_user<S: StateTrait>(
+    State(state): State<S>,
+    competition: Competition,
+    user_id: UserID,
+    Json(request): Json<Request>,
+) -> Result<StatusCode> {
+    let txn = state.db().begin().await?;
+
+    let team = find_coowned_team(&txn, &competition.id, &user_id).await?;
+
+    if team.locked {
+        return Err(error::LOCKED_TEAM);
+    }
+
+    ensure_team_not_full(&txn, &team).await?;
+
+    let user = users::Entity::find_by_id(request.user)
+        .one(&txn)
+        .await?
+        .ok_or(error::USER_NOT_REGISTERED)?;
+
+    if teams::Entity::find_from_member(&competition.id, &user.id)
+        .one(&txn)
+        .await?
+        .is_some()
+    {
+        return Err(error::ALREADY_IN_TEAM);
+    }
+
+    let expires_at = Utc::now() + Duration::days(INVITATION_DAYS);
+
+    let invitation = team_invitations::Entity::insert(team_invitations::ActiveModel {
+        id: Set(Uuid::new_v4()),
+        team: Set(team.id),
+        user: Set(user.id),
+        invited_by: Set(*user_id),
+        expires_at: Set(expires_at),
+    })
+    .on_conflict(
+        OnConflict::columns([
+            team_invitations::Column::Team,
+            team_invitations::Column::User,
+        ])
+        .update_columns([
+            team_invitations::Column::InvitedBy,
+            team_invitations::Column::ExpiresAt,
+        ])
+        .to_owned(),
+    )
+    .exec_with_returning(&txn)
+    .await?;
+
+    let name = get_user_name(&state, user.id).await?;
+    let invited_by_name = get_user_name(&state, *user_id).await?;
+
+    state
+        .nats()
+        .publish(
+            topics::team_info(&competition.id, &team.id),
+            serde_json::to_vec(&Event::InviteUser {
+                user: user.id,
+                name,
+                expires_at,
+            })
+            .unwrap()
+            .into(),
+        )
+        .await?;
+
+    state
+        .nats()
+        .publish(
+            topics::user_invitations(&competition.id, &user.id),
+            serde_json::to_vec(&Event::ReceiveInvitation {
+                id: invitation.id,
+                team: team.id,
+                team_name: team.name,
+                invited_by: *user_id,
+                invited_by_name,
+                expires_at,
+            })
+            .unwrap()
+            .into(),
+        )
+        .await?;
+
+    txn.commit().await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Takes back the invitation of a user
+pub async fn cancel_invitation<S: StateTrait>(
+    State(state): State<S>,
+    competition: Competition,
+    user_id: UserID,
+    Json(request): Json<Request>,
+) -> Result<StatusCode> {
+    let txn = state.db().begin().await?;
+
+    let team = find_coowned_team(&txn, &competition.id, &user_id).await?;
+
+    let invitation = team_invitations::Entity::find()
+        .filter(team_invitations::Column::Team.eq(team.id))
+        .filter(team_invitations::Column::User.eq(request.user))
+        .one(&txn)
+        .await?
+        .ok_or(error::INVITATION_NOT_FOUND)?;
+
+    team_invitations::Entity::delete_by_id(invitation.id)
+        .exec(&txn)
+        .await?;
+
+    publish_deleted(&state, competition.id, &invitation).await?;
+
+    txn.commit().await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// The invitations of the user that can be accepted
+pub async fn list_invitations<S: StateTrait>(
+    State(state): State<S>,
+    competition: Competition,
+    user_id: UserID,
+) -> Result<Json<Vec<Invitation>>> {
+    Ok(Json(
+        find_invitations(&state, competition.id, *user_id).await?,
+    ))
+}
+
+/// Pushes the invitations of a user who is not in a team yet.
+///
+/// After sending `{"token": "..."}` the user gets `RECEIVE_INVITATION` for every pending
+/// invitation, then `RECEIVE_INVITATION` and `REMOVE_INVITATION` as they change. The invitations
+/// are not removed when they expire, the client has to check `expires_at`.
+pub async fn invitations_ws<S: StateTrait>(
+    State(state): State<S>,
+    competition: Competition,
+    ws: WebSocketUpgrade,
+) -> impl IntoResponse {
+    ws.on_upgrade(move |mut socket: WebSocket| async move {
+        if let Err(err) = invitations_handler(state, competition.id, &mut socket).await {
+            // it's okay to ignore the error here
+            let _ = socket
+                .send(Message::Close(Some(CloseFrame {
+                    code: close_code::ERROR,
+                    reason: err.to_bytes().try_into().unwrap(),
+                })))
+                .await;
+
+            socket.next().await;
+            warn!("invitations socket ended with error: {:?}", err);
+        }
+    })
+}
+
+async fn invitations_handler<S: StateTrait>(
+    state: S,
+    competition_id: Uuid,
+    socket: &mut WebSocket,
+) -> Result<()> {
+    let user_id = authenticate(&state, socket).await?;
+
+    // Subscribe before loading the invitations, so none of them are missed
+    let mut events = state
+        .nats()
+        .subscribe(topics::user_invitations(&competition_id, &user_id))
+        .await?;
+
+    for invitation in find_invitations(&state, competition_id, *user_id).await? {
+        let event = Event::ReceiveInvitation {
+            id: invitation.id,
+            team: invitation.team,
+            team_name: invitation.team_name,
+            invited_by: invitation.invited_by,
+            invited_by_name: invitation.invited_by_name,
+            expires_at: invitation.expires_at,
+        };
+
+        send_event(socket, serde_json::to_string(&event).unwrap()).await?;
+    }
+
+    loop {
+        tokio::select! {
+            Some(message) = events.next() => {
+                send_event(socket, String::from_utf8_lossy(&message.payload).into_owned()).await?;
+            }
+            message = socket.next() => {
+                match message {
+                    Some(Ok(Message::Close(_))) | None => break Ok(()),
+                    Some(Ok(_)) => {
+                        warn!("got message on invitations websocket");
+                    }
+                    Some(Err(err)) => {
+                        error!("websocket error: {:?}", err);
+                        return Err(error::WEBSOCKET_ERROR)
+                    }
+                }
+            }
+        }
+    }
+}
+
+async fn send_event(socket: &mut WebSocket, payload: String) -> Result<()> {
+    socket
+        .send(Message::Text(payload.into()))
+        .await
+        .map_err(|err| {
+            error!("websocket error: {:?}", err);
+            error::WEBSOCKET_ERROR
+        })
+}
+
+async fn find_invitations<S: StateTrait>(
+    state: &S,
+    competition_id: Uuid,
+    user_id: Uuid,
+) -> Result<Vec<Invitation>> {
+    let mut invitations =
+        team_invitations::Entity::find_pending_for_user(&competition_id, &user_id, Utc::now())
+            .select_only()
+            .column(team_invitations::Column::Id)
+            .column(team_invitations::Column::Team)
+            .column_as(teams::Column::Name, "team_name")
+            .column(team_invitations::Column::InvitedBy)
+            .column(team_invitations::Column::ExpiresAt)
+            .order_by_asc(team_invitations::Column::ExpiresAt)
+            .into_model::<Invitation>()
+            .all(state.db())
+            .await?;
+
+    for invitation in &mut invitations {
+        invitation.invited_by_name = get_user_name(state, invitation.invited_by).await?;
+    }
+
+    Ok(invitations)
+}
+
+/// Joins the team of the invitation
+pub async fn accept_invitation<S: StateTrait>(
+    State(state): State<S>,
+    competition: Competition,
+    user_id: UserID,
+    Path((_, invitation_id)): Path<(Uuid, Uuid)>,
+) -> Result<StatusCode> {
+    let txn = state.db().begin().await?;
+
+    let invitation = team_invitations::Entity::find_by_id(invitation_id)
+        .filter(team_invitations::Column::User.eq(*user_id))
+        .one(&txn)
+        .await?
+        .ok_or(error::INVITATION_NOT_FOUND)?;
+
+    let team = teams::Entity::find_in_competition(&competition.id)
+        .filter(teams::Column::Id.eq(invitation.team))
+        .lock_exclusive()
+        .one(&txn)
+        .await?
+        .ok_or(error::INVITATION_NOT_FOUND)?;
+
+    if invitation.expires_at <= Utc::now() {
+        return Err(error::INVITATION_EXPIRED);
+    }
+
+    add_member(&state, &txn, &team, *user_id).await?;
+
+    txn.commit().await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Deletes the invitation without joining the team
+pub async fn decline_invitation<S: StateTrait>(
+    State(state): State<S>,
+    competition: Competition,
+    user_id: UserID,
+    Path((_, invitation_id)): Path<(Uuid, Uuid)>,
+) -> Result<StatusCode> {
+    let txn = state.db().begin().await?;
+
+    let invitation = team_invitations::Entity::find_by_id(invitation_id)
+        .join(JoinType::InnerJoin, team_invitations::Relation::Team.def())
+        .filter(team_invitations::Column::User.eq(*user_id))
+        .filter(teams::Column::Competition.eq(competition.id))
+        .one(&txn)
+        .await?
+        .ok_or(error::INVITATION_NOT_FOUND)?;
+
+    team_invitations::Entity::delete_by_id(invitation.id)
+        .exec(&txn)
+        .await?;
+
+    publish_deleted(&state, competition.id, &invitation).await?;
+
+    txn.commit().await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Tells the team and the invited user that the invitation is gone
+pub(super) async fn publish_deleted<S: StateTrait>(
+    state: &S,
+    competition_id: Uuid,
+    invitation: &team_invitations::Model,
+) -> Result<()> {
+    state
+        .nats()
+        .publish(
+            topics::team_info(&competition_id, &invitation.team),
+            serde_json::to_vec(&Event::DeleteInvitation {
+                user: invitation.user,
+            })
+            .unwrap()
+            .into(),
+        )
+        .await?;
+
+    state
+        .nats()
+        .publish(
+            topics::user_invitations(&competition_id, &invitation.user),
+            serde_json::to_vec(&Event::RemoveInvitation { id: invitation.id })
+                .unwrap()
+                .into(),
+        )
+        .await?;
+
+    Ok(())
+}
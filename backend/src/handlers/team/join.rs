@@ -1,102 +1,312 @@
+use super::{
+    invitation::publish_deleted,
+    join_codes::{count_use, find_join_code},
+};
 use crate::{
     Result, StateTrait,
     error::{self, DatabaseError as _},
-    extractors::{Json, UserID},
+    extractors::{Competition, Json, UserID},
     handlers::socket::Event,
-    utils::topics,
+    utils::{get_user_name, topics},
 };
 use axum::{extract::State, http::StatusCode};
+use chrono::Utc;
 use entity::{
+    competitions, team_invitations, team_join_requests,
     team_members::{self, constraints::*},
     teams, users,
 };
 use sea_orm::{
-    ColumnTrait, EntityTrait, PaginatorTrait, QueryFilter, QuerySelect, Set, TransactionTrait,
+    ColumnTrait, ConnectionTrait, DatabaseTransaction, EntityTrait, JoinType, PaginatorTrait,
+    QueryFilter, QuerySelect, RelationTrait, Set, TransactionTrait,
 };
 use serde::Deserialize;
-
-const MAX_TEAM_SIZE: u64 = 8;
+use uuid::Uuid;
 
 #[derive(Deserialize)]
 pub struct Request {
     code: String,
 }
 
+/// Joins the team with the permanent or an extra code, or creates a join request if the team needs approval.
+///
+/// Returns `202 Accepted` if the user has to wait for the approval. The use of an extra code is
+/// only counted when the user gets into the team.
 pub async fn join_team<S: StateTrait>(
     State(state): State<S>,
+    competition: Competition,
     user_id: UserID,
     Json(request): Json<Request>,
 ) -> Result<StatusCode> {
     let txn = state.db().begin().await?;
 
-    let team = teams::Entity::find_by_join_code(&request.code)
+    let (team, join_code) = match teams::Entity::find_by_join_code(&competition.id, &request.code)
         .lock_exclusive()
         .one(&txn)
+        .await?
+    {
+        Some(team) => (team, None),
+        None => {
+            let (team, join_code) = find_join_code(&txn, &competition.id, &request.code).await?;
+            (team, Some(join_code))
+        }
+    };
+
+    if team.require_approval {
+        let join_code = join_code.map(|join_code| join_code.code);
+        request_join(&state, &txn, &team, *user_id, join_code).await?;
+        txn.commit().await?;
+
+        return Ok(StatusCode::ACCEPTED);
+    }
+
+    add_member(&state, &txn, &team, *user_id).await?;
+
+    if let Some(join_code) = join_code {
+        count_use(&txn, join_code).await?;
+    }
+
+    txn.commit().await?;
+
+    Ok(StatusCode::OK)
+}
+
+/// Creates a join request, a repeated request is ignored
+async fn request_join<S: StateTrait>(
+    state: &S,
+    txn: &DatabaseTransaction,
+    team: &teams::Model,
+    user_id: Uuid,
+    join_code: Option<String>,
+) -> Result<()> {
+    if team.locked {
+        return Err(error::LOCKED_TEAM);
+    }
+
+    ensure_team_not_full(txn, team).await?;
+
+    let user = users::Entity::find_by_id(user_id)
+        .one(txn)
+        .await?
+        .ok_or(error::USER_NOT_REGISTERED)?;
+
+    if teams::Entity::find_from_member(&team.competition, &user.id)
+        .one(txn)
+        .await?
+        .is_some()
+    {
+        return Err(error::ALREADY_IN_TEAM);
+    }
+
+    // The team is locked, so the request can't be created concurrently
+    if team_join_requests::Entity::find_by_id((team.id, user.id))
+        .one(txn)
+        .await?
+        .is_some()
+    {
+        return Ok(());
+    }
+
+    let created_at = Utc::now();
+
+    team_join_requests::Entity::insert(team_join_requests::ActiveModel {
+        team: Set(team.id),
+        user: Set(user.id),
+        created_at: Set(created_at),
+        join_code: Set(join_code),
+    })
+    .exec(txn)
+    .await?;
+
+    let name = get_user_name(state, user.id).await?;
+
+    state
+        .nats()
+        .publish(
+            topics::team_info(&team.competition, &team.id),
+            serde_json::to_vec(&Event::RequestJoin {
+                user: user.id,
+                name,
+                created_at,
+            })
+            .unwrap()
+            .into(),
+        )
         .await?;
 
-    if let Some(team) = team {
-        if team.locked {
-            return Err(error::LOCKED_TEAM);
-        }
+    Ok(())
+}
 
-        let member_count = team_members::Entity::find()
-            .filter(team_members::Column::TeamId.eq(team.id))
-            .count(&txn)
-            .await?;
+/// Adds a user to a locked row of a team, if the team is not locked and not full.
+///
+/// Every way into a team goes through this, so they all have the same rules. The other
+/// invitations and join requests of the user are deleted, because a user can only be in one team.
+pub(super) async fn add_member<S: StateTrait>(
+    state: &S,
+    txn: &DatabaseTransaction,
+    team: &teams::Model,
+    user_id: Uuid,
+) -> Result<()> {
+    if team.locked {
+        return Err(error::LOCKED_TEAM);
+    }
 
-        if member_count >= MAX_TEAM_SIZE {
-            return Err(error::TEAM_FULL);
-        }
+    ensure_team_not_full(txn, team).await?;
+
+    let user = users::Entity::find_by_id(user_id)
+        .lock_exclusive()
+        .one(txn)
+        .await?
+        .ok_or_else(|| {
+            // this is suspicious so log it
+            warn!("tried to join team without registration");
+            error::USER_NOT_REGISTERED
+        })?;
+
+    let active_model = team_members::ActiveModel {
+        user_id: Set(user.id),
+        team_id: Set(team.id),
+        competition: Set(team.competition),
+    };
+
+    let result = team_members::Entity::insert(active_model).exec(txn).await;
 
-        let user = users::Entity::find_by_id(*user_id)
-            .lock_exclusive()
-            .one(&txn)
-            .await?
-            .ok_or_else(|| {
-                // this is suspicious so log it
-                warn!("tried to join team without registration");
-                error::USER_NOT_REGISTERED
-            })?;
-
-        let active_model = team_members::ActiveModel {
-            user_id: Set(user.id),
-            team_id: Set(team.id),
-        };
-
-        let result = team_members::Entity::insert(active_model).exec(&txn).await;
-
-        if let Err(err) = result {
-            if err.unique_violation(UC_TEAM_MEMBERS_USER_ID) {
-                return Err(error::ALREADY_IN_TEAM);
-            }
+    if let Err(err) = result {
+        if err.unique_violation(UC_TEAM_MEMBERS_USER_ID) {
+            return Err(error::ALREADY_IN_TEAM);
         }
+    }
+
+    let user_info = state
+        .iam_app()
+        .get_user_info(&format!("UserID-{}", &user.id))
+        .await
+        .map_err(|error| {
+            error!("iam error: {:?}", error);
+            error::IAM_FAILED_GET_NAME
+        })?;
+
+    state
+        .nats()
+        .publish(
+            topics::team_info(&team.competition, &team.id),
+            serde_json::to_vec(&Event::JoinTeam {
+                user: user.id,
+                name: user_info.name,
+            })
+            .unwrap()
+            .into(),
+        )
+        .await?;
+
+    delete_pending(state, txn, team.competition, user.id).await
+}
+
+/// Deletes the invitations and the join requests of a user who is in a team now
+pub(super) async fn delete_pending<S: StateTrait>(
+    state: &S,
+    txn: &DatabaseTransaction,
+    competition_id: Uuid,
+    user_id: Uuid,
+) -> Result<()> {
+    let invitations = team_invitations::Entity::find()
+        .join(JoinType::InnerJoin, team_invitations::Relation::Team.def())
+        .filter(team_invitations::Column::User.eq(user_id))
+        .filter(teams::Column::Competition.eq(competition_id))
+        .all(txn)
+        .await?;
+
+    let join_requests = team_join_requests::Entity::find()
+        .join(
+            JoinType::InnerJoin,
+            team_join_requests::Relation::Team.def(),
+        )
+        .filter(team_join_requests::Column::User.eq(user_id))
+        .filter(teams::Column::Competition.eq(competition_id))
+        .all(txn)
+        .await?;
+
+    if !invitations.is_empty() {
+        team_invitations::Entity::delete_many()
+            .filter(
+                team_invitations::Column::Id
+                    .is_in(invitations.iter().map(|invitation| invitation.id)),
+            )
+            .exec(txn)
+            .await?;
+    }
+
+    if !join_requests.is_empty() {
+        team_join_requests::Entity::delete_many()
+            .filter(team_join_requests::Column::User.eq(user_id))
+            .filter(
+                team_join_requests::Column::Team
+                    .is_in(join_requests.iter().map(|join_request| join_request.team)),
+            )
+            .exec(txn)
+            .await?;
+    }
 
-        let user_info = state
-            .iam_app()
-            .get_user_info(&format!("UserID-{}", &user.id))
-            .await
-            .map_err(|error| {
-                error!("iam error: {:?}", error);
-                error::IAM_FAILED_GET_NAME
-            })?;
+    for invitation in &invitations {
+        publish_deleted(state, competition_id, invitation).await?;
+    }
 
+    for join_request in join_requests {
         state
             .nats()
             .publish(
-                topics::team_info(&team.id),
-                serde_json::to_vec(&Event::JoinTeam {
-                    user: user.id,
-                    name: user_info.name,
-                })
-                .unwrap()
-                .into(),
+                topics::team_info(&competition_id, &join_request.team),
+                serde_json::to_vec(&Event::DeleteJoinRequest { user: user_id })
+                    .unwrap()
+                    .into(),
             )
             .await?;
+    }
 
-        txn.commit().await?;
+    Ok(())
+}
 
-        Ok(StatusCode::OK)
-    } else {
-        Err(error::JOIN_CODE_NOT_FOUND)
+/// Finds the team of the user and locks it, the user has to be the owner or the co-owner
+pub(super) async fn find_coowned_team(
+    db: &impl ConnectionTrait,
+    competition_id: &Uuid,
+    user_id: &Uuid,
+) -> Result<teams::Model> {
+    let team = teams::Entity::find_from_member(competition_id, user_id)
+        .lock_exclusive()
+        .one(db)
+        .await?
+        .ok_or(error::USER_NOT_IN_TEAM)?;
+
+    if team.owner != *user_id && team.co_owner != Some(*user_id) {
+        return Err(error::USER_NOT_COOWNER);
     }
+
+    Ok(team)
+}
+
+/// Returns `TEAM_FULL` if nobody else can join the team
+pub(super) async fn ensure_team_not_full(
+    db: &impl ConnectionTrait,
+    team: &teams::Model,
+) -> Result<()> {
+    let competition = competitions::Entity::find_by_id(team.competition)
+        .one(db)
+        .await?
+        .ok_or(error::COMPETITION_NOT_FOUND)?;
+
+    if count_members(db, team.id).await? >= competition.max_team_size {
+        return Err(error::TEAM_FULL);
+    }
+
+    Ok(())
+}
+
+pub(super) async fn count_members(db: &impl ConnectionTrait, team_id: Uuid) -> Result<i32> {
+    let member_count = team_members::Entity::find()
+        .filter(team_members::Column::TeamId.eq(team_id))
+        .count(db)
+        .await?;
+
+    Ok(member_count.try_into().unwrap_or(i32::MAX))
 }
@@ -0,0 +1,206 @@
+use super::join::find_coowned_team;
+use crate::{
+    StateTrait,
+    error::{self, Result},
+    extractors::{Competition, Json, UserID, ValidatedJson},
+    utils::generate_join_code,
+};
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+};
+use chrono::{DateTime, Utc};
+use entity::{team_join_codes, teams};
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, ConnectionTrait, DatabaseTransaction, EntityTrait,
+    IntoActiveModel, PaginatorTrait, QueryFilter, QueryOrder, QuerySelect, Set, TransactionTrait,
+};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use validator::{Validate, ValidationError};
+
+#[derive(Debug, Deserialize, Validate)]
+#[validate(schema(function = "validate_limits"))]
+pub struct Request {
+    expires_at: Option<DateTime<Utc>>,
+    #[validate(range(min = 1))]
+    max_uses: Option<i32>,
+}
+
+/// The permanent code of the team can be used for unlimited joins
+fn validate_limits(request: &Request) -> std::result::Result<(), ValidationError> {
+    if request.expires_at.is_none() && request.max_uses.is_none() {
+        return Err(ValidationError::new("no_limit"));
+    }
+
+    if request
+        .expires_at
+        .is_some_and(|expires_at| expires_at <= Utc::now())
+    {
+        return Err(ValidationError::new("expired"));
+    }
+
+    Ok(())
+}
+
+#[derive(Debug, Serialize)]
+pub struct JoinCode {
+    code: String,
+    created_at: DateTime<Utc>,
+    expires_at: Option<DateTime<Utc>>,
+    max_uses: Option<i32>,
+    uses: i32,
+}
+
+impl From<team_join_codes::Model> for JoinCode {
+    fn from(model: team_join_codes::Model) -> Self {
+        Self {
+            code: model.code,
+            created_at: model.created_at,
+            expires_at: model.expires_at,
+            max_uses: model.max_uses,
+            uses: model.uses,
+        }
+    }
+}
+
+/// Creates a join code that expires, or that can only be used a few times
+pub async fn create_join_code<S: StateTrait>(
+    State(state): State<S>,
+    competition: Competition,
+    user_id: UserID,
+    ValidatedJson(request): ValidatedJson<Request>,
+) -> Result<(StatusCode, Json<JoinCode>)> {
+    let txn = state.db().begin().await?;
+
+    let team = find_coowned_team(&txn, &competition.id, &user_id).await?;
+
+    if team.locked {
+        return Err(error::LOCKED_TEAM);
+    }
+
+    for _ in 0..16 {
+        let code = generate_join_code(&mut state.rng());
+
+        if is_taken(&txn, &code).await? {
+            continue;
+        }
+
+        let join_code = team_join_codes::ActiveModel {
+            code: Set(code),
+            team: Set(team.id),
+            created_at: Set(Utc::now()),
+            expires_at: Set(request.expires_at),
+            max_uses: Set(request.max_uses),
+            uses: Set(0),
+        }
+        .insert(&txn)
+        .await?;
+
+        txn.commit().await?;
+
+        return Ok((StatusCode::CREATED, Json(join_code.into())));
+    }
+
+    Err(error::FAILED_TO_GENERATE_JOIN_CODE)
+}
+
+/// The extra join codes of the team, including the ones that can't be used anymore
+pub async fn list_join_codes<S: StateTrait>(
+    State(state): State<S>,
+    competition: Competition,
+    user_id: UserID,
+) -> Result<Json<Vec<JoinCode>>> {
+    let txn = state.db().begin().await?;
+
+    let team = find_coowned_team(&txn, &competition.id, &user_id).await?;
+
+    let join_codes = team_join_codes::Entity::find()
+        .filter(team_join_codes::Column::Team.eq(team.id))
+        .order_by_asc(team_join_codes::Column::CreatedAt)
+        .all(&txn)
+        .await?;
+
+    txn.commit().await?;
+
+    Ok(Json(join_codes.into_iter().map(Into::into).collect()))
+}
+
+/// Revokes an extra join code, the users who joined with it stay in the team
+pub async fn delete_join_code<S: StateTrait>(
+    State(state): State<S>,
+    competition: Competition,
+    user_id: UserID,
+    Path((_, code)): Path<(Uuid, String)>,
+) -> Result<StatusCode> {
+    let txn = state.db().begin().await?;
+
+    let team = find_coowned_team(&txn, &competition.id, &user_id).await?;
+
+    let res = team_join_codes::Entity::delete_by_id(code)
+        .filter(team_join_codes::Column::Team.eq(team.id))
+        .exec(&txn)
+        .await?;
+
+    if res.rows_affected == 0 {
+        return Err(error::JOIN_CODE_NOT_FOUND);
+    }
+
+    txn.commit().await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Finds an extra join code that can be used and its team, both rows are locked.
+///
+/// The use is not counted here, only when somebody is added to the team with it.
+pub(super) async fn find_join_code(
+    txn: &DatabaseTransaction,
+    competition_id: &Uuid,
+    code: &str,
+) -> Result<(teams::Model, team_join_codes::Model)> {
+    let join_code = team_join_codes::Entity::find_by_id(code)
+        .lock_exclusive()
+        .one(txn)
+        .await?
+        .ok_or(error::JOIN_CODE_NOT_FOUND)?;
+
+    let team = teams::Entity::find_in_competition(competition_id)
+        .filter(teams::Column::Id.eq(join_code.team))
+        .lock_exclusive()
+        .one(txn)
+        .await?
+        .ok_or(error::JOIN_CODE_NOT_FOUND)?;
+
+    if !join_code.is_usable(Utc::now()) {
+        return Err(error::JOIN_CODE_EXPIRED);
+    }
+
+    Ok((team, join_code))
+}
+
+/// Counts a use of an extra join code, the row must be locked
+pub(super) async fn count_use(
+    txn: &DatabaseTransaction,
+    join_code: team_join_codes::Model,
+) -> Result<()> {
+    let uses = join_code.uses + 1;
+    let mut join_code = join_code.into_active_model();
+    join_code.uses = Set(uses);
+    join_code.update(txn).await?;
+
+    Ok(())
+}
+
+/// Whether the code is the permanent or an extra code of any team, a new code of either kind must
+/// not be taken by the other kind, because the permanent codes are looked up first
+pub(super) async fn is_taken(db: &impl ConnectionTrait, code: &str) -> Result<bool> {
+    let teams = teams::Entity::find()
+        .filter(teams::Column::JoinCode.eq(code))
+        .count(db)
+        .await?;
+
+    let join_codes = team_join_codes::Entity::find_by_id(code).count(db).await?;
+
+    Ok(teams + join_codes > 0)
+}
@@ -0,0 +1,92 @@
+use super::{
+    join::{add_member, find_coowned_team},
+    join_codes::count_use,
+};
+use crate::{
+    StateTrait,
+    error::{self, Result},
+    extractors::{Competition, Json, UserID},
+    handlers::socket::Event,
+    utils::topics,
+};
+use axum::{extract::State, http::StatusCode};
+use entity::{team_join_codes, team_join_requests};
+use sea_orm::{EntityTrait, QuerySelect, TransactionTrait};
+use serde::Deserialize;
+use uuid::Uuid;
+
+#[derive(Deserialize)]
+pub struct Request {
+    user: Uuid,
+}
+
+/// Lets the user into the team, the same rules apply as for joining with the code.
+///
+/// The use of the extra code the request was made with is counted now, even if the code can't be
+/// used anymore, because the owner decided to let the user in.
+pub async fn approve_join_request<S: StateTrait>(
+    State(state): State<S>,
+    competition: Competition,
+    user_id: UserID,
+    Json(request): Json<Request>,
+) -> Result<StatusCode> {
+    let txn = state.db().begin().await?;
+
+    let team = find_coowned_team(&txn, &competition.id, &user_id).await?;
+
+    let join_request = team_join_requests::Entity::find_by_id((team.id, request.user))
+        .one(&txn)
+        .await?
+        .ok_or(error::JOIN_REQUEST_NOT_FOUND)?;
+
+    // The join request is deleted with the other pending ones of the user
+    add_member(&state, &txn, &team, request.user).await?;
+
+    // The code might have been deleted since the request was made
+    if let Some(code) = join_request.join_code {
+        if let Some(join_code) = team_join_codes::Entity::find_by_id(code)
+            .lock_exclusive()
+            .one(&txn)
+            .await?
+        {
+            count_use(&txn, join_code).await?;
+        }
+    }
+
+    txn.commit().await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+pub async fn reject_join_request<S: StateTrait>(
+    State(state): State<S>,
+    competition: Competition,
+    user_id: UserID,
+    Json(request): Json<Request>,
+) -> Result<StatusCode> {
+    let txn = state.db().begin().await?;
+
+    let team = find_coowned_team(&txn, &competition.id, &user_id).await?;
+
+    let res = team_join_requests::Entity::delete_by_id((team.id, request.user))
+        .exec(&txn)
+        .await?;
+
+    if res.rows_affected == 0 {
+        return Err(error::JOIN_REQUEST_NOT_FOUND);
+    }
+
+    state
+        .nats()
+        .publish(
+            topics::team_info(&competition.id, &team.id),
+            serde_json::to_vec(&Event::DeleteJoinRequest { user: request.user })
+                .unwrap()
+                .into(),
+        )
+        .await?;
+
+    txn.commit().await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -1,7 +1,7 @@
 use crate::{
     StateTrait,
     error::{self, Result},
-    extractors::{Json, UserID},
+    extractors::{Competition, Json, UserID},
     handlers::socket::Event,
     utils::topics,
 };
@@ -18,12 +18,13 @@ pub struct Request {
 
 pub async fn kick_user<S: StateTrait>(
     State(state): State<S>,
+    competition: Competition,
     user_id: UserID,
     Json(request): Json<Request>,
 ) -> Result<StatusCode> {
     let txn = state.db().begin().await?;
 
-    let team = teams::Entity::find_from_member(&user_id)
+    let team = teams::Entity::find_from_member(&competition.id, &user_id)
         .lock_exclusive()
         .one(&txn)
         .await?
@@ -54,6 +55,7 @@ pub async fn kick_user<S: StateTrait>(
     let res = team_members::Entity::delete(team_members::ActiveModel {
         user_id: Set(user.id),
         team_id: Set(team.id),
+        ..Default::default()
     })
     .exec(&txn)
     .await?;
@@ -62,7 +64,7 @@ pub async fn kick_user<S: StateTrait>(
         return Err(error::NO_SUCH_MEMBER);
     }
 
-    let topic = topics::team_info(&team.id);
+    let topic = topics::team_info(&competition.id, &team.id);
 
     if Some(request.user) == team.co_owner {
         let mut model = team.into_active_model();
@@ -1,5 +1,8 @@
 use crate::{
-    Result, StateTrait, error, extractors::UserID, handlers::socket::Event, utils::topics,
+    Result, StateTrait, error,
+    extractors::{Competition, UserID},
+    handlers::socket::Event,
+    utils::topics,
 };
 use axum::{extract::State, http::StatusCode};
 use entity::{team_members, teams, users};
@@ -7,6 +10,7 @@ use sea_orm::{EntityTrait, QuerySelect, TransactionTrait};
 
 pub async fn leave_team<S: StateTrait>(
     State(state): State<S>,
+    competition: Competition,
     user_id: UserID,
 ) -> Result<StatusCode> {
     let txn = state.db().begin().await?;
@@ -21,7 +25,7 @@ pub async fn leave_team<S: StateTrait>(
             error::USER_NOT_REGISTERED
         })?;
 
-    let team = teams::Entity::find_from_member(&user.id)
+    let team = teams::Entity::find_from_member(&competition.id, &user.id)
         // NOTE: maybe not neccessary because locking the team (in the application and not in the database)
         //       while this handler is running shouldn't make invalid state in the database
         .lock_shared()
@@ -44,7 +48,7 @@ pub async fn leave_team<S: StateTrait>(
     state
         .nats()
         .publish(
-            topics::team_info(&team.id),
+            topics::team_info(&competition.id, &team.id),
             serde_json::to_vec(&Event::LeaveTeam { user: user.id })
                 .unwrap()
                 .into(),
@@ -1,24 +1,36 @@
 mod code;
 mod create;
 mod disband;
+mod exclusion;
 mod get;
+mod invitation;
 mod join;
+mod join_codes;
+mod join_request;
 mod kick;
 mod leave;
+mod size;
+mod solution;
+mod time;
 mod update;
 
 use crate::{middlewares::PermissionsLayer, state::StateTrait};
 use axum::{
     Router,
-    routing::{get, patch, post},
+    routing::{delete, get, patch, post, put},
 };
 
-/// Routes for team management
+/// Routes for team management, nested under `/competition/{competition}`
 ///
 /// # Member actions
 /// POST  /team/create
 /// POST  /team/join
 /// POST  /team/leave
+/// GET   /team/code/qr
+/// GET   /team/invitations
+/// GET   /team/invitations/ws
+/// POST  /team/invitations/{id}/accept
+/// POST  /team/invitations/{id}/decline
 ///
 /// # Owner actions
 /// PATCH /team
@@ -27,9 +39,22 @@ use axum::{
 /// # Co-Owner actions
 /// POST  /team/kick
 /// POST  /team/code
+/// GET   /team/codes
+/// POST  /team/codes
+/// DELETE /team/codes/{code}
+/// POST  /team/invite
+/// POST  /team/invite/cancel
+/// POST  /team/requests/approve
+/// POST  /team/requests/reject
 ///
 /// # Admin actions
-/// GET /team
+/// GET    /team
+/// GET    /team/size
+/// PUT    /team/{id}/time
+/// DELETE /team/{id}/time
+/// PUT    /team/{id}/exclusion
+/// DELETE /team/{id}/exclusion
+/// PUT    /team/{id}/solution
 pub fn routes<S: StateTrait>(state: S) -> Router<S> {
     Router::new()
         .route("/create", post(create::create_team::<S>))
@@ -39,9 +64,77 @@ pub fn routes<S: StateTrait>(state: S) -> Router<S> {
         .route("/disband", post(disband::disband_team::<S>))
         .route("/kick", post(kick::kick_user::<S>))
         .route("/code", post(code::regenerate_code::<S>))
+        .route("/code/qr", get(code::get_code_qr::<S>))
+        .route(
+            "/codes",
+            get(join_codes::list_join_codes::<S>).post(join_codes::create_join_code::<S>),
+        )
+        .route("/codes/{code}", delete(join_codes::delete_join_code::<S>))
+        .route("/invite", post(invitation::invite_user::<S>))
+        .route("/invite/cancel", post(invitation::cancel_invitation::<S>))
+        .route(
+            "/requests/approve",
+            post(join_request::approve_join_request::<S>),
+        )
+        .route(
+            "/requests/reject",
+            post(join_request::reject_join_request::<S>),
+        )
+        .route("/invitations", get(invitation::list_invitations::<S>))
+        .route("/invitations/ws", get(invitation::invitations_ws::<S>))
+        .route(
+            "/invitations/{id}/accept",
+            post(invitation::accept_invitation::<S>),
+        )
+        .route(
+            "/invitations/{id}/decline",
+            post(invitation::decline_invitation::<S>),
+        )
         .route(
             "/",
-            get(get::get_all_teams::<S>)
+            get(get::get_all_teams::<S>).layer(PermissionsLayer::new(
+                state.clone(),
+                &["mathcompetition.admin"],
+            )),
+        )
+        .route(
+            "/size",
+            get(size::get_team_size_report::<S>).layer(PermissionsLayer::new(
+                state.clone(),
+                &["mathcompetition.admin"],
+            )),
+        )
+        .route(
+            "/{id}/time",
+            put(time::set_team_time::<S>).layer(PermissionsLayer::new(
+                state.clone(),
+                &["mathcompetition.admin"],
+            )),
+        )
+        .route(
+            "/{id}/time",
+            delete(time::delete_team_time::<S>).layer(PermissionsLayer::new(
+                state.clone(),
+                &["mathcompetition.admin"],
+            )),
+        )
+        .route(
+            "/{id}/exclusion",
+            put(exclusion::set_exclusion::<S>).layer(PermissionsLayer::new(
+                state.clone(),
+                &["mathcompetition.admin"],
+            )),
+        )
+        .route(
+            "/{id}/exclusion",
+            delete(exclusion::delete_exclusion::<S>).layer(PermissionsLayer::new(
+                state.clone(),
+                &["mathcompetition.admin"],
+            )),
+        )
+        .route(
+            "/{id}/solution",
+            put(solution::set_team_solution::<S>)
                 .layer(PermissionsLayer::new(state, &["mathcompetition.admin"])),
         )
 }
@@ -0,0 +1,101 @@
+use crate::{
+    StateTrait,
+    error::Result,
+    extractors::{Competition, Json},
+};
+use axum::extract::State;
+use entity::{team_members, teams};
+use sea_orm::{
+    ColumnTrait, EntityTrait, FromQueryResult, QueryFilter, QueryOrder, QuerySelect,
+    TransactionTrait,
+};
+use serde::Serialize;
+use std::collections::HashMap;
+use uuid::Uuid;
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum Violation {
+    TooSmall,
+    TooLarge,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Team {
+    id: Uuid,
+    name: String,
+    members: i64,
+    locked: bool,
+    violation: Violation,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Response {
+    min_team_size: i32,
+    max_team_size: i32,
+    teams: Vec<Team>,
+}
+
+#[derive(FromQueryResult)]
+struct MemberCount {
+    team_id: Uuid,
+    members: i64,
+}
+
+/// The teams with fewer members than the minimum or more than the maximum size of the
+/// competition, for example after the limits were changed
+pub async fn get_team_size_report<S: StateTrait>(
+    State(state): State<S>,
+    competition: Competition,
+) -> Result<Json<Response>> {
+    let txn = state.db().begin().await?;
+
+    let members = team_members::Entity::find()
+        .select_only()
+        .column(team_members::Column::TeamId)
+        .column_as(team_members::Column::UserId.count(), "members")
+        .filter(team_members::Column::Competition.eq(competition.id))
+        .group_by(team_members::Column::TeamId)
+        .into_model::<MemberCount>()
+        .all(&txn)
+        .await?
+        .into_iter()
+        .map(|count| (count.team_id, count.members))
+        .collect::<HashMap<_, _>>();
+
+    let teams = teams::Entity::find_in_competition(&competition.id)
+        .order_by_asc(teams::Column::Name)
+        .all(&txn)
+        .await?;
+
+    txn.commit().await?;
+
+    let teams = teams
+        .into_iter()
+        .filter_map(|team| {
+            let members = members.get(&team.id).copied().unwrap_or(0);
+
+            let violation = if members < competition.min_team_size.into() {
+                Violation::TooSmall
+            } else if members > competition.max_team_size.into() {
+                Violation::TooLarge
+            } else {
+                return None;
+            };
+
+            Some(Team {
+                id: team.id,
+                name: team.name,
+                members,
+                locked: team.locked,
+                violation,
+            })
+        })
+        .collect();
+
+    Ok(Json(Response {
+        min_team_size: competition.min_team_size,
+        max_team_size: competition.max_team_size,
+        teams,
+    }))
+}
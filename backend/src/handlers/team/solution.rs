@@ -0,0 +1,111 @@
+use super::time::find_team;
+use crate::{
+    StateTrait,
+    error::{self, Result},
+    extractors::{Competition, Json, UserID},
+    handlers::socket::Event,
+    utils::{RawAnswer, normalize_answer, topics},
+};
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+};
+use entity::{problems, solutions_history};
+use sea_orm::{
+    ActiveValue::Set, ColumnTrait, EntityTrait, PaginatorTrait, QueryFilter, TransactionTrait,
+};
+use serde::Deserialize;
+use uuid::Uuid;
+
+#[derive(Debug, Deserialize)]
+pub struct Request {
+    problem: Uuid,
+    solution: Option<RawAnswer>,
+}
+
+/// Sets or clears the answer of a team, for example from a paper answer sheet.
+///
+/// The time of the team, pauses, releases and attempt limits are not checked, and the answer
+/// doesn't count as an attempt of the team.
+pub async fn set_team_solution<S: StateTrait>(
+    State(state): State<S>,
+    competition: Competition,
+    admin_id: UserID,
+    Path((_, team_id)): Path<(Uuid, Uuid)>,
+    Json(request): Json<Request>,
+) -> Result<StatusCode> {
+    let txn = state.db().begin().await?;
+
+    let team = find_team(&txn, &competition.id, team_id).await?;
+
+    let problem = problems::Entity::find_by_id(request.problem)
+        .filter(problems::Column::Competition.eq(competition.id))
+        .one(&txn)
+        .await?
+        .ok_or(error::PROBLEM_NOT_FOUND)?;
+
+    let solution = request
+        .solution
+        .as_ref()
+        .map(|solution| normalize_answer(problem.kind, solution))
+        .transpose()?;
+
+    info!(
+        "admin set solution: admin: {}, team: {}, problem: {}, solution: {:?}",
+        *admin_id, team.id, problem.id, solution
+    );
+
+    let solution_history = solutions_history::ActiveModel {
+        id: Set(Uuid::new_v4()),
+        team: Set(team.id),
+        problem: Set(problem.id),
+        user: Set(None),
+        admin: Set(Some(*admin_id)),
+        solution: Set(solution.clone()),
+        created_at: Default::default(),
+    };
+
+    solutions_history::Entity::insert(solution_history)
+        .exec(&txn)
+        .await?;
+
+    state
+        .nats()
+        .publish(
+            topics::team_solutions(&competition.id, &team.id),
+            serde_json::to_vec(&Event::SolutionSet {
+                problem: problem.id,
+                solution,
+            })
+            .unwrap()
+            .into(),
+        )
+        .await?;
+
+    // The answer is not an attempt, but the team gets the count like after its own answers
+    if let Some(max_attempts) = problem.max_attempts {
+        let attempts = solutions_history::Entity::find()
+            .filter(solutions_history::Column::Team.eq(team.id))
+            .filter(solutions_history::Column::Problem.eq(problem.id))
+            .filter(solutions_history::Column::Admin.is_null())
+            .count(&txn)
+            .await?;
+
+        state
+            .nats()
+            .publish(
+                topics::team_solutions(&competition.id, &team.id),
+                serde_json::to_vec(&Event::RemainingAttempts {
+                    problem: problem.id,
+                    remaining: (i64::from(max_attempts) - attempts as i64).max(0),
+                })
+                .unwrap()
+                .into(),
+            )
+            .await?;
+    }
+
+    txn.commit().await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -0,0 +1,132 @@
+use crate::{
+    StateTrait,
+    error::{self, Result},
+    extractors::{Competition, ValidatedJson},
+    handlers::socket::Event,
+    utils::{get_team_times, topics},
+};
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+};
+use chrono::DateTime;
+use entity::{team_times, teams};
+use sea_orm::{
+    ColumnTrait, ConnectionTrait, EntityTrait, QueryFilter, QuerySelect, Set, TransactionTrait,
+    sea_query::OnConflict,
+};
+use serde::Deserialize;
+use uuid::Uuid;
+use validator::Validate;
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct Request {
+    start_time: Option<i64>,
+    end_time: Option<i64>,
+    #[serde(default)]
+    #[validate(range(min = 0))]
+    extra_minutes: i32,
+}
+
+/// Replaces the time override of a team
+pub async fn set_team_time<S: StateTrait>(
+    State(state): State<S>,
+    competition: Competition,
+    Path((_, team_id)): Path<(Uuid, Uuid)>,
+    ValidatedJson(request): ValidatedJson<Request>,
+) -> Result<StatusCode> {
+    let txn = state.db().begin().await?;
+
+    find_team(&txn, &competition.id, team_id).await?;
+
+    let start_time = request
+        .start_time
+        .map(|time| DateTime::from_timestamp(time, 0).ok_or(error::TIME_SECONDS_OUT_OF_RANGE))
+        .transpose()?;
+    let end_time = request
+        .end_time
+        .map(|time| DateTime::from_timestamp(time, 0).ok_or(error::TIME_SECONDS_OUT_OF_RANGE))
+        .transpose()?;
+
+    let model = team_times::ActiveModel {
+        team: Set(team_id),
+        start_time: Set(start_time),
+        end_time: Set(end_time),
+        extra_minutes: Set(request.extra_minutes),
+    };
+
+    team_times::Entity::insert(model)
+        .on_conflict(
+            OnConflict::column(team_times::Column::Team)
+                .update_columns([
+                    team_times::Column::StartTime,
+                    team_times::Column::EndTime,
+                    team_times::Column::ExtraMinutes,
+                ])
+                .to_owned(),
+        )
+        .exec(&txn)
+        .await?;
+
+    publish_times(&state, &txn, competition.id, team_id).await?;
+
+    txn.commit().await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Removes the time override of a team, so it uses the times of the competition again
+pub async fn delete_team_time<S: StateTrait>(
+    State(state): State<S>,
+    competition: Competition,
+    Path((_, team_id)): Path<(Uuid, Uuid)>,
+) -> Result<StatusCode> {
+    let txn = state.db().begin().await?;
+
+    find_team(&txn, &competition.id, team_id).await?;
+
+    team_times::Entity::delete_by_id(team_id).exec(&txn).await?;
+
+    publish_times(&state, &txn, competition.id, team_id).await?;
+
+    txn.commit().await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+pub(super) async fn find_team(
+    db: &impl ConnectionTrait,
+    competition_id: &Uuid,
+    team_id: Uuid,
+) -> Result<teams::Model> {
+    teams::Entity::find_in_competition(competition_id)
+        .filter(teams::Column::Id.eq(team_id))
+        .lock_exclusive()
+        .one(db)
+        .await?
+        .ok_or(error::TEAM_NOT_FOUND)
+}
+
+async fn publish_times<S: StateTrait>(
+    state: &S,
+    db: &impl ConnectionTrait,
+    competition_id: Uuid,
+    team_id: Uuid,
+) -> Result<()> {
+    let (start_time, end_time) = get_team_times(db, competition_id, team_id).await?;
+
+    state
+        .nats()
+        .publish(
+            topics::team_info(&competition_id, &team_id),
+            serde_json::to_vec(&Event::UpdateTime {
+                start_time: Some(start_time),
+                end_time: Some(end_time),
+            })
+            .unwrap()
+            .into(),
+        )
+        .await?;
+
+    Ok(())
+}
@@ -1,7 +1,8 @@
+use super::join::count_members;
 use crate::{
     StateTrait,
     error::{self, Result},
-    extractors::{UserID, ValidatedJson},
+    extractors::{Competition, UserID, ValidatedJson},
     handlers::socket::Event,
     utils::{set_option, topics},
 };
@@ -20,16 +21,18 @@ pub struct Request {
     #[serde(default, with = "::serde_with::rust::double_option")]
     co_owner: Option<Option<Uuid>>,
     locked: Option<bool>,
+    require_approval: Option<bool>,
 }
 
 pub async fn update_team<S: StateTrait>(
     State(state): State<S>,
+    competition: Competition,
     user_id: UserID,
     ValidatedJson(request): ValidatedJson<Request>,
 ) -> Result<StatusCode> {
     let txn = state.db().begin().await?;
 
-    let team = teams::Entity::find_from_member(&user_id)
+    let team = teams::Entity::find_from_member(&competition.id, &user_id)
         .lock_exclusive()
         .one(&txn)
         .await?
@@ -50,22 +53,29 @@ pub async fn update_team<S: StateTrait>(
         && request.owner.is_none()
         && request.co_owner.is_none()
         && request.locked.is_none()
+        && request.require_approval.is_none()
     {
         return Ok(StatusCode::NO_CONTENT);
     }
 
+    if request.locked == Some(true)
+        && count_members(&txn, team.id).await? < competition.min_team_size
+    {
+        return Err(error::TEAM_TOO_SMALL);
+    }
+
     if let Some(owner) = &request.owner {
         if owner == &*user_id {
             return Ok(StatusCode::NO_CONTENT);
         }
 
-        if !is_user_in_team(&txn, owner, &team.id).await? {
+        if !is_user_in_team(&txn, &competition.id, owner, &team.id).await? {
             return Err(error::NO_SUCH_MEMBER);
         }
     }
 
     if let Some(Some(coowner)) = &request.co_owner {
-        if !is_user_in_team(&txn, coowner, &team.id).await? {
+        if !is_user_in_team(&txn, &competition.id, coowner, &team.id).await? {
             return Err(error::NO_SUCH_MEMBER);
         }
     }
@@ -75,17 +85,19 @@ pub async fn update_team<S: StateTrait>(
         owner: request.owner,
         co_owner: request.co_owner,
         locked: request.locked,
+        require_approval: request.require_approval,
         code: None,
     })
     .unwrap();
 
-    let topic = topics::team_info(&team.id);
+    let topic = topics::team_info(&competition.id, &team.id);
 
     let mut active_model = team.into_active_model();
     active_model.name = set_option(request.name);
     active_model.owner = set_option(request.owner);
     active_model.co_owner = set_option(request.co_owner);
     active_model.locked = set_option(request.locked);
+    active_model.require_approval = set_option(request.require_approval);
 
     teams::Entity::update(active_model).exec(&txn).await?;
 
@@ -99,10 +111,13 @@ pub async fn update_team<S: StateTrait>(
 // between non-existing and not in team for security reasons
 async fn is_user_in_team(
     db: &impl ConnectionTrait,
+    competition_id: &Uuid,
     user_id: &Uuid,
     team_id: &Uuid,
 ) -> Result<bool> {
-    let team = teams::Entity::find_from_member(user_id).one(db).await?;
+    let team = teams::Entity::find_from_member(competition_id, user_id)
+        .one(db)
+        .await?;
 
     if let Some(teams::Model { id, .. }) = team {
         Ok(*team_id == id)
@@ -79,50 +79,62 @@ where
             return Box::pin(async { Ok(error::NOT_ENOUGH_PERMISSIONS.into_response()) });
         };
 
-        let json = json!({
-            "actions": self.permissions,
-            "user": claims.sub,
-        });
+        let user = claims.sub.clone();
+        let permissions = self.permissions;
         let token = self.state.iam_app().token().to_owned();
 
         let future = self.inner.call(req);
 
         Box::pin(async move {
-            let client = reqwest::Client::new();
-
-            let res = client
-                .post(format!("{}/v1/decision", env::var("IAM_URL").unwrap()))
-                .header(AUTHORIZATION, format!("Bearer {}", token))
-                .json(&json)
-                .send()
-                .await;
-
-            let Ok(res) = res else {
-                warn!("failed to send http request: {:?}", res);
+            if !has_permissions(&token, &user, permissions).await {
                 return Ok(error::NOT_ENOUGH_PERMISSIONS.into_response());
-            };
-
-            #[derive(Debug, Deserialize)]
-            #[allow(unused)]
-            struct Response {
-                code: String,
-                error: String,
             }
 
-            if !res.status().is_success() {
-                let status = res.status();
-                let text = res.text().await.unwrap();
+            future.await
+        })
+    }
+}
 
-                if let Ok(err) = serde_json::from_str::<Response>(&text) {
-                    error!("IAM returned error: {:?}, status: {}", err, status);
-                    return Ok(error::NOT_ENOUGH_PERMISSIONS.into_response());
-                }
+/// Asks the IAM whether `user` is allowed to do all of `permissions`
+pub async fn has_permissions(app_token: &str, user: &str, permissions: &[&str]) -> bool {
+    let json = json!({
+        "actions": permissions,
+        "user": user,
+    });
+
+    let client = reqwest::Client::new();
+
+    let res = client
+        .post(format!("{}/v1/decision", env::var("IAM_URL").unwrap()))
+        .header(AUTHORIZATION, format!("Bearer {}", app_token))
+        .json(&json)
+        .send()
+        .await;
+
+    let Ok(res) = res else {
+        warn!("failed to send http request: {:?}", res);
+        return false;
+    };
+
+    #[derive(Debug, Deserialize)]
+    #[allow(unused)]
+    struct Response {
+        code: String,
+        error: String,
+    }
 
-                error!("text: {}", text);
-                return Ok(error::NOT_ENOUGH_PERMISSIONS.into_response());
-            }
+    if !res.status().is_success() {
+        let status = res.status();
+        let text = res.text().await.unwrap();
 
-            future.await
-        })
+        if let Ok(err) = serde_json::from_str::<Response>(&text) {
+            error!("IAM returned error: {:?}, status: {}", err, status);
+            return false;
+        }
+
+        error!("text: {}", text);
+        return false;
     }
+
+    true
 }
@@ -1,4 +1,5 @@
-use crate::utils::Problems;
+use crate::utils::{Problems, Scoreboards};
+use chrono::TimeDelta;
 use libiam::{App, jwt::Jwt};
 use rand::{
     Rng,
@@ -18,8 +19,12 @@ pub trait StateTrait: Send + Sync + Clone + 'static {
     fn rng(&self) -> Self::Rand;
     fn app_secret(&self) -> &str;
     fn problems(&self) -> Arc<Problems>;
+    fn scoreboards(&self) -> Arc<Scoreboards>;
     fn nats(&self) -> async_nats::Client;
     fn jwt(&self) -> &Jwt;
+    fn solution_grace_period(&self) -> TimeDelta;
+    fn scoreboard_freeze(&self) -> TimeDelta;
+    fn join_link(&self) -> Option<&str>;
 }
 
 pub struct State {
@@ -27,8 +32,12 @@ pub struct State {
     iam_app: App,
     app_secret: String,
     problems: Arc<Problems>,
+    scoreboards: Arc<Scoreboards>,
     nats: async_nats::Client,
     jwt: Jwt,
+    solution_grace_period: TimeDelta,
+    scoreboard_freeze: TimeDelta,
+    join_link: Option<String>,
 }
 
 impl State {
@@ -48,11 +57,32 @@ impl State {
             iam_app,
             app_secret: env::var("IAM_APP_SECRET").expect("IAM_APP_SECRET is not set"),
             problems: Arc::new(problems),
+            scoreboards: Arc::new(Scoreboards::new()),
             nats,
             jwt: Jwt::new(iam.api()).await.expect("Failed to get IAM jwks"),
+            // Grace period after `end_time` in which solutions are still accepted to
+            // compensate for network latency
+            solution_grace_period: Self::seconds_from_env("SOLUTION_GRACE_PERIOD"),
+            // Time before `end_time` after which the public scoreboard stops updating
+            scoreboard_freeze: Self::seconds_from_env("SCOREBOARD_FREEZE"),
+            // Link of the frontend page for joining a team, `{code}` is replaced with the code
+            join_link: env::var("JOIN_LINK").ok(),
         })
     }
 
+    /// Reads a duration in seconds from an environment variable, defaults to zero
+    fn seconds_from_env(name: &str) -> TimeDelta {
+        let Ok(seconds) = env::var(name) else {
+            return TimeDelta::zero();
+        };
+
+        let Ok(seconds) = seconds.parse() else {
+            panic!("{name} is not a valid number of seconds");
+        };
+
+        TimeDelta::try_seconds(seconds).unwrap_or_else(|| panic!("{name} is out of range"))
+    }
+
     async fn connect_database() -> DbConn {
         info!("Trying to connect to database");
 
@@ -107,6 +137,10 @@ impl StateTrait for Arc<State> {
         Arc::clone(&self.problems)
     }
 
+    fn scoreboards(&self) -> Arc<Scoreboards> {
+        Arc::clone(&self.scoreboards)
+    }
+
     fn nats(&self) -> async_nats::Client {
         self.nats.clone()
     }
@@ -114,4 +148,16 @@ impl StateTrait for Arc<State> {
     fn jwt(&self) -> &Jwt {
         &self.jwt
     }
+
+    fn solution_grace_period(&self) -> TimeDelta {
+        self.solution_grace_period
+    }
+
+    fn scoreboard_freeze(&self) -> TimeDelta {
+        self.scoreboard_freeze
+    }
+
+    fn join_link(&self) -> Option<&str> {
+        self.join_link.as_deref()
+    }
 }
@@ -0,0 +1,189 @@
+use crate::error::{self, Result};
+use entity::problems::AnswerKind;
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+
+/// Largest number of elements in a [`AnswerKind::Set`] answer
+const MAX_SET_SIZE: usize = 32;
+
+/// An answer as it is sent by the clients, plain JSON numbers are accepted too
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum RawAnswer {
+    Integer(i64),
+    Number(f64),
+    Text(String),
+}
+
+impl RawAnswer {
+    fn as_text(&self) -> Cow<'_, str> {
+        match self {
+            RawAnswer::Integer(number) => Cow::Owned(number.to_string()),
+            RawAnswer::Number(number) => Cow::Owned(number.to_string()),
+            RawAnswer::Text(text) => Cow::Borrowed(text.trim()),
+        }
+    }
+}
+
+/// Normalizes an answer, so equal answers of the same kind have the same representation.
+/// The result is what gets stored in the database and compared by the stats.
+pub fn normalize_answer(kind: AnswerKind, answer: &RawAnswer) -> Result<String> {
+    let text = answer.as_text();
+
+    let normalized = match kind {
+        AnswerKind::Integer => parse_integer(&text).map(|number| number.to_string()),
+        AnswerKind::Fraction => normalize_fraction(&text),
+        AnswerKind::Decimal => normalize_decimal(&text),
+        AnswerKind::Choice => normalize_choice(&text),
+        AnswerKind::Set => normalize_set(&text),
+    };
+
+    normalized.ok_or(error::INVALID_ANSWER)
+}
+
+fn parse_integer(text: &str) -> Option<i64> {
+    text.trim().parse().ok()
+}
+
+fn gcd(mut a: i128, mut b: i128) -> i128 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a.abs()
+}
+
+fn normalize_fraction(text: &str) -> Option<String> {
+    let (numerator, denominator) = match text.split_once('/') {
+        Some((numerator, denominator)) => (parse_integer(numerator)?, parse_integer(denominator)?),
+        None => (parse_integer(text)?, 1),
+    };
+
+    if denominator == 0 {
+        return None;
+    }
+
+    let (mut numerator, mut denominator) = (i128::from(numerator), i128::from(denominator));
+
+    if denominator < 0 {
+        numerator = -numerator;
+        denominator = -denominator;
+    }
+
+    let divisor = gcd(numerator, denominator);
+    numerator /= divisor;
+    denominator /= divisor;
+
+    if denominator == 1 {
+        Some(numerator.to_string())
+    } else {
+        Some(format!("{numerator}/{denominator}"))
+    }
+}
+
+fn normalize_decimal(text: &str) -> Option<String> {
+    // Decimal commas are common on paper
+    let number: f64 = text.replace(',', ".").parse().ok()?;
+
+    if !number.is_finite() {
+        return None;
+    }
+
+    // Adding zero turns `-0` into `0`
+    Some((number + 0.0).to_string())
+}
+
+fn normalize_choice(text: &str) -> Option<String> {
+    let mut chars = text.chars();
+
+    match (chars.next(), chars.next()) {
+        (Some(choice), None) if choice.is_ascii_alphabetic() => {
+            Some(choice.to_ascii_uppercase().to_string())
+        }
+        _ => None,
+    }
+}
+
+fn normalize_set(text: &str) -> Option<String> {
+    let text = text.trim().trim_start_matches('{').trim_end_matches('}');
+
+    let mut elements = text
+        .split([',', ';', ' '])
+        .filter(|element| !element.is_empty())
+        .map(parse_integer)
+        .collect::<Option<Vec<_>>>()?;
+
+    elements.sort_unstable();
+    elements.dedup();
+
+    if elements.is_empty() || elements.len() > MAX_SET_SIZE {
+        return None;
+    }
+
+    Some(
+        elements
+            .iter()
+            .map(i64::to_string)
+            .collect::<Vec<_>>()
+            .join(","),
+    )
+}
+
+#[cfg(test)]
+fn normalize(kind: AnswerKind, text: &str) -> Option<String> {
+    normalize_answer(kind, &RawAnswer::Text(text.to_owned())).ok()
+}
+
+#[test]
+fn normalize_integers() {
+    assert_eq!(
+        normalize_answer(AnswerKind::Integer, &RawAnswer::Integer(-12)).ok(),
+        Some("-12".to_owned())
+    );
+    assert_eq!(normalize(AnswerKind::Integer, " +7 "), Some("7".to_owned()));
+    assert_eq!(normalize(AnswerKind::Integer, "1.5"), None);
+}
+
+#[test]
+fn normalize_fractions() {
+    assert_eq!(
+        normalize(AnswerKind::Fraction, "6/-8"),
+        Some("-3/4".to_owned())
+    );
+    assert_eq!(
+        normalize(AnswerKind::Fraction, "4 / 2"),
+        Some("2".to_owned())
+    );
+    assert_eq!(normalize(AnswerKind::Fraction, "0/5"), Some("0".to_owned()));
+    assert_eq!(normalize(AnswerKind::Fraction, "1/0"), None);
+}
+
+#[test]
+fn normalize_decimals() {
+    assert_eq!(
+        normalize(AnswerKind::Decimal, "3,140"),
+        Some("3.14".to_owned())
+    );
+    assert_eq!(normalize(AnswerKind::Decimal, "-0"), Some("0".to_owned()));
+    assert_eq!(
+        normalize_answer(AnswerKind::Decimal, &RawAnswer::Number(0.5)).ok(),
+        Some("0.5".to_owned())
+    );
+    assert_eq!(normalize(AnswerKind::Decimal, "inf"), None);
+}
+
+#[test]
+fn normalize_choices() {
+    assert_eq!(normalize(AnswerKind::Choice, " b "), Some("B".to_owned()));
+    assert_eq!(normalize(AnswerKind::Choice, "AB"), None);
+    assert_eq!(normalize(AnswerKind::Choice, "1"), None);
+}
+
+#[test]
+fn normalize_sets() {
+    assert_eq!(
+        normalize(AnswerKind::Set, "{9, 1; 4 1}"),
+        Some("1,4,9".to_owned())
+    );
+    assert_eq!(normalize(AnswerKind::Set, "{}"), None);
+    assert_eq!(normalize(AnswerKind::Set, "1, x"), None);
+}
@@ -0,0 +1,149 @@
+use crate::error::{self, Result};
+use entity::{
+    competitions::{self, Division, DivisionRule},
+    team_members,
+    users::{self, Class},
+};
+use sea_orm::{
+    ColumnTrait, ConnectionTrait, EntityTrait, FromQueryResult, JoinType, QueryFilter, QuerySelect,
+    RelationTrait,
+};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use uuid::Uuid;
+
+/// Returns the division of a team with members from `classes`, if any division has the class
+/// that is chosen by the rule
+pub fn team_division<'a>(
+    divisions: &'a [Division],
+    rule: DivisionRule,
+    classes: &[Class],
+) -> Option<&'a Division> {
+    let class = match rule {
+        DivisionRule::HighestClass => classes.iter().max(),
+        DivisionRule::LowestClass => classes.iter().min(),
+        DivisionRule::Majority => {
+            let mut counts = BTreeMap::<&Class, usize>::new();
+
+            for class in classes {
+                *counts.entry(class).or_default() += 1;
+            }
+
+            counts
+                .into_iter()
+                .max_by(|(a, a_count), (b, b_count)| a_count.cmp(b_count).then(a.cmp(b)))
+                .map(|(class, _)| class)
+        }
+    }?;
+
+    divisions.iter().find(|division| division.contains(class))
+}
+
+/// Returns the name of the division of every team in the competition that is in one
+pub async fn get_team_divisions(
+    db: &impl ConnectionTrait,
+    competition: &competitions::Model,
+) -> Result<HashMap<Uuid, String>> {
+    #[derive(FromQueryResult)]
+    struct Member {
+        team_id: Uuid,
+        class: Class,
+    }
+
+    if competition.divisions.0.is_empty() {
+        return Ok(HashMap::new());
+    }
+
+    let members = team_members::Entity::find()
+        .select_only()
+        .column(team_members::Column::TeamId)
+        .column(users::Column::Class)
+        .join(JoinType::InnerJoin, team_members::Relation::User.def())
+        .filter(team_members::Column::Competition.eq(competition.id))
+        .into_model::<Member>()
+        .all(db)
+        .await?;
+
+    let mut classes = HashMap::<Uuid, Vec<Class>>::new();
+
+    for member in members {
+        classes
+            .entry(member.team_id)
+            .or_default()
+            .push(member.class);
+    }
+
+    Ok(classes
+        .into_iter()
+        .filter_map(|(team_id, classes)| {
+            team_division(
+                &competition.divisions.0,
+                competition.division_rule,
+                &classes,
+            )
+            .map(|division| (team_id, division.name.clone()))
+        })
+        .collect())
+}
+
+/// Returns the teams in the division called `name`
+pub async fn get_division_teams(
+    db: &impl ConnectionTrait,
+    competition: &competitions::Model,
+    name: &str,
+) -> Result<HashSet<Uuid>> {
+    if !competition
+        .divisions
+        .0
+        .iter()
+        .any(|division| division.name == name)
+    {
+        return Err(error::DIVISION_NOT_FOUND);
+    }
+
+    Ok(get_team_divisions(db, competition)
+        .await?
+        .into_iter()
+        .filter(|(_, division)| division == name)
+        .map(|(team_id, _)| team_id)
+        .collect())
+}
+
+#[cfg(test)]
+fn divisions() -> Vec<Division> {
+    vec![
+        Division {
+            name: "junior".to_owned(),
+            min_class: Class::Nine,
+            max_class: Class::Ten,
+        },
+        Division {
+            name: "senior".to_owned(),
+            min_class: Class::Eleven,
+            max_class: Class::Twelve,
+        },
+    ]
+}
+
+#[test]
+fn mixed_team_division() {
+    let divisions = divisions();
+    let classes = [Class::Nine, Class::Nine, Class::Eleven];
+
+    let division = |rule| team_division(&divisions, rule, &classes).map(|d| d.name.as_str());
+
+    assert_eq!(division(DivisionRule::HighestClass), Some("senior"));
+    assert_eq!(division(DivisionRule::LowestClass), Some("junior"));
+    assert_eq!(division(DivisionRule::Majority), Some("junior"));
+}
+
+#[test]
+fn majority_tie_goes_up() {
+    let divisions = divisions();
+    let classes = [Class::Ten, Class::Twelve];
+
+    assert_eq!(
+        team_division(&divisions, DivisionRule::Majority, &classes).map(|d| d.name.as_str()),
+        Some("senior")
+    );
+    assert_eq!(team_division(&[], DivisionRule::Majority, &classes), None);
+}
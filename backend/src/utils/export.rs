@@ -0,0 +1,208 @@
+use crate::error::{self, Result};
+use axum::{
+    http::{HeaderValue, header},
+    response::{IntoResponse, Response},
+};
+use chrono::{DateTime, Utc};
+use rust_xlsxwriter::{Format, Workbook, XlsxError};
+use serde::Deserialize;
+use uuid::Uuid;
+
+const CSV_CONTENT_TYPE: &str = "text/csv; charset=utf-8";
+const XLSX_CONTENT_TYPE: &str = "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet";
+
+#[derive(Debug, Default, Clone, Copy, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ExportFormat {
+    #[default]
+    Csv,
+    Xlsx,
+}
+
+#[derive(Debug)]
+pub enum Cell {
+    Empty,
+    Text(String),
+    Number(i64),
+}
+
+impl From<String> for Cell {
+    fn from(value: String) -> Self {
+        Cell::Text(value)
+    }
+}
+
+impl From<&str> for Cell {
+    fn from(value: &str) -> Self {
+        Cell::Text(value.to_owned())
+    }
+}
+
+impl From<Uuid> for Cell {
+    fn from(value: Uuid) -> Self {
+        Cell::Text(value.to_string())
+    }
+}
+
+impl From<DateTime<Utc>> for Cell {
+    fn from(value: DateTime<Utc>) -> Self {
+        Cell::Text(value.to_rfc3339())
+    }
+}
+
+impl From<bool> for Cell {
+    fn from(value: bool) -> Self {
+        Cell::Text(value.to_string())
+    }
+}
+
+impl From<i64> for Cell {
+    fn from(value: i64) -> Self {
+        Cell::Number(value)
+    }
+}
+
+impl From<u64> for Cell {
+    fn from(value: u64) -> Self {
+        Cell::Number(value as i64)
+    }
+}
+
+impl From<i16> for Cell {
+    fn from(value: i16) -> Self {
+        Cell::Number(value.into())
+    }
+}
+
+impl<T: Into<Cell>> From<Option<T>> for Cell {
+    fn from(value: Option<T>) -> Self {
+        value.map_or(Cell::Empty, Into::into)
+    }
+}
+
+impl Cell {
+    /// The text of the cell in a CSV file.
+    ///
+    /// Spreadsheet programs run text starting with `=`, `+`, `-` or `@` as a formula, so
+    /// those are escaped unless they are numbers.
+    fn to_csv(&self) -> String {
+        match self {
+            Cell::Empty => String::new(),
+            Cell::Number(number) => number.to_string(),
+            Cell::Text(text) => match text.chars().next() {
+                Some('=' | '+' | '@') => format!("'{text}"),
+                Some('-') if text.parse::<f64>().is_err() => format!("'{text}"),
+                _ => text.clone(),
+            },
+        }
+    }
+}
+
+/// A table of exported data, it is sent as a CSV file or as an XLSX workbook with one sheet
+#[derive(Debug)]
+pub struct Table {
+    name: &'static str,
+    headers: &'static [&'static str],
+    rows: Vec<Vec<Cell>>,
+}
+
+impl Table {
+    pub fn new(name: &'static str, headers: &'static [&'static str]) -> Self {
+        Self {
+            name,
+            headers,
+            rows: Vec::new(),
+        }
+    }
+
+    pub fn push(&mut self, row: Vec<Cell>) {
+        debug_assert_eq!(row.len(), self.headers.len());
+        self.rows.push(row);
+    }
+
+    pub fn into_response(self, format: ExportFormat) -> Result<Response> {
+        let (body, content_type, extension) = match format {
+            ExportFormat::Csv => (
+                self.to_csv().map_err(|err| {
+                    error!("failed to create csv: {:?}", err);
+                    error::FAILED_TO_EXPORT
+                })?,
+                CSV_CONTENT_TYPE,
+                "csv",
+            ),
+            ExportFormat::Xlsx => (
+                self.to_xlsx().map_err(|err| {
+                    error!("failed to create xlsx: {:?}", err);
+                    error::FAILED_TO_EXPORT
+                })?,
+                XLSX_CONTENT_TYPE,
+                "xlsx",
+            ),
+        };
+
+        let disposition = format!("attachment; filename=\"{}.{extension}\"", self.name);
+
+        Ok((
+            [
+                (header::CONTENT_TYPE, HeaderValue::from_static(content_type)),
+                (
+                    header::CONTENT_DISPOSITION,
+                    HeaderValue::try_from(disposition).map_err(|_| error::FAILED_TO_EXPORT)?,
+                ),
+            ],
+            body,
+        )
+            .into_response())
+    }
+
+    fn to_csv(&self) -> std::result::Result<Vec<u8>, csv::Error> {
+        let mut writer = csv::Writer::from_writer(Vec::new());
+        writer.write_record(self.headers)?;
+
+        for row in &self.rows {
+            writer.write_record(row.iter().map(Cell::to_csv))?;
+        }
+
+        writer.into_inner().map_err(|err| err.into_error().into())
+    }
+
+    fn to_xlsx(&self) -> std::result::Result<Vec<u8>, XlsxError> {
+        let mut workbook = Workbook::new();
+        let worksheet = workbook.add_worksheet();
+        worksheet.set_name(self.name)?;
+
+        let bold = Format::new().set_bold();
+
+        for (col, header) in (0..).zip(self.headers) {
+            worksheet.write_string_with_format(0, col, *header, &bold)?;
+        }
+
+        for (row, cells) in (1..).zip(&self.rows) {
+            for (col, cell) in (0..).zip(cells) {
+                match cell {
+                    Cell::Empty => {}
+                    Cell::Text(text) => {
+                        worksheet.write_string(row, col, text)?;
+                    }
+                    Cell::Number(number) => {
+                        worksheet.write_number(row, col, *number as f64)?;
+                    }
+                }
+            }
+        }
+
+        worksheet.set_freeze_panes(1, 0)?;
+        worksheet.autofit();
+
+        workbook.save_to_buffer()
+    }
+}
+
+#[test]
+fn escape_csv_formulas() {
+    assert_eq!(Cell::from("=1+1").to_csv(), "'=1+1");
+    assert_eq!(Cell::from("-cmd").to_csv(), "'-cmd");
+    assert_eq!(Cell::from("-12").to_csv(), "-12");
+    assert_eq!(Cell::from(-12i64).to_csv(), "-12");
+    assert_eq!(Cell::from(None::<String>).to_csv(), "");
+}
@@ -0,0 +1,18 @@
+use crate::{
+    StateTrait,
+    error::{self, Result},
+};
+use uuid::Uuid;
+
+/// Gets the name of a user from the IAM
+pub async fn get_user_name<S: StateTrait>(state: &S, user_id: Uuid) -> Result<String> {
+    Ok(state
+        .iam_app()
+        .get_user_info(&format!("UserID-{user_id}"))
+        .await
+        .map_err(|error| {
+            error!("iam error: {:?}", error);
+            error::IAM_FAILED_GET_NAME
+        })?
+        .name)
+}
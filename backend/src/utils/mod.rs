@@ -1,11 +1,27 @@
+mod answer;
+mod divisions;
+mod export;
+mod iam;
 mod join_code;
 mod orm;
 pub mod panic;
 mod problems;
+mod qr_code;
+mod release;
+mod scoreboards;
 mod signal;
+mod times;
 pub mod topics;
 
+pub use answer::*;
+pub use divisions::*;
+pub use export::*;
+pub use iam::*;
 pub use join_code::*;
 pub use orm::*;
 pub use problems::*;
+pub use qr_code::*;
+pub use release::*;
+pub use scoreboards::*;
 pub use signal::*;
+pub use times::*;
@@ -19,17 +19,28 @@ use tokio::{
 use uuid::Uuid;
 
 #[derive(Debug, FromQueryResult)]
+struct CompetitionProblem {
+    competition: Uuid,
+    id: Uuid,
+    body: String,
+    image: Option<String>,
+    release_minutes: i32,
+    next: Option<Uuid>,
+}
+
+#[derive(Debug, Clone)]
 pub struct Problem {
     pub id: Uuid,
     pub body: String,
     pub image: Option<String>,
+    pub release_minutes: i32,
     pub next: Option<Uuid>,
 }
 
 #[derive(Debug)]
 pub struct Problems {
-    problems: Arc<RwLock<Vec<Problem>>>,
-    channel: broadcast::Sender<Event>,
+    problems: Arc<RwLock<HashMap<Uuid, Vec<Problem>>>>,
+    channel: broadcast::Sender<(Uuid, Event)>,
 }
 
 impl Problems {
@@ -46,20 +57,42 @@ impl Problems {
 
         let res = problems_order::Entity::find()
             .select_only()
+            .column(problems::Column::Competition)
             .column(problems::Column::Id)
             .column(problems::Column::Body)
             .column(problems::Column::Solution)
             .column(problems::Column::Image)
+            .column(problems::Column::ReleaseMinutes)
             .column(problems_order::Column::Next)
             .join(JoinType::InnerJoin, problems_order::Relation::Problem.def())
-            .into_model::<Problem>()
+            .into_model::<CompetitionProblem>()
             .all(&txn)
             .await
             .expect("failed to query the problems");
 
-        let problems = Arc::new(RwLock::new(sort_linked(res)));
+        let mut by_competition: HashMap<Uuid, Vec<Problem>> = HashMap::new();
+
+        for problem in res {
+            by_competition
+                .entry(problem.competition)
+                .or_default()
+                .push(Problem {
+                    id: problem.id,
+                    body: problem.body,
+                    image: problem.image,
+                    release_minutes: problem.release_minutes,
+                    next: problem.next,
+                });
+        }
+
+        let by_competition: HashMap<_, _> = by_competition
+            .into_iter()
+            .map(|(competition, problems)| (competition, sort_linked(problems)))
+            .collect();
+
+        let problems = Arc::new(RwLock::new(by_competition));
 
-        let mut subscription = nats.subscribe(topics::problems()).await.unwrap();
+        let mut subscription = nats.subscribe(topics::all_problems()).await.unwrap();
 
         txn.commit().await.expect("failed to commit transaction");
 
@@ -70,10 +103,16 @@ impl Problems {
             let tx = tx.clone();
             async move {
                 while let Some(message) = subscription.next().await {
+                    let Some(competition) = topics::competition_from_subject(&message.subject)
+                    else {
+                        warn!("problems message on unknown subject: {}", message.subject);
+                        continue;
+                    };
                     let event: Event = serde_json::from_slice(&message.payload).unwrap();
-                    debug!("problems message: {event:?}");
+                    debug!("problems message: {competition} {event:?}");
 
-                    let mut guard = problems.write().await;
+                    let mut all_guard = problems.write().await;
+                    let guard = all_guard.entry(competition).or_default();
 
                     match &event {
                         Event::InsertProblem {
@@ -81,11 +120,13 @@ impl Problems {
                             id,
                             body,
                             image,
+                            release_minutes,
                         } => {
                             let problem = Problem {
                                 id: *id,
                                 body: body.clone(),
                                 image: image.clone(),
+                                release_minutes: release_minutes.unwrap_or_default(),
                                 next: None,
                             };
                             if let Some(before) = before {
@@ -131,12 +172,20 @@ impl Problems {
                                 warn!("no problems with id: {}", id);
                             }
                         }
+                        Event::ReleaseProblems {
+                            ids,
+                            release_minutes,
+                        } => {
+                            for problem in guard.iter_mut().filter(|p| ids.contains(&p.id)) {
+                                problem.release_minutes = *release_minutes;
+                            }
+                        }
                         _ => unreachable!(),
                     };
 
-                    drop(guard);
+                    drop(all_guard);
 
-                    let _ = tx.send(event);
+                    let _ = tx.send((competition, event));
                 }
             }
         });
@@ -147,28 +196,35 @@ impl Problems {
         }
     }
 
-    pub async fn stream(&self) -> (ProblemStream, ProblemStream) {
-        let (tx, rx) = mpsc::unbounded_channel();
+    /// Returns the current problems of a competition in order
+    pub async fn snapshot(&self, competition_id: Uuid) -> Vec<Problem> {
+        let guard = self.problems.read().await;
+
+        guard.get(&competition_id).cloned().unwrap_or_default()
+    }
+
+    /// Forgets the problems of a deleted competition
+    pub async fn remove(&self, competition_id: &Uuid) {
+        self.problems.write().await.remove(competition_id);
+    }
+
+    /// Returns the current problems of a competition and the stream of their changes
+    pub async fn stream(&self, competition_id: Uuid) -> (Vec<Problem>, ProblemStream) {
         let (tx2, rx2) = mpsc::unbounded_channel();
 
         let guard = self.problems.read().await;
 
-        for problem in guard.iter() {
-            tx.send(Event::InsertProblem {
-                before: None,
-                id: problem.id,
-                body: problem.body.clone(),
-                image: problem.image.clone(),
-            })
-            .unwrap();
-        }
+        let problems = guard.get(&competition_id).cloned().unwrap_or_default();
 
         let mut rx3 = self.channel.subscribe();
         drop(guard);
 
         {
             task::spawn(async move {
-                while let Ok(message) = rx3.recv().await {
+                while let Ok((competition, message)) = rx3.recv().await {
+                    if competition != competition_id {
+                        continue;
+                    }
                     if tx2.send(message).is_err() {
                         break;
                     }
@@ -176,10 +232,7 @@ impl Problems {
             });
         }
 
-        (
-            ProblemStream::Channel { channel: rx },
-            ProblemStream::Channel { channel: rx2 },
-        )
+        (problems, ProblemStream::Channel { channel: rx2 })
     }
 }
 pin_project! {
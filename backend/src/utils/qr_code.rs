@@ -0,0 +1,73 @@
+use crate::error::{self, Result};
+use axum::{
+    http::{HeaderValue, header},
+    response::{IntoResponse, Response},
+};
+use image::{ImageFormat, Luma};
+use qrcode::{QrCode, render::svg};
+use serde::Deserialize;
+use std::io::Cursor;
+
+const SVG_CONTENT_TYPE: &str = "image/svg+xml";
+const PNG_CONTENT_TYPE: &str = "image/png";
+
+/// The size of the rendered image in pixels, large enough to be scanned from a projector
+const MIN_SIZE: u32 = 256;
+
+#[derive(Debug, Default, Clone, Copy, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum QrCodeFormat {
+    #[default]
+    Svg,
+    Png,
+}
+
+/// Renders `data` as a QR code image, the response must not be cached because the data can change
+pub fn render_qr_code(data: &str, format: QrCodeFormat) -> Result<Response> {
+    let code = QrCode::new(data).map_err(|err| {
+        error!("failed to encode qr code: {:?}", err);
+        error::FAILED_TO_RENDER_QR_CODE
+    })?;
+
+    let (body, content_type) = match format {
+        QrCodeFormat::Svg => (
+            code.render::<svg::Color>()
+                .min_dimensions(MIN_SIZE, MIN_SIZE)
+                .build()
+                .into_bytes(),
+            SVG_CONTENT_TYPE,
+        ),
+        QrCodeFormat::Png => {
+            let image = code
+                .render::<Luma<u8>>()
+                .min_dimensions(MIN_SIZE, MIN_SIZE)
+                .build();
+
+            let mut body = Vec::new();
+            image
+                .write_to(&mut Cursor::new(&mut body), ImageFormat::Png)
+                .map_err(|err| {
+                    error!("failed to create png: {:?}", err);
+                    error::FAILED_TO_RENDER_QR_CODE
+                })?;
+
+            (body, PNG_CONTENT_TYPE)
+        }
+    };
+
+    Ok((
+        [
+            (header::CONTENT_TYPE, HeaderValue::from_static(content_type)),
+            (header::CACHE_CONTROL, HeaderValue::from_static("no-store")),
+        ],
+        body,
+    )
+        .into_response())
+}
+
+#[test]
+fn png_qr_code() {
+    let res = render_qr_code("ABC123", QrCodeFormat::Png).unwrap();
+
+    assert_eq!(res.headers()[header::CONTENT_TYPE], PNG_CONTENT_TYPE);
+}
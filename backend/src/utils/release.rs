@@ -0,0 +1,211 @@
+use crate::{handlers::socket::Event, utils::Problem};
+use chrono::{DateTime, Duration, Utc};
+use std::collections::HashSet;
+use uuid::Uuid;
+
+/// Returns when a problem is released for a team that started at `start_time`
+pub fn release_time(start_time: DateTime<Utc>, release_minutes: i32) -> DateTime<Utc> {
+    start_time + Duration::minutes(release_minutes.into())
+}
+
+/// The problems that are not released yet for a team.
+///
+/// A team gets the `InsertProblem` event of a problem when it is released, every other change
+/// of a locked problem is hidden from them.
+#[derive(Debug)]
+pub struct LockedProblems {
+    start_time: DateTime<Utc>,
+    locked: HashSet<Uuid>,
+}
+
+impl LockedProblems {
+    pub fn new(start_time: DateTime<Utc>) -> Self {
+        Self {
+            start_time,
+            locked: HashSet::new(),
+        }
+    }
+
+    pub fn set_start_time(&mut self, start_time: DateTime<Utc>) {
+        self.start_time = start_time;
+    }
+
+    /// Locks every problem, then releases the ones that are due
+    pub fn initial(&mut self, problems: &[Problem], now: DateTime<Utc>) -> Vec<Event> {
+        self.locked = problems.iter().map(|problem| problem.id).collect();
+        self.release(problems, now)
+    }
+
+    /// Releases the problems that are due, returns their `InsertProblem` events
+    pub fn release(&mut self, problems: &[Problem], now: DateTime<Utc>) -> Vec<Event> {
+        let mut events = Vec::new();
+
+        for (index, problem) in problems.iter().enumerate() {
+            if !self.locked.contains(&problem.id)
+                || release_time(self.start_time, problem.release_minutes) > now
+            {
+                continue;
+            }
+
+            // Only the problems after this one are checked, so it can be unlocked right away
+            events.push(insert_event(problem, self.next_released(problems, index)));
+            self.locked.remove(&problem.id);
+        }
+
+        events
+    }
+
+    /// Returns when the next locked problem is released
+    pub fn next_release(&self, problems: &[Problem]) -> Option<DateTime<Utc>> {
+        problems
+            .iter()
+            .filter(|problem| self.locked.contains(&problem.id))
+            .map(|problem| release_time(self.start_time, problem.release_minutes))
+            .min()
+    }
+
+    /// Turns a change of the problems into the events the team should get
+    pub fn filter(&mut self, event: Event, problems: &[Problem], now: DateTime<Utc>) -> Vec<Event> {
+        match event {
+            Event::InsertProblem {
+                before,
+                id,
+                body,
+                image,
+                release_minutes,
+            } => {
+                if release_time(self.start_time, release_minutes.unwrap_or_default()) > now {
+                    self.locked.insert(id);
+                    return Vec::new();
+                }
+
+                let before = match before {
+                    Some(before) if self.locked.contains(&before) => problems
+                        .iter()
+                        .position(|problem| problem.id == id)
+                        .and_then(|index| self.next_released(problems, index)),
+                    before => before,
+                };
+
+                vec![Event::InsertProblem {
+                    before,
+                    id,
+                    body,
+                    image,
+                    release_minutes: None,
+                }]
+            }
+            Event::DeleteProblem { id } if self.locked.remove(&id) => Vec::new(),
+            Event::SwapProblems { id1, id2 } => {
+                match (self.locked.contains(&id1), self.locked.contains(&id2)) {
+                    (false, false) => vec![event],
+                    (true, true) => Vec::new(),
+                    // The released problem takes the place of a locked one, so it has to be moved
+                    (locked1, _) => {
+                        let id = if locked1 { id2 } else { id1 };
+                        let mut events = vec![Event::DeleteProblem { id }];
+
+                        if let Some(index) = problems.iter().position(|problem| problem.id == id) {
+                            events.push(insert_event(
+                                &problems[index],
+                                self.next_released(problems, index),
+                            ));
+                        }
+
+                        events
+                    }
+                }
+            }
+            Event::UpdateProblem { id, .. } if self.locked.contains(&id) => Vec::new(),
+            Event::ReleaseProblems { .. } => self.release(problems, now),
+            event => vec![event],
+        }
+    }
+
+    /// Returns the first released problem after `index`
+    fn next_released(&self, problems: &[Problem], index: usize) -> Option<Uuid> {
+        problems[index + 1..]
+            .iter()
+            .find(|problem| !self.locked.contains(&problem.id))
+            .map(|problem| problem.id)
+    }
+}
+
+fn insert_event(problem: &Problem, before: Option<Uuid>) -> Event {
+    Event::InsertProblem {
+        before,
+        id: problem.id,
+        body: problem.body.clone(),
+        image: problem.image.clone(),
+        release_minutes: None,
+    }
+}
+
+#[cfg(test)]
+fn problem(id: u128, release_minutes: i32) -> Problem {
+    Problem {
+        id: Uuid::from_u128(id),
+        body: String::new(),
+        image: None,
+        release_minutes,
+        next: None,
+    }
+}
+
+#[cfg(test)]
+fn inserted(events: &[Event]) -> Vec<(u128, Option<u128>)> {
+    events
+        .iter()
+        .map(|event| match event {
+            Event::InsertProblem { id, before, .. } => {
+                (id.as_u128(), before.map(|before| before.as_u128()))
+            }
+            event => panic!("unexpected event: {event:?}"),
+        })
+        .collect()
+}
+
+#[test]
+fn release_in_blocks() {
+    let start_time = DateTime::UNIX_EPOCH;
+    let problems = [problem(1, 0), problem(2, 30), problem(3, 0), problem(4, 30)];
+
+    let mut locked = LockedProblems::new(start_time);
+
+    let events = locked.initial(&problems, start_time);
+    assert_eq!(inserted(&events), [(1, None), (3, None)]);
+    assert_eq!(
+        locked.next_release(&problems),
+        Some(start_time + Duration::minutes(30))
+    );
+
+    let events = locked.release(&problems, start_time + Duration::minutes(30));
+    assert_eq!(inserted(&events), [(2, Some(3)), (4, None)]);
+    assert_eq!(locked.next_release(&problems), None);
+}
+
+#[test]
+fn hide_locked_changes() {
+    let start_time = DateTime::UNIX_EPOCH;
+    let mut problems = [problem(1, 0), problem(2, 30)];
+
+    let mut locked = LockedProblems::new(start_time);
+    locked.initial(&problems, start_time);
+
+    let update = Event::UpdateProblem {
+        id: Uuid::from_u128(2),
+        body: None,
+        image: None,
+    };
+    assert!(locked.filter(update, &problems, start_time).is_empty());
+
+    // The cache is updated before the event is handled
+    problems.swap(0, 1);
+    let swap = Event::SwapProblems {
+        id1: Uuid::from_u128(1),
+        id2: Uuid::from_u128(2),
+    };
+    let events = locked.filter(swap, &problems, start_time);
+    assert!(matches!(events[0], Event::DeleteProblem { id } if id.as_u128() == 1));
+    assert_eq!(inserted(&events[1..]), [(1, None)]);
+}
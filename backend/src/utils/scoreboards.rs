@@ -0,0 +1,104 @@
+use crate::{
+    error::Result,
+    handlers::{socket::Event, stats::scoreboard},
+};
+use axum::extract::ws::Utf8Bytes;
+use chrono::{DateTime, Utc};
+use sea_orm::ConnectionTrait;
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+use uuid::Uuid;
+
+#[derive(Debug)]
+struct Cached {
+    /// When the calculation started, every change before this is included
+    calculated_at: DateTime<Utc>,
+    payload: Utf8Bytes,
+}
+
+#[derive(Debug, Default)]
+struct CompetitionScoreboards {
+    live: Option<Cached>,
+    /// The frozen scoreboard with the time it is frozen at
+    frozen: Option<(DateTime<Utc>, Cached)>,
+}
+
+/// The last calculated scoreboards of the competitions.
+///
+/// The scoreboard sockets of a competition share the calculation, so the number of viewers
+/// doesn't change the load on the database.
+#[derive(Debug, Default)]
+pub struct Scoreboards {
+    competitions: Mutex<HashMap<Uuid, Arc<tokio::sync::Mutex<CompetitionScoreboards>>>>,
+}
+
+impl Scoreboards {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the `UPDATE_SCOREBOARD` event of the live scoreboard, or of the one frozen at
+    /// `frozen_at`. It is calculated again only if the cached one is older than `changed_at`.
+    pub async fn get(
+        &self,
+        db: &impl ConnectionTrait,
+        competition_id: Uuid,
+        frozen_at: Option<DateTime<Utc>>,
+        changed_at: DateTime<Utc>,
+    ) -> Result<Utf8Bytes> {
+        let competition = Arc::clone(
+            self.competitions
+                .lock()
+                .unwrap()
+                .entry(competition_id)
+                .or_default(),
+        );
+
+        // The sockets that need the scoreboard at the same time wait for one calculation
+        let mut competition = competition.lock().await;
+
+        let cached = match frozen_at {
+            Some(frozen_at) => competition
+                .frozen
+                .as_ref()
+                .filter(|(cached_frozen_at, _)| *cached_frozen_at == frozen_at)
+                .map(|(_, cached)| cached),
+            None => competition.live.as_ref(),
+        };
+
+        if let Some(cached) = cached.filter(|cached| cached.calculated_at >= changed_at) {
+            return Ok(cached.payload.clone());
+        }
+
+        let calculated_at = Utc::now();
+        let scoreboard =
+            scoreboard::calculate(db, competition_id, frozen_at.unwrap_or(calculated_at), None)
+                .await?;
+
+        let payload: Utf8Bytes = serde_json::to_string(&Event::UpdateScoreboard {
+            frozen: frozen_at.is_some(),
+            scoreboard,
+        })
+        .unwrap()
+        .into();
+
+        let cached = Cached {
+            calculated_at,
+            payload: payload.clone(),
+        };
+
+        match frozen_at {
+            Some(frozen_at) => competition.frozen = Some((frozen_at, cached)),
+            None => competition.live = Some(cached),
+        }
+
+        Ok(payload)
+    }
+
+    /// Forgets the scoreboards of a deleted competition
+    pub fn remove(&self, competition_id: &Uuid) {
+        self.competitions.lock().unwrap().remove(competition_id);
+    }
+}
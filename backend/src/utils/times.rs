@@ -0,0 +1,73 @@
+use crate::error::{self, Result};
+use chrono::{DateTime, TimeDelta, Utc};
+use entity::{pauses, team_times, times};
+use sea_orm::{ColumnTrait, Condition, ConnectionTrait, EntityTrait, QueryFilter};
+use uuid::Uuid;
+
+/// Returns the `start_time` and the `end_time` of the competition
+pub async fn get_times(
+    db: &impl ConnectionTrait,
+    competition_id: Uuid,
+) -> Result<(DateTime<Utc>, DateTime<Utc>)> {
+    let start_time = times::Entity::find_start_time(competition_id)
+        .one(db)
+        .await?;
+    let end_time = times::Entity::find_end_time(competition_id).one(db).await?;
+
+    let (Some(start_time), Some(end_time)) = (start_time, end_time) else {
+        error!("start_time or end_time is not found in the database");
+        return Err(error::INTERNAL);
+    };
+
+    Ok((start_time.time, end_time.time))
+}
+
+/// Returns the effective `start_time` and `end_time` of a team, that is the times of the
+/// competition with the overrides and extra minutes of the team applied
+pub async fn get_team_times(
+    db: &impl ConnectionTrait,
+    competition_id: Uuid,
+    team_id: Uuid,
+) -> Result<(DateTime<Utc>, DateTime<Utc>)> {
+    let (start_time, end_time) = get_times(db, competition_id).await?;
+
+    let Some(team_time) = team_times::Entity::find_by_id(team_id).one(db).await? else {
+        return Ok((start_time, end_time));
+    };
+
+    Ok((
+        team_time.start_time.unwrap_or(start_time),
+        team_time.end_time.unwrap_or(end_time) + TimeDelta::minutes(team_time.extra_minutes.into()),
+    ))
+}
+
+/// Returns how long the competition was paused between `since` and `now`, an ongoing pause is
+/// counted until `now`.
+///
+/// The problems are released by the running time of the competition, so the release of a team
+/// that started at `since` is moved later by this.
+pub async fn get_paused_duration(
+    db: &impl ConnectionTrait,
+    competition_id: Uuid,
+    since: DateTime<Utc>,
+    now: DateTime<Utc>,
+) -> Result<TimeDelta> {
+    let res = pauses::Entity::find()
+        .filter(pauses::Column::Competition.eq(competition_id))
+        .filter(pauses::Column::StartTime.lt(now))
+        .filter(
+            Condition::any()
+                .add(pauses::Column::EndTime.is_null())
+                .add(pauses::Column::EndTime.gt(since)),
+        )
+        .all(db)
+        .await?;
+
+    Ok(res
+        .into_iter()
+        .map(|pause| {
+            let paused_for = pause.end_time.unwrap_or(now).min(now) - pause.start_time.max(since);
+            paused_for.max(TimeDelta::zero())
+        })
+        .sum())
+}
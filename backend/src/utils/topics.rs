@@ -1,25 +1,70 @@
 use uuid::{Uuid, fmt::Simple};
 
 #[inline(always)]
-pub fn team_info(team_id: &Uuid) -> String {
+fn encode(id: &Uuid) -> String {
     let mut buf = [0u8; Simple::LENGTH];
-    let id = team_id.as_simple().encode_lower(&mut buf);
-    format!("team.{id}.info")
+    id.as_simple().encode_lower(&mut buf).to_owned()
 }
 
 #[inline(always)]
-pub fn team_solutions(team_id: &Uuid) -> String {
-    let mut buf = [0u8; Simple::LENGTH];
-    let id = team_id.as_simple().encode_lower(&mut buf);
-    format!("team.{id}.solutions")
+pub fn team_info(competition_id: &Uuid, team_id: &Uuid) -> String {
+    format!(
+        "competition.{}.team.{}.info",
+        encode(competition_id),
+        encode(team_id)
+    )
+}
+
+#[inline(always)]
+pub fn team_solutions(competition_id: &Uuid, team_id: &Uuid) -> String {
+    format!(
+        "competition.{}.team.{}.solutions",
+        encode(competition_id),
+        encode(team_id)
+    )
+}
+
+/// The invitations of a user who is not in a team yet
+#[inline(always)]
+pub fn user_invitations(competition_id: &Uuid, user_id: &Uuid) -> String {
+    format!(
+        "competition.{}.user.{}.invitations",
+        encode(competition_id),
+        encode(user_id)
+    )
+}
+
+#[inline(always)]
+pub fn all_team_solutions(competition_id: &Uuid) -> String {
+    format!("competition.{}.team.*.solutions", encode(competition_id))
 }
 
 #[inline(always)]
-pub fn problems() -> &'static str {
-    "info.problems"
+pub fn problems(competition_id: &Uuid) -> String {
+    format!("competition.{}.problems", encode(competition_id))
 }
 
 #[inline(always)]
-pub const fn times() -> &'static str {
-    "info.times"
+pub const fn all_problems() -> &'static str {
+    "competition.*.problems"
+}
+
+#[inline(always)]
+pub fn times(competition_id: &Uuid) -> String {
+    format!("competition.{}.times", encode(competition_id))
+}
+
+#[inline(always)]
+pub fn rescoring(competition_id: &Uuid) -> String {
+    format!("competition.{}.rescoring", encode(competition_id))
+}
+
+/// Returns the competition id from a topic created by this module
+pub fn competition_from_subject(subject: &str) -> Option<Uuid> {
+    let mut parts = subject.split('.');
+
+    match (parts.next(), parts.next()) {
+        (Some("competition"), Some(id)) => Uuid::try_parse(id).ok(),
+        _ => None,
+    }
 }
@@ -1,5 +1,337 @@
+use chrono::Utc;
 use test_utils::prelude::*;
 
+mod manage {
+    use super::*;
+
+    #[tokio::test]
+    #[parallel]
+    async fn create_not_admin() {
+        let app = get_cached_app().await;
+        let user = iam::register_user().await;
+
+        let res = app
+            .post("/v1/competition")
+            .user(&user)
+            .json(&json!({
+                "name": uuid(),
+            }))
+            .send()
+            .await;
+
+        assert_error!(res, error::NOT_ENOUGH_PERMISSIONS);
+    }
+
+    #[tokio::test]
+    #[parallel]
+    async fn not_found() {
+        let app = get_cached_app().await;
+        let user = app.register_user().await;
+
+        let res = app
+            .post(&format!("/v1/competition/{}/team/create", uuid()))
+            .user(&user)
+            .json(&json!({
+                "name": uuid(),
+            }))
+            .send()
+            .await;
+
+        assert_error!(res, error::COMPETITION_NOT_FOUND);
+    }
+
+    #[tokio::test]
+    #[parallel]
+    async fn create_list_update_delete() {
+        let app = get_cached_app().await;
+
+        let admin = iam::register_user().await;
+        iam::make_admin(&admin).await;
+
+        let name = uuid();
+
+        let res = app
+            .post("/v1/competition")
+            .user(&admin)
+            .json(&json!({
+                "name": name,
+            }))
+            .send()
+            .await;
+
+        assert_eq!(res.status(), StatusCode::CREATED);
+
+        let body: Value = res.json().await;
+        let id = body["id"].as_str().unwrap().to_owned();
+
+        let res = app.get("/v1/competition").send().await;
+        assert_eq!(res.status(), StatusCode::OK);
+
+        let body: Value = res.json().await;
+        assert!(body.as_array().unwrap().contains(&json!({
+            "id": id,
+            "name": name,
+            "tie_breakers": ["LAST_CORRECT_TIME", "WRONG_ANSWERS"],
+            "divisions": [],
+            "division_rule": "HIGHEST_CLASS",
+            "min_team_size": 1,
+            "max_team_size": 8,
+        })));
+
+        let res = app
+            .get(&format!("/v1/competition/{id}/time"))
+            .user(&admin)
+            .send()
+            .await;
+
+        assert_eq!(res.status(), StatusCode::OK);
+        assert_json_eq!(
+            res.json::<Value>().await,
+            json!({
+                "start_time": 0,
+                "end_time": 0,
+            })
+        );
+
+        let new_name = uuid();
+
+        let res = app
+            .patch(&format!("/v1/competition/{id}"))
+            .user(&admin)
+            .json(&json!({
+                "name": new_name,
+            }))
+            .send()
+            .await;
+
+        assert_eq!(res.status(), StatusCode::NO_CONTENT);
+
+        let res = app
+            .delete(&format!("/v1/competition/{id}"))
+            .user(&admin)
+            .send()
+            .await;
+
+        assert_eq!(res.status(), StatusCode::NO_CONTENT);
+
+        let res = app
+            .get(&format!("/v1/competition/{id}/time"))
+            .user(&admin)
+            .send()
+            .await;
+
+        assert_error!(res, error::COMPETITION_NOT_FOUND);
+    }
+
+    #[tokio::test]
+    #[parallel]
+    async fn tie_breakers() {
+        let app = get_cached_app().await;
+
+        let admin = iam::register_user().await;
+        iam::make_admin(&admin).await;
+
+        let res = app
+            .post("/v1/competition")
+            .user(&admin)
+            .json(&json!({
+                "name": uuid(),
+                "tie_breakers": ["WRONG_ANSWERS"],
+            }))
+            .send()
+            .await;
+
+        assert_eq!(res.status(), StatusCode::CREATED);
+
+        let body: Value = res.json().await;
+        assert_eq!(body["tie_breakers"], json!(["WRONG_ANSWERS"]));
+        let id = body["id"].as_str().unwrap().to_owned();
+
+        let res = app
+            .patch(&format!("/v1/competition/{id}"))
+            .user(&admin)
+            .json(&json!({
+                "tie_breakers": ["WRONG_ANSWERS", "WRONG_ANSWERS"],
+            }))
+            .send()
+            .await;
+
+        assert_error!(res, error::JSON_VALIDATE_INVALID);
+
+        let res = app
+            .patch(&format!("/v1/competition/{id}"))
+            .user(&admin)
+            .json(&json!({
+                "tie_breakers": ["LAST_CORRECT_TIME"],
+            }))
+            .send()
+            .await;
+
+        assert_eq!(res.status(), StatusCode::NO_CONTENT);
+
+        let res = app.get("/v1/competition").send().await;
+        assert_eq!(res.status(), StatusCode::OK);
+
+        let body: Value = res.json().await;
+        let competition = body
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|competition| competition["id"] == id)
+            .unwrap();
+
+        assert_eq!(competition["tie_breakers"], json!(["LAST_CORRECT_TIME"]));
+    }
+
+    #[tokio::test]
+    #[parallel]
+    async fn divisions() {
+        let app = get_cached_app().await;
+
+        let admin = iam::register_user().await;
+        iam::make_admin(&admin).await;
+
+        let res = app
+            .post("/v1/competition")
+            .user(&admin)
+            .json(&json!({
+                "name": uuid(),
+                "divisions": [
+                    { "name": "junior", "min_class": 9, "max_class": 10 },
+                    { "name": "senior", "min_class": 11, "max_class": 12 },
+                ],
+                "division_rule": "MAJORITY",
+            }))
+            .send()
+            .await;
+
+        assert_eq!(res.status(), StatusCode::CREATED);
+
+        let body: Value = res.json().await;
+        assert_eq!(body["division_rule"], "MAJORITY");
+        assert_eq!(body["divisions"][1]["name"], "senior");
+        let id = body["id"].as_str().unwrap().to_owned();
+
+        for divisions in [
+            json!([
+                { "name": "junior", "min_class": 9, "max_class": 11 },
+                { "name": "senior", "min_class": 11, "max_class": 12 },
+            ]),
+            json!([
+                { "name": "all", "min_class": 9, "max_class": 12 },
+                { "name": "all", "min_class": 9, "max_class": 12 },
+            ]),
+            json!([{ "name": "backwards", "min_class": 12, "max_class": 9 }]),
+        ] {
+            let res = app
+                .patch(&format!("/v1/competition/{id}"))
+                .user(&admin)
+                .json(&json!({
+                    "divisions": divisions,
+                }))
+                .send()
+                .await;
+
+            assert_error!(res, error::JSON_VALIDATE_INVALID);
+        }
+
+        let res = app
+            .patch(&format!("/v1/competition/{id}"))
+            .user(&admin)
+            .json(&json!({
+                "divisions": [],
+            }))
+            .send()
+            .await;
+
+        assert_eq!(res.status(), StatusCode::NO_CONTENT);
+
+        let res = app.get("/v1/competition").send().await;
+        assert_eq!(res.status(), StatusCode::OK);
+
+        let body: Value = res.json().await;
+        let competition = body
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|competition| competition["id"] == id)
+            .unwrap();
+
+        assert_eq!(competition["divisions"], json!([]));
+        assert_eq!(competition["division_rule"], "MAJORITY");
+    }
+
+    #[tokio::test]
+    #[parallel]
+    async fn duplicate_name() {
+        let app = get_cached_app().await;
+
+        let admin = iam::register_user().await;
+        iam::make_admin(&admin).await;
+
+        let name = uuid();
+
+        let res = app
+            .post("/v1/competition")
+            .user(&admin)
+            .json(&json!({
+                "name": name,
+            }))
+            .send()
+            .await;
+
+        assert_eq!(res.status(), StatusCode::CREATED);
+
+        let res = app
+            .post("/v1/competition")
+            .user(&admin)
+            .json(&json!({
+                "name": name,
+            }))
+            .send()
+            .await;
+
+        assert_error!(res, error::DUPLICATE_COMPETITION_NAME);
+    }
+
+    #[tokio::test]
+    #[parallel]
+    async fn team_in_every_competition() {
+        let app = get_cached_app().await;
+
+        let admin = iam::register_user().await;
+        iam::make_admin(&admin).await;
+
+        let res = app
+            .post("/v1/competition")
+            .user(&admin)
+            .json(&json!({
+                "name": uuid(),
+            }))
+            .send()
+            .await;
+
+        assert_eq!(res.status(), StatusCode::CREATED);
+
+        let body: Value = res.json().await;
+        let id = body["id"].as_str().unwrap().to_owned();
+
+        let user = app.register_user().await;
+        let _ = app.create_team(&user).await;
+
+        let res = app
+            .post(&format!("/v1/competition/{id}/team/create"))
+            .user(&user)
+            .json(&json!({
+                "name": uuid(),
+            }))
+            .send()
+            .await;
+
+        assert_eq!(res.status(), StatusCode::CREATED);
+    }
+}
+
 mod time {
     use super::*;
 
@@ -9,7 +341,11 @@ mod time {
         let app = get_cached_app().await;
         let user = iam::register_user().await;
 
-        let res = app.put("/v1/competition/time").user(&user).send().await;
+        let res = app
+            .put(&format!("/v1/competition/{COMPETITION_ID}/time"))
+            .user(&user)
+            .send()
+            .await;
 
         assert_error!(res, error::NOT_ENOUGH_PERMISSIONS);
     }
@@ -20,7 +356,11 @@ mod time {
         let app = get_cached_app().await;
         let user = iam::register_user().await;
 
-        let res = app.patch("/v1/competition/time").user(&user).send().await;
+        let res = app
+            .patch(&format!("/v1/competition/{COMPETITION_ID}/time"))
+            .user(&user)
+            .send()
+            .await;
 
         assert_error!(res, error::NOT_ENOUGH_PERMISSIONS);
     }
@@ -34,7 +374,7 @@ mod time {
         iam::make_admin(&user).await;
 
         let res = app
-            .patch("/v1/competition/time")
+            .patch(&format!("/v1/competition/{COMPETITION_ID}/time"))
             .user(&user)
             .json(&json!({
                 "start_time": 123,
@@ -44,7 +384,11 @@ mod time {
 
         assert_eq!(res.status(), StatusCode::NO_CONTENT);
 
-        let res = app.get("/v1/competition/time").user(&user).send().await;
+        let res = app
+            .get(&format!("/v1/competition/{COMPETITION_ID}/time"))
+            .user(&user)
+            .send()
+            .await;
 
         assert_eq!(res.status(), StatusCode::OK);
 
@@ -67,7 +411,7 @@ mod time {
         iam::make_admin(&user).await;
 
         let res = app
-            .patch("/v1/competition/time")
+            .patch(&format!("/v1/competition/{COMPETITION_ID}/time"))
             .user(&user)
             .json(&json!({
                 "end_time": 123,
@@ -77,7 +421,11 @@ mod time {
 
         assert_eq!(res.status(), StatusCode::NO_CONTENT);
 
-        let res = app.get("/v1/competition/time").user(&user).send().await;
+        let res = app
+            .get(&format!("/v1/competition/{COMPETITION_ID}/time"))
+            .user(&user)
+            .send()
+            .await;
 
         assert_eq!(res.status(), StatusCode::OK);
 
@@ -100,7 +448,7 @@ mod time {
         iam::make_admin(&user).await;
 
         let res = app
-            .patch("/v1/competition/time")
+            .patch(&format!("/v1/competition/{COMPETITION_ID}/time"))
             .user(&user)
             .json(&json!({
                 "start_time": 432,
@@ -111,7 +459,11 @@ mod time {
 
         assert_eq!(res.status(), StatusCode::NO_CONTENT);
 
-        let res = app.get("/v1/competition/time").user(&user).send().await;
+        let res = app
+            .get(&format!("/v1/competition/{COMPETITION_ID}/time"))
+            .user(&user)
+            .send()
+            .await;
 
         assert_eq!(res.status(), StatusCode::OK);
 
@@ -136,11 +488,14 @@ mod time {
 
         let owner = app.register_user().await;
         let _ = app.create_team(&owner).await;
-        let mut socket = app.socket("/v1/ws").start().await;
+        let mut socket = app
+            .socket(&format!("/v1/competition/{COMPETITION_ID}/ws"))
+            .start()
+            .await;
         assert_team_info!(socket, owner);
 
         let res = app
-            .put("/v1/competition/time")
+            .put(&format!("/v1/competition/{COMPETITION_ID}/time"))
             .user(&admin)
             .json(&json!({
                 "start_time": 1234,
@@ -167,3 +522,510 @@ mod time {
         socket.close(None).await.unwrap();
     }
 }
+
+mod pause {
+    use super::*;
+
+    #[tokio::test]
+    #[parallel]
+    async fn not_admin() {
+        let app = get_cached_app().await;
+        let user = iam::register_user().await;
+
+        let res = app
+            .post(&format!("/v1/competition/{COMPETITION_ID}/pause"))
+            .user(&user)
+            .send()
+            .await;
+
+        assert_error!(res, error::NOT_ENOUGH_PERMISSIONS);
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn pause_and_resume() {
+        let app = get_cached_app().await;
+
+        let admin = iam::register_user().await;
+        iam::make_admin(&admin).await;
+
+        let res = app
+            .post(&format!("/v1/competition/{COMPETITION_ID}/problem"))
+            .user(&admin)
+            .json(&json!({
+                "body": "some body",
+                "solution": 23,
+            }))
+            .send()
+            .await;
+
+        assert_eq!(res.status(), StatusCode::CREATED);
+        let id = res.json::<Value>().await["id"].clone();
+
+        app.start_competition(&admin).await;
+
+        let owner = app.register_user().await;
+        let _ = app.create_team(&owner).await;
+
+        let mut socket = app
+            .socket(&format!("/v1/competition/{COMPETITION_ID}/ws"))
+            .start()
+            .await;
+        assert_team_info!(socket, owner);
+
+        let res = app
+            .post(&format!("/v1/competition/{COMPETITION_ID}/pause"))
+            .user(&admin)
+            .send()
+            .await;
+
+        assert_eq!(res.status(), StatusCode::NO_CONTENT);
+
+        // skip the problems that are sent at the start
+        let message = loop {
+            let message = get_socket_message(socket.next().await);
+            if message["event"] != "INSERT_PROBLEM" {
+                break message;
+            }
+        };
+        assert_event_type!(message, "PAUSE_COMPETITION");
+
+        let res = app
+            .post(&format!("/v1/competition/{COMPETITION_ID}/pause"))
+            .user(&admin)
+            .send()
+            .await;
+
+        assert_error!(res, error::COMPETITION_ALREADY_PAUSED);
+
+        let res = app
+            .post(&format!("/v1/competition/{COMPETITION_ID}/solution"))
+            .user(&owner)
+            .json(&json!({
+                "problem": id,
+                "solution": 23,
+            }))
+            .send()
+            .await;
+
+        assert_error!(res, error::COMPETITION_PAUSED);
+
+        let res = app
+            .get(&format!("/v1/competition/{COMPETITION_ID}/time"))
+            .user(&admin)
+            .send()
+            .await;
+        let end_time = res.json::<Value>().await["end_time"].as_i64().unwrap();
+
+        tokio::time::sleep(std::time::Duration::from_secs(1)).await;
+
+        let res = app
+            .post(&format!("/v1/competition/{COMPETITION_ID}/resume"))
+            .user(&admin)
+            .send()
+            .await;
+
+        assert_eq!(res.status(), StatusCode::NO_CONTENT);
+
+        let message = get_socket_message(socket.next().await);
+        assert_event_type!(message, "RESUME_COMPETITION");
+
+        let message = get_socket_message(socket.next().await);
+        assert_event_type!(message, "UPDATE_TIME");
+
+        let res = app
+            .get(&format!("/v1/competition/{COMPETITION_ID}/time"))
+            .user(&admin)
+            .send()
+            .await;
+        assert!(res.json::<Value>().await["end_time"].as_i64().unwrap() > end_time);
+
+        let res = app
+            .post(&format!("/v1/competition/{COMPETITION_ID}/resume"))
+            .user(&admin)
+            .send()
+            .await;
+
+        assert_error!(res, error::COMPETITION_NOT_PAUSED);
+
+        let res = app
+            .post(&format!("/v1/competition/{COMPETITION_ID}/solution"))
+            .user(&owner)
+            .json(&json!({
+                "problem": id,
+                "solution": 23,
+            }))
+            .send()
+            .await;
+
+        assert_eq!(res.status(), StatusCode::NO_CONTENT);
+
+        socket.close(None).await.unwrap();
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn not_running() {
+        let app = get_cached_app().await;
+
+        let admin = iam::register_user().await;
+        iam::make_admin(&admin).await;
+
+        let now = Utc::now().timestamp();
+
+        app.set_time(&admin, now + 3600, now + 7200).await;
+
+        let res = app
+            .post(&format!("/v1/competition/{COMPETITION_ID}/pause"))
+            .user(&admin)
+            .send()
+            .await;
+
+        assert_error!(res, error::COMPETITION_NOT_STARTED);
+
+        app.set_time(&admin, now - 7200, now - 3600).await;
+
+        let res = app
+            .post(&format!("/v1/competition/{COMPETITION_ID}/pause"))
+            .user(&admin)
+            .send()
+            .await;
+
+        assert_error!(res, error::COMPETITION_ENDED);
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn running_for_team() {
+        let app = get_cached_app().await;
+
+        let admin = iam::register_user().await;
+        iam::make_admin(&admin).await;
+
+        let now = Utc::now().timestamp();
+
+        app.set_time(&admin, now - 7200, now - 60).await;
+
+        let owner = app.register_user().await;
+        let team = app.create_team(&owner).await;
+        let team_id = team.get_id(&admin).await;
+
+        let res = app
+            .put(&format!(
+                "/v1/competition/{COMPETITION_ID}/team/{team_id}/time"
+            ))
+            .user(&admin)
+            .json(&json!({
+                "extra_minutes": 10,
+            }))
+            .send()
+            .await;
+
+        assert_eq!(res.status(), StatusCode::NO_CONTENT);
+
+        let res = app
+            .post(&format!("/v1/competition/{COMPETITION_ID}/pause"))
+            .user(&admin)
+            .send()
+            .await;
+
+        assert_eq!(res.status(), StatusCode::NO_CONTENT);
+
+        let res = app
+            .post(&format!("/v1/competition/{COMPETITION_ID}/resume"))
+            .user(&admin)
+            .send()
+            .await;
+
+        assert_eq!(res.status(), StatusCode::NO_CONTENT);
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn release_after_pause() {
+        let app = get_cached_app().await;
+        app.clean_database().await;
+
+        let admin = iam::register_user().await;
+        iam::make_admin(&admin).await;
+
+        let id = app
+            .create_problem(&admin, json!({"release_minutes": 1}))
+            .await;
+
+        // The problem is released 3 seconds from now without the pause
+        let now = Utc::now().timestamp();
+        app.set_time(&admin, now - 57, now + 3600).await;
+
+        let owner = app.register_user().await;
+        let _ = app.create_team(&owner).await;
+
+        let res = app
+            .post(&format!("/v1/competition/{COMPETITION_ID}/pause"))
+            .user(&admin)
+            .send()
+            .await;
+
+        assert_eq!(res.status(), StatusCode::NO_CONTENT);
+
+        tokio::time::sleep(std::time::Duration::from_secs(4)).await;
+
+        let res = app
+            .post(&format!("/v1/competition/{COMPETITION_ID}/resume"))
+            .user(&admin)
+            .send()
+            .await;
+
+        assert_eq!(res.status(), StatusCode::NO_CONTENT);
+
+        let solution = json!({
+            "problem": id,
+            "solution": 23,
+        });
+
+        let res = app
+            .post(&format!("/v1/competition/{COMPETITION_ID}/solution"))
+            .user(&owner)
+            .json(&solution)
+            .send()
+            .await;
+
+        assert_error!(res, error::PROBLEM_NOT_RELEASED);
+
+        tokio::time::sleep(std::time::Duration::from_secs(4)).await;
+
+        let res = app
+            .post(&format!("/v1/competition/{COMPETITION_ID}/solution"))
+            .user(&owner)
+            .json(&solution)
+            .send()
+            .await;
+
+        assert_eq!(res.status(), StatusCode::NO_CONTENT);
+    }
+}
+
+mod solution {
+    use super::*;
+
+    #[tokio::test]
+    #[serial]
+    async fn not_started() {
+        let app = get_cached_app().await;
+
+        let admin = iam::register_user().await;
+        iam::make_admin(&admin).await;
+
+        let id = app.create_problem(&admin, json!({})).await;
+        app.set_time(&admin, i32::MAX as i64 - 3600, i32::MAX as i64)
+            .await;
+
+        let owner = app.register_user().await;
+        let _ = app.create_team(&owner).await;
+
+        let res = app
+            .post(&format!("/v1/competition/{COMPETITION_ID}/solution"))
+            .user(&owner)
+            .json(&json!({
+                "problem": id,
+                "solution": 23,
+            }))
+            .send()
+            .await;
+
+        assert_error!(res, error::COMPETITION_NOT_STARTED);
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn ended() {
+        let app = get_cached_app().await;
+
+        let admin = iam::register_user().await;
+        iam::make_admin(&admin).await;
+
+        let id = app.create_problem(&admin, json!({})).await;
+        app.set_time(&admin, 0, 3600).await;
+
+        let owner = app.register_user().await;
+        let _ = app.create_team(&owner).await;
+
+        let res = app
+            .post(&format!("/v1/competition/{COMPETITION_ID}/solution"))
+            .user(&owner)
+            .json(&json!({
+                "problem": id,
+                "solution": 23,
+            }))
+            .send()
+            .await;
+
+        assert_error!(res, error::COMPETITION_ENDED);
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn success() {
+        let app = get_cached_app().await;
+
+        let admin = iam::register_user().await;
+        iam::make_admin(&admin).await;
+
+        let id = app.create_problem(&admin, json!({})).await;
+        app.start_competition(&admin).await;
+
+        let owner = app.register_user().await;
+        let _ = app.create_team(&owner).await;
+
+        let res = app
+            .post(&format!("/v1/competition/{COMPETITION_ID}/solution"))
+            .user(&owner)
+            .json(&json!({
+                "problem": id,
+                "solution": 23,
+            }))
+            .send()
+            .await;
+
+        assert_eq!(res.status(), StatusCode::NO_CONTENT);
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn attempt_limit() {
+        let app = get_cached_app().await;
+
+        let admin = iam::register_user().await;
+        iam::make_admin(&admin).await;
+
+        let res = app
+            .post(&format!("/v1/competition/{COMPETITION_ID}/problem"))
+            .user(&admin)
+            .json(&json!({
+                "body": "some body",
+                "solution": 23,
+                "max_attempts": 2,
+            }))
+            .send()
+            .await;
+
+        assert_eq!(res.status(), StatusCode::CREATED);
+
+        let id = res.json::<Value>().await["id"].clone();
+        app.start_competition(&admin).await;
+
+        let owner = app.register_user().await;
+        let _ = app.create_team(&owner).await;
+
+        for solution in [json!(22), json!(null)] {
+            let res = app
+                .post(&format!("/v1/competition/{COMPETITION_ID}/solution"))
+                .user(&owner)
+                .json(&json!({
+                    "problem": id,
+                    "solution": solution,
+                }))
+                .send()
+                .await;
+
+            assert_eq!(res.status(), StatusCode::NO_CONTENT);
+        }
+
+        let res = app
+            .post(&format!("/v1/competition/{COMPETITION_ID}/solution"))
+            .user(&owner)
+            .json(&json!({
+                "problem": id,
+                "solution": 23,
+            }))
+            .send()
+            .await;
+
+        assert_error!(res, error::NO_ATTEMPTS_LEFT);
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn not_released() {
+        let app = get_cached_app().await;
+
+        let admin = iam::register_user().await;
+        iam::make_admin(&admin).await;
+
+        let res = app
+            .post(&format!("/v1/competition/{COMPETITION_ID}/problem"))
+            .user(&admin)
+            .json(&json!({
+                "body": "some body",
+                "solution": 23,
+                // The competition started an hour ago
+                "release_minutes": 120,
+            }))
+            .send()
+            .await;
+
+        assert_eq!(res.status(), StatusCode::CREATED);
+
+        let id = res.json::<Value>().await["id"].clone();
+        app.start_competition(&admin).await;
+
+        let owner = app.register_user().await;
+        let _ = app.create_team(&owner).await;
+
+        let res = app
+            .post(&format!("/v1/competition/{COMPETITION_ID}/solution"))
+            .user(&owner)
+            .json(&json!({
+                "problem": id,
+                "solution": 23,
+            }))
+            .send()
+            .await;
+
+        assert_error!(res, error::PROBLEM_NOT_RELEASED);
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn extended_team() {
+        let app = get_cached_app().await;
+
+        let admin = iam::register_user().await;
+        iam::make_admin(&admin).await;
+
+        let id = app.create_problem(&admin, json!({})).await;
+        app.set_time(&admin, 0, 3600).await;
+
+        let owner = app.register_user().await;
+        let team = app.create_team(&owner).await;
+        let team_id = team.get_id(&admin).await;
+
+        let now = Utc::now().timestamp();
+
+        let res = app
+            .put(&format!(
+                "/v1/competition/{COMPETITION_ID}/team/{team_id}/time"
+            ))
+            .user(&admin)
+            .json(&json!({
+                "end_time": now,
+                "extra_minutes": 10,
+            }))
+            .send()
+            .await;
+
+        assert_eq!(res.status(), StatusCode::NO_CONTENT);
+
+        let res = app
+            .post(&format!("/v1/competition/{COMPETITION_ID}/solution"))
+            .user(&owner)
+            .json(&json!({
+                "problem": id,
+                "solution": 23,
+            }))
+            .send()
+            .await;
+
+        assert_eq!(res.status(), StatusCode::NO_CONTENT);
+    }
+}
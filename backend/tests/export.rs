@@ -0,0 +1,264 @@
+use test_utils::prelude::*;
+
+async fn answer(app: &App, admin: &impl UserLike, owner: &impl UserLike, solution: i64) -> Value {
+    let res = app
+        .post(&format!("/v1/competition/{COMPETITION_ID}/problem"))
+        .user(admin)
+        .json(&json!({
+            "body": "some body",
+            "solution": 23,
+            "points": 3,
+        }))
+        .send()
+        .await;
+
+    assert_eq!(res.status(), StatusCode::CREATED);
+
+    let problem = res.json::<Value>().await["id"].clone();
+
+    let res = app
+        .post(&format!("/v1/competition/{COMPETITION_ID}/solution"))
+        .user(owner)
+        .json(&json!({
+            "problem": problem,
+            "solution": solution,
+        }))
+        .send()
+        .await;
+
+    assert_eq!(res.status(), StatusCode::NO_CONTENT);
+
+    problem
+}
+
+#[tokio::test]
+#[parallel]
+async fn not_admin() {
+    let app = get_cached_app().await;
+    let user = app.register_user().await;
+
+    for export in ["results", "solutions", "teams", "users"] {
+        let res = app
+            .get(&format!("/v1/competition/{COMPETITION_ID}/export/{export}"))
+            .user(&user)
+            .send()
+            .await;
+
+        assert_error!(res, error::NOT_ENOUGH_PERMISSIONS);
+    }
+}
+
+#[tokio::test]
+#[serial]
+async fn results_csv() {
+    let app = get_cached_app().await;
+    app.clean_database().await;
+
+    let admin_user = iam::register_user().await;
+    iam::make_admin(&admin_user).await;
+    app.start_competition(&admin_user).await;
+
+    let owner = app.register_user_in("Other School", 11).await;
+    let team = app.create_team(&owner).await;
+
+    answer(app, &admin_user, &owner, 23).await;
+
+    let res = app
+        .get(&format!(
+            "/v1/competition/{COMPETITION_ID}/export/results?format=csv"
+        ))
+        .user(&admin_user)
+        .send()
+        .await;
+
+    assert_eq!(res.status(), StatusCode::OK);
+    assert_eq!(
+        res.headers()[header::CONTENT_DISPOSITION],
+        "attachment; filename=\"results.csv\""
+    );
+
+    let body = res.text().await;
+    let lines = body.lines().collect::<Vec<_>>();
+
+    assert_eq!(
+        lines[0],
+        "rank,team_id,team,points,solved,division,member_id,member,school,class"
+    );
+    assert_eq!(lines.len(), 2);
+    assert!(lines[1].starts_with("1,"));
+    assert!(lines[1].contains(&format!(
+        ",{},3,1,,{},",
+        team.get_name(),
+        owner.id.strip_prefix("UserID-").unwrap()
+    )));
+    assert!(lines[1].ends_with(",Other School,11"));
+}
+
+#[tokio::test]
+#[serial]
+async fn results_division() {
+    let app = get_cached_app().await;
+    app.clean_database().await;
+
+    let admin_user = iam::register_user().await;
+    iam::make_admin(&admin_user).await;
+
+    let res = app
+        .patch(&format!("/v1/competition/{COMPETITION_ID}"))
+        .user(&admin_user)
+        .json(&json!({
+            "divisions": [
+                { "name": "junior", "min_class": 9, "max_class": 10 },
+                { "name": "senior", "min_class": 11, "max_class": 12 },
+            ],
+        }))
+        .send()
+        .await;
+
+    assert_eq!(res.status(), StatusCode::NO_CONTENT);
+
+    let senior = app.register_user_in("Senior School", 12).await;
+    let senior_team = app.create_team(&senior).await;
+    let junior = app.register_user_in("Junior School", 9).await;
+    let junior_team = app.create_team(&junior).await;
+
+    let res = app
+        .get(&format!(
+            "/v1/competition/{COMPETITION_ID}/export/results?format=csv&division=junior"
+        ))
+        .user(&admin_user)
+        .send()
+        .await;
+
+    assert_eq!(res.status(), StatusCode::OK);
+
+    let body = res.text().await;
+    let lines = body.lines().collect::<Vec<_>>();
+
+    assert_eq!(lines.len(), 2);
+    assert!(lines[1].starts_with("1,"));
+    assert!(lines[1].contains(&format!(",{},", junior_team.get_name())));
+    assert!(lines[1].contains(",junior,"));
+    assert!(!body.contains(&senior_team.get_name()));
+
+    let res = app
+        .get(&format!(
+            "/v1/competition/{COMPETITION_ID}/export/results?division=unknown"
+        ))
+        .user(&admin_user)
+        .send()
+        .await;
+
+    assert_error!(res, error::DIVISION_NOT_FOUND);
+}
+
+#[tokio::test]
+#[serial]
+async fn solutions_csv() {
+    let app = get_cached_app().await;
+    app.clean_database().await;
+
+    let admin_user = iam::register_user().await;
+    iam::make_admin(&admin_user).await;
+    app.start_competition(&admin_user).await;
+
+    let owner = app.register_user().await;
+    let team = app.create_team(&owner).await;
+
+    let problem = answer(app, &admin_user, &owner, 22).await;
+
+    let res = app
+        .get(&format!(
+            "/v1/competition/{COMPETITION_ID}/export/solutions"
+        ))
+        .user(&admin_user)
+        .send()
+        .await;
+
+    assert_eq!(res.status(), StatusCode::OK);
+
+    let body = res.text().await;
+    let lines = body.lines().collect::<Vec<_>>();
+
+    assert_eq!(
+        lines[0],
+        "id,created_at,team_id,team,problem_id,user_id,admin_id,solution"
+    );
+    assert_eq!(lines.len(), 2);
+    assert!(lines[1].ends_with(&format!(
+        ",{},{},{},,22",
+        team.get_name(),
+        problem.as_str().unwrap(),
+        owner.id.strip_prefix("UserID-").unwrap()
+    )));
+}
+
+#[tokio::test]
+#[serial]
+async fn teams_xlsx() {
+    let app = get_cached_app().await;
+    app.clean_database().await;
+
+    let admin_user = iam::register_user().await;
+    iam::make_admin(&admin_user).await;
+
+    let owner = app.register_user().await;
+    app.create_team(&owner).await;
+
+    let res = app
+        .get(&format!(
+            "/v1/competition/{COMPETITION_ID}/export/teams?format=xlsx"
+        ))
+        .user(&admin_user)
+        .send()
+        .await;
+
+    assert_eq!(res.status(), StatusCode::OK);
+    assert_eq!(
+        res.headers()[header::CONTENT_TYPE],
+        "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet"
+    );
+
+    // XLSX workbooks are zip archives
+    assert!(res.bytes().await.starts_with(b"PK"));
+}
+
+#[tokio::test]
+#[serial]
+async fn users_csv() {
+    let app = get_cached_app().await;
+    app.clean_database().await;
+
+    let admin_user = iam::register_user().await;
+    iam::make_admin(&admin_user).await;
+
+    let owner = app.register_user().await;
+    let team = app.create_team(&owner).await;
+    let user = app.register_user().await;
+
+    let res = app
+        .get(&format!("/v1/competition/{COMPETITION_ID}/export/users"))
+        .user(&admin_user)
+        .send()
+        .await;
+
+    assert_eq!(res.status(), StatusCode::OK);
+
+    let body = res.text().await;
+    let lines = body.lines().collect::<Vec<_>>();
+
+    assert_eq!(lines[0], "user_id,name,school,class,team_id,team");
+    assert_eq!(lines.len(), 3);
+
+    let owner_line = lines
+        .iter()
+        .find(|line| line.starts_with(owner.id.strip_prefix("UserID-").unwrap()))
+        .unwrap();
+    assert!(owner_line.ends_with(&format!(",{}", team.get_name())));
+
+    let user_line = lines
+        .iter()
+        .find(|line| line.starts_with(user.id.strip_prefix("UserID-").unwrap()))
+        .unwrap();
+    assert!(user_line.ends_with(",Test School,9,,"));
+}
@@ -11,7 +11,7 @@ mod create {
         iam::make_admin(&user).await;
 
         let res = app
-            .post("/v1/problem")
+            .post(&format!("/v1/competition/{COMPETITION_ID}/problem"))
             .user(&user)
             .json(&json!({
                 "body": "some body",
@@ -44,7 +44,7 @@ mod create {
         iam::make_admin(&user).await;
 
         let res = app
-            .post("/v1/problem")
+            .post(&format!("/v1/competition/{COMPETITION_ID}/problem"))
             .user(&user)
             .json(&json!({
                 "body": "some body",
@@ -63,7 +63,7 @@ mod create {
         let user = iam::register_user().await;
 
         let res = app
-            .post("/v1/problem")
+            .post(&format!("/v1/competition/{COMPETITION_ID}/problem"))
             .user(&user)
             .json(&json!({
                 "body": "some body",
@@ -74,6 +74,87 @@ mod create {
 
         assert_error!(res, error::NOT_ENOUGH_PERMISSIONS);
     }
+
+    #[tokio::test]
+    #[parallel]
+    async fn invalid_solution() {
+        let app = get_cached_app().await;
+        let user = iam::register_user().await;
+        iam::make_admin(&user).await;
+
+        let res = app
+            .post(&format!("/v1/competition/{COMPETITION_ID}/problem"))
+            .user(&user)
+            .json(&json!({
+                "body": "some body",
+                "kind": "FRACTION",
+                "solution": "1/0",
+            }))
+            .send()
+            .await;
+
+        assert_error!(res, error::INVALID_ANSWER);
+    }
+
+    #[tokio::test]
+    #[parallel]
+    async fn tolerance_not_decimal() {
+        let app = get_cached_app().await;
+        let user = iam::register_user().await;
+        iam::make_admin(&user).await;
+
+        let res = app
+            .post(&format!("/v1/competition/{COMPETITION_ID}/problem"))
+            .user(&user)
+            .json(&json!({
+                "body": "some body",
+                "solution": 32,
+                "tolerance": 0.5,
+            }))
+            .send()
+            .await;
+
+        assert_error!(res, error::JSON_VALIDATE_INVALID);
+    }
+
+    #[tokio::test]
+    #[parallel]
+    async fn accepted_solutions() {
+        let app = get_cached_app().await;
+        let user = iam::register_user().await;
+        iam::make_admin(&user).await;
+
+        let res = app
+            .post(&format!("/v1/competition/{COMPETITION_ID}/problem"))
+            .user(&user)
+            .json(&json!({
+                "body": "some body",
+                "solution": 1,
+                "accepted_solutions": [3, "2", 1, "+3"],
+            }))
+            .send()
+            .await;
+
+        assert_eq!(res.status(), StatusCode::CREATED);
+
+        let body: Value = res.json().await;
+        let id = body["id"].as_str().unwrap();
+
+        let res = app
+            .get(&format!("/v1/competition/{COMPETITION_ID}/problem/{id}"))
+            .user(&user)
+            .send()
+            .await;
+
+        assert_eq!(res.status(), StatusCode::OK);
+        assert_json_include!(
+            actual: res.json::<Value>().await,
+            expected: json!({
+                "solution": "1",
+                "accepted_solutions": ["2", "3"],
+            })
+        );
+    }
 }
 
 mod get {
@@ -89,7 +170,10 @@ mod get {
         iam::make_admin(&user).await;
 
         let res = app
-            .get(&format!("/v1/problem/{}", uuid()))
+            .get(&format!(
+                "/v1/competition/{COMPETITION_ID}/problem/{}",
+                uuid()
+            ))
             .user(&user)
             .send()
             .await;
@@ -107,7 +191,7 @@ mod get {
         iam::make_admin(&user).await;
 
         let res = app
-            .post("/v1/problem")
+            .post(&format!("/v1/competition/{COMPETITION_ID}/problem"))
             .user(&user)
             .json(&json!({
                 "body": "Test body.",
@@ -128,7 +212,7 @@ mod get {
         let id = body["id"].as_str().unwrap();
 
         let res = app
-            .get(&format!("/v1/problem/{}", id))
+            .get(&format!("/v1/competition/{COMPETITION_ID}/problem/{}", id))
             .user(&user)
             .send()
             .await;
@@ -147,7 +231,12 @@ mod get {
             json!({
                 "id": id,
                 "body": "Test body.",
-                "solution": 123,
+                "kind": "INTEGER",
+                "solution": "123",
+                "points": 1,
+                "wrong_points": 0,
+                "attempt_penalty": 0,
+                "release_minutes": 0,
                 "image": "test image",
             })
         );
@@ -160,7 +249,11 @@ mod get {
         let user = iam::register_user().await;
         iam::make_admin(&user).await;
 
-        let res = app.get("/v1/problem/test").user(&user).send().await;
+        let res = app
+            .get(&format!("/v1/competition/{COMPETITION_ID}/problem/test"))
+            .user(&user)
+            .send()
+            .await;
 
         assert_error!(res, error::PROBLEM_NOT_FOUND);
     }
@@ -172,7 +265,10 @@ mod get {
         let user = iam::register_user().await;
 
         let res = app
-            .get(&format!("/v1/problem/{}", uuid()))
+            .get(&format!(
+                "/v1/competition/{COMPETITION_ID}/problem/{}",
+                uuid()
+            ))
             .user(&user)
             .send()
             .await;
@@ -193,18 +289,25 @@ mod list {
         let user = iam::register_user().await;
         iam::make_admin(&user).await;
 
-        let res = app.get("/v1/problem").user(&user).send().await;
+        let res = app
+            .get(&format!("/v1/competition/{COMPETITION_ID}/problem"))
+            .user(&user)
+            .send()
+            .await;
 
         assert_eq!(res.status(), StatusCode::OK);
         let body: Value = res.json().await;
         assert_json_eq!(body, json!([]));
 
         let res = app
-            .post("/v1/problem")
+            .post(&format!("/v1/competition/{COMPETITION_ID}/problem"))
             .user(&user)
             .json(&json!({
                 "body": "Test body 1.",
-                "solution": 1,
+                "kind": "INTEGER",
+                "solution": "1",
+                "points": 1,
+                "wrong_points": 0,
                 "image": "test image 1",
             }))
             .send()
@@ -220,7 +323,11 @@ mod list {
         let body: Value = res.json().await;
         let id1 = body["id"].as_str().unwrap();
 
-        let res = app.get("/v1/problem").user(&user).send().await;
+        let res = app
+            .get(&format!("/v1/competition/{COMPETITION_ID}/problem"))
+            .user(&user)
+            .send()
+            .await;
 
         assert_eq!(res.status(), StatusCode::OK);
         let body: Value = res.json().await;
@@ -229,13 +336,18 @@ mod list {
             json!([{
                 "id": id1,
                 "body": "Test body 1.",
-                "solution": 1,
+                "kind": "INTEGER",
+                "solution": "1",
+                "points": 1,
+                "wrong_points": 0,
+                "attempt_penalty": 0,
+                "release_minutes": 0,
                 "image": "test image 1",
             }])
         );
 
         let res = app
-            .post("/v1/problem")
+            .post(&format!("/v1/competition/{COMPETITION_ID}/problem"))
             .user(&user)
             .json(&json!({
                 "body": "Test body 2.",
@@ -255,7 +367,11 @@ mod list {
         let body: Value = res.json().await;
         let id2 = body["id"].as_str().unwrap();
 
-        let res = app.get("/v1/problem").user(&user).send().await;
+        let res = app
+            .get(&format!("/v1/competition/{COMPETITION_ID}/problem"))
+            .user(&user)
+            .send()
+            .await;
 
         assert_eq!(res.status(), StatusCode::OK);
         let body: Value = res.json().await;
@@ -265,13 +381,23 @@ mod list {
                 {
                     "id": id1,
                     "body": "Test body 1.",
-                    "solution": 1,
+                    "kind": "INTEGER",
+                    "solution": "1",
+                    "points": 1,
+                    "wrong_points": 0,
+                    "attempt_penalty": 0,
+                    "release_minutes": 0,
                     "image": "test image 1",
                 },
                 {
                     "id": id2,
                     "body": "Test body 2.",
-                    "solution": 2,
+                    "kind": "INTEGER",
+                    "solution": "2",
+                    "points": 1,
+                    "wrong_points": 0,
+                    "attempt_penalty": 0,
+                    "release_minutes": 0,
                     "image": "test image 2",
                 },
             ])
@@ -284,7 +410,11 @@ mod list {
         let app = get_cached_app().await;
         let user = iam::register_user().await;
 
-        let res = app.get("/v1/problem").user(&user).send().await;
+        let res = app
+            .get(&format!("/v1/competition/{COMPETITION_ID}/problem"))
+            .user(&user)
+            .send()
+            .await;
 
         assert_error!(res, error::NOT_ENOUGH_PERMISSIONS);
     }
@@ -302,7 +432,10 @@ mod delete {
         iam::make_admin(&user).await;
 
         let res = app
-            .delete(&format!("/v1/problem/{}", uuid()))
+            .delete(&format!(
+                "/v1/competition/{COMPETITION_ID}/problem/{}",
+                uuid()
+            ))
             .user(&user)
             .send()
             .await;
@@ -319,7 +452,7 @@ mod delete {
         let _team = app.create_team(&user).await;
 
         let res = app
-            .post("/v1/problem")
+            .post(&format!("/v1/competition/{COMPETITION_ID}/problem"))
             .user(&user)
             .json(&json!({
                 "body": "Test body 1.",
@@ -336,14 +469,17 @@ mod delete {
             res.json::<Value>().await,
         );
 
-        let mut socket = app.socket("/v1/ws").start().await;
+        let mut socket = app
+            .socket(&format!("/v1/competition/{COMPETITION_ID}/ws"))
+            .start()
+            .await;
         assert_team_info!(socket, user);
 
         let body: Value = res.json().await;
         let id = body["id"].as_str().unwrap();
 
         let res = app
-            .delete(&format!("/v1/problem/{}", id))
+            .delete(&format!("/v1/competition/{COMPETITION_ID}/problem/{}", id))
             .user(&user)
             .send()
             .await;
@@ -374,7 +510,11 @@ mod delete {
         let app = get_cached_app().await;
         let user = iam::register_user().await;
 
-        let res = app.delete("/v1/problem").user(&user).send().await;
+        let res = app
+            .delete(&format!("/v1/competition/{COMPETITION_ID}/problem"))
+            .user(&user)
+            .send()
+            .await;
 
         assert_error!(res, error::NOT_ENOUGH_PERMISSIONS);
     }
@@ -397,7 +537,7 @@ mod update {
             let id = uuid();
 
             let res = app
-                .put(&format!("/v1/problem/{}", id))
+                .put(&format!("/v1/competition/{COMPETITION_ID}/problem/{}", id))
                 .user(&user)
                 .json(&json!({
                     "id": id,
@@ -420,7 +560,7 @@ mod update {
             iam::make_admin(&user).await;
 
             let res = app
-                .post("/v1/problem")
+                .post(&format!("/v1/competition/{COMPETITION_ID}/problem"))
                 .user(&user)
                 .json(&json!({
                     "body": "Test body 1.",
@@ -441,7 +581,7 @@ mod update {
             let id = body["id"].as_str().unwrap();
 
             let res = app
-                .put(&format!("/v1/problem/{}", id))
+                .put(&format!("/v1/competition/{COMPETITION_ID}/problem/{}", id))
                 .user(&user)
                 .json(&json!({
                     "id": id,
@@ -460,7 +600,7 @@ mod update {
             );
 
             let res = app
-                .get(&format!("/v1/problem/{}", id))
+                .get(&format!("/v1/competition/{COMPETITION_ID}/problem/{}", id))
                 .user(&user)
                 .send()
                 .await;
@@ -472,7 +612,12 @@ mod update {
                 json!({
                     "id": id,
                     "body": "Test body 2.",
-                    "solution": 2,
+                    "kind": "INTEGER",
+                    "solution": "2",
+                    "points": 1,
+                    "wrong_points": 0,
+                    "attempt_penalty": 0,
+                    "release_minutes": 0,
                 })
             )
         }
@@ -486,7 +631,7 @@ mod update {
             iam::make_admin(&user).await;
 
             let res = app
-                .post("/v1/problem")
+                .post(&format!("/v1/competition/{COMPETITION_ID}/problem"))
                 .user(&user)
                 .json(&json!({
                     "body": "Test body 1.",
@@ -507,7 +652,7 @@ mod update {
             let id = body["id"].as_str().unwrap();
 
             let res = app
-                .put(&format!("/v1/problem/{}", id))
+                .put(&format!("/v1/competition/{COMPETITION_ID}/problem/{}", id))
                 .user(&user)
                 .json(&json!({
                     "id": id,
@@ -526,7 +671,7 @@ mod update {
             );
 
             let res = app
-                .get(&format!("/v1/problem/{}", id))
+                .get(&format!("/v1/competition/{COMPETITION_ID}/problem/{}", id))
                 .user(&user)
                 .send()
                 .await;
@@ -538,7 +683,12 @@ mod update {
                 json!({
                     "id": id,
                     "body": "Test body 2.",
-                    "solution": 2,
+                    "kind": "INTEGER",
+                    "solution": "2",
+                    "points": 1,
+                    "wrong_points": 0,
+                    "attempt_penalty": 0,
+                    "release_minutes": 0,
                     "image": "test image 2",
                 })
             )
@@ -551,7 +701,10 @@ mod update {
             let user = iam::register_user().await;
 
             let res = app
-                .put(&format!("/v1/problem/{}", uuid()))
+                .put(&format!(
+                    "/v1/competition/{COMPETITION_ID}/problem/{}",
+                    uuid()
+                ))
                 .user(&user)
                 .send()
                 .await;
@@ -571,7 +724,7 @@ mod update {
         let id = uuid();
 
         let res = app
-            .patch(&format!("/v1/problem/{}", id))
+            .patch(&format!("/v1/competition/{COMPETITION_ID}/problem/{}", id))
             .user(&user)
             .json(&json!({
                 "id": id,
@@ -594,7 +747,7 @@ mod update {
         iam::make_admin(&user).await;
 
         let res = app
-            .post("/v1/problem")
+            .post(&format!("/v1/competition/{COMPETITION_ID}/problem"))
             .user(&user)
             .json(&json!({
                 "body": "Test body 1.",
@@ -615,7 +768,7 @@ mod update {
         let id = body["id"].as_str().unwrap();
 
         let res = app
-            .patch(&format!("/v1/problem/{}", id))
+            .patch(&format!("/v1/competition/{COMPETITION_ID}/problem/{}", id))
             .user(&user)
             .json(&json!({
                 "id": id,
@@ -634,7 +787,7 @@ mod update {
         );
 
         let res = app
-            .get(&format!("/v1/problem/{}", id))
+            .get(&format!("/v1/competition/{COMPETITION_ID}/problem/{}", id))
             .user(&user)
             .send()
             .await;
@@ -646,7 +799,12 @@ mod update {
             json!({
                 "id": id,
                 "body": "Test body 2.",
-                "solution": 2,
+                "kind": "INTEGER",
+                "solution": "2",
+                "points": 1,
+                "wrong_points": 0,
+                "attempt_penalty": 0,
+                "release_minutes": 0,
             })
         )
     }
@@ -660,7 +818,7 @@ mod update {
         iam::make_admin(&user).await;
 
         let res = app
-            .post("/v1/problem")
+            .post(&format!("/v1/competition/{COMPETITION_ID}/problem"))
             .user(&user)
             .json(&json!({
                 "body": "Test body 1.",
@@ -681,7 +839,7 @@ mod update {
         let id = body["id"].as_str().unwrap();
 
         let res = app
-            .patch(&format!("/v1/problem/{}", id))
+            .patch(&format!("/v1/competition/{COMPETITION_ID}/problem/{}", id))
             .user(&user)
             .json(&json!({
                 "id": id,
@@ -706,7 +864,7 @@ mod update {
         iam::make_admin(&user).await;
 
         let res = app
-            .post("/v1/problem")
+            .post(&format!("/v1/competition/{COMPETITION_ID}/problem"))
             .user(&user)
             .json(&json!({
                 "body": "Test body 1.",
@@ -727,7 +885,7 @@ mod update {
         let id = body["id"].as_str().unwrap();
 
         let res = app
-            .patch(&format!("/v1/problem/{}", id))
+            .patch(&format!("/v1/competition/{COMPETITION_ID}/problem/{}", id))
             .user(&user)
             .json(&json!({
                 "id": id,
@@ -746,7 +904,7 @@ mod update {
         );
 
         let res = app
-            .get(&format!("/v1/problem/{}", id))
+            .get(&format!("/v1/competition/{COMPETITION_ID}/problem/{}", id))
             .user(&user)
             .send()
             .await;
@@ -758,7 +916,12 @@ mod update {
             json!({
                 "id": id,
                 "body": "Test body 2.",
-                "solution": 2,
+                "kind": "INTEGER",
+                "solution": "2",
+                "points": 1,
+                "wrong_points": 0,
+                "attempt_penalty": 0,
+                "release_minutes": 0,
                 "image": "test image 2",
             })
         )
@@ -771,7 +934,10 @@ mod update {
         let user = iam::register_user().await;
 
         let res = app
-            .patch(&format!("/v1/problem/{}", uuid()))
+            .patch(&format!(
+                "/v1/competition/{COMPETITION_ID}/problem/{}",
+                uuid()
+            ))
             .user(&user)
             .send()
             .await;
@@ -790,7 +956,7 @@ mod order {
 
         for id_slot in ids.iter_mut() {
             let res = app
-                .post("/v1/problem")
+                .post(&format!("/v1/competition/{COMPETITION_ID}/problem"))
                 .user(user)
                 .json(&json!({
                     "body": "",
@@ -804,7 +970,7 @@ mod order {
             let id = Uuid::parse_str(res.json::<Value>().await["id"].as_str().unwrap()).unwrap();
 
             let res = app
-                .post("/v1/problem/order")
+                .post(&format!("/v1/competition/{COMPETITION_ID}/problem/order"))
                 .user(user)
                 .json(&json!({
                     "type": "INSERT",
@@ -829,7 +995,7 @@ mod order {
 
     async fn create_test_problem(app: &App, user: &impl UserLike) -> Uuid {
         let res = app
-            .post("/v1/problem")
+            .post(&format!("/v1/competition/{COMPETITION_ID}/problem"))
             .user(user)
             .json(&json!({
                 "body": "",
@@ -852,7 +1018,7 @@ mod order {
         iam::make_admin(&user).await;
 
         let res = app
-            .post("/v1/problem/order")
+            .post(&format!("/v1/competition/{COMPETITION_ID}/problem/order"))
             .user(&user)
             .json(&json!({
                 "type": "INSERT",
@@ -873,7 +1039,7 @@ mod order {
         iam::make_admin(&user).await;
 
         let res = app
-            .post("/v1/problem/order")
+            .post(&format!("/v1/competition/{COMPETITION_ID}/problem/order"))
             .user(&user)
             .json(&json!({
                 "type": "INSERT",
@@ -897,7 +1063,7 @@ mod order {
         let id = create_test_problem(app, &user).await;
 
         let res = app
-            .post("/v1/problem/order")
+            .post(&format!("/v1/competition/{COMPETITION_ID}/problem/order"))
             .user(&user)
             .json(&json!({
                 "type": "INSERT",
@@ -923,7 +1089,7 @@ mod order {
         let id2 = create_test_problem(app, &user).await;
 
         let res = app
-            .post("/v1/problem/order")
+            .post(&format!("/v1/competition/{COMPETITION_ID}/problem/order"))
             .user(&user)
             .json(&json!({
                 "type": "INSERT",
@@ -935,7 +1101,7 @@ mod order {
         assert_eq!(res.status(), StatusCode::NO_CONTENT);
 
         let res = app
-            .post("/v1/problem/order")
+            .post(&format!("/v1/competition/{COMPETITION_ID}/problem/order"))
             .user(&user)
             .json(&json!({
                 "type": "INSERT",
@@ -947,7 +1113,7 @@ mod order {
         assert_eq!(res.status(), StatusCode::NO_CONTENT);
 
         let res = app
-            .post("/v1/problem/order")
+            .post(&format!("/v1/competition/{COMPETITION_ID}/problem/order"))
             .user(&user)
             .json(&json!({
                 "type": "INSERT",
@@ -972,7 +1138,7 @@ mod order {
         let id = create_test_problem(app, &user).await;
 
         let res = app
-            .post("/v1/problem/order")
+            .post(&format!("/v1/competition/{COMPETITION_ID}/problem/order"))
             .user(&user)
             .json(&json!({
                 "type": "INSERT",
@@ -984,7 +1150,7 @@ mod order {
         assert_eq!(res.status(), StatusCode::NO_CONTENT);
 
         let res = app
-            .post("/v1/problem/order")
+            .post(&format!("/v1/competition/{COMPETITION_ID}/problem/order"))
             .user(&user)
             .json(&json!({
                 "type": "INSERT",
@@ -1005,7 +1171,7 @@ mod order {
         iam::make_admin(&user).await;
 
         let res = app
-            .post("/v1/problem/order")
+            .post(&format!("/v1/competition/{COMPETITION_ID}/problem/order"))
             .user(&user)
             .json(&json!({
                 "type": "DELETE",
@@ -1026,7 +1192,7 @@ mod order {
         iam::make_admin(&user).await;
 
         let res = app
-            .post("/v1/problem/order")
+            .post(&format!("/v1/competition/{COMPETITION_ID}/problem/order"))
             .user(&user)
             .json(&json!({
                 "type": "SWAP",
@@ -1040,7 +1206,11 @@ mod order {
     }
 
     async fn get_order_list(app: &App, user: &impl UserLike) -> Vec<String> {
-        let res = app.get("/v1/problem/order").user(user).send().await;
+        let res = app
+            .get(&format!("/v1/competition/{COMPETITION_ID}/problem/order"))
+            .user(user)
+            .send()
+            .await;
 
         assert_eq!(res.status(), StatusCode::OK);
 
@@ -1071,7 +1241,7 @@ mod order {
         let id = create_test_problem(app, &user).await;
 
         let res = app
-            .post("/v1/problem/order")
+            .post(&format!("/v1/competition/{COMPETITION_ID}/problem/order"))
             .user(&user)
             .json(&json!({
                 "type": "INSERT",
@@ -1088,7 +1258,7 @@ mod order {
         let id2 = create_test_problem(app, &user).await;
 
         let res = app
-            .post("/v1/problem/order")
+            .post(&format!("/v1/competition/{COMPETITION_ID}/problem/order"))
             .user(&user)
             .json(&json!({
                 "type": "INSERT",
@@ -1119,7 +1289,7 @@ mod order {
         let id2 = create_test_problem(app, &user).await;
 
         let res = app
-            .post("/v1/problem/order")
+            .post(&format!("/v1/competition/{COMPETITION_ID}/problem/order"))
             .user(&user)
             .json(&json!({
                 "type": "INSERT",
@@ -1134,7 +1304,7 @@ mod order {
         assert_eq!(order, [id.to_string()]);
 
         let res = app
-            .post("/v1/problem/order")
+            .post(&format!("/v1/competition/{COMPETITION_ID}/problem/order"))
             .user(&user)
             .json(&json!({
                 "type": "INSERT",
@@ -1167,7 +1337,7 @@ mod order {
         let id3 = create_test_problem(app, &user).await;
 
         let res = app
-            .post("/v1/problem/order")
+            .post(&format!("/v1/competition/{COMPETITION_ID}/problem/order"))
             .user(&user)
             .json(&json!({
                 "type": "INSERT",
@@ -1179,7 +1349,7 @@ mod order {
         assert_eq!(res.status(), StatusCode::NO_CONTENT);
 
         let res = app
-            .post("/v1/problem/order")
+            .post(&format!("/v1/competition/{COMPETITION_ID}/problem/order"))
             .user(&user)
             .json(&json!({
                 "type": "INSERT",
@@ -1194,7 +1364,7 @@ mod order {
         assert_eq!(order, [id.to_string(), id3.to_string()]);
 
         let res = app
-            .post("/v1/problem/order")
+            .post(&format!("/v1/competition/{COMPETITION_ID}/problem/order"))
             .user(&user)
             .json(&json!({
                 "type": "INSERT",
@@ -1222,7 +1392,7 @@ mod order {
         let [id, id2] = create_test_problem2(app, &user).await;
 
         let res = app
-            .post("/v1/problem/order")
+            .post(&format!("/v1/competition/{COMPETITION_ID}/problem/order"))
             .user(&user)
             .json(&json!({
                 "type": "DELETE",
@@ -1249,7 +1419,7 @@ mod order {
         let [id, id2, id3] = create_test_problem2(app, &user).await;
 
         let res = app
-            .post("/v1/problem/order")
+            .post(&format!("/v1/competition/{COMPETITION_ID}/problem/order"))
             .user(&user)
             .json(&json!({
                 "type": "DELETE",
@@ -1276,7 +1446,7 @@ mod order {
         let [id, id2] = create_test_problem2(app, &user).await;
 
         let res = app
-            .post("/v1/problem/order")
+            .post(&format!("/v1/competition/{COMPETITION_ID}/problem/order"))
             .user(&user)
             .json(&json!({
                 "type": "DELETE",
@@ -1303,7 +1473,7 @@ mod order {
         let [id1, id2, id3] = create_test_problem2(app, &user).await;
 
         let res = app
-            .post("/v1/problem/order")
+            .post(&format!("/v1/competition/{COMPETITION_ID}/problem/order"))
             .user(&user)
             .json(&json!({
                 "type": "SWAP",
@@ -1331,7 +1501,7 @@ mod order {
         let [id1, id2, id3] = create_test_problem2(app, &user).await;
 
         let res = app
-            .post("/v1/problem/order")
+            .post(&format!("/v1/competition/{COMPETITION_ID}/problem/order"))
             .user(&user)
             .json(&json!({
                 "type": "SWAP",
@@ -1359,7 +1529,7 @@ mod order {
         let [id1, id2, id3] = create_test_problem2(app, &user).await;
 
         let res = app
-            .post("/v1/problem/order")
+            .post(&format!("/v1/competition/{COMPETITION_ID}/problem/order"))
             .user(&user)
             .json(&json!({
                 "type": "SWAP",
@@ -1387,7 +1557,7 @@ mod order {
         let [id1, id2, id3] = create_test_problem2(app, &user).await;
 
         let res = app
-            .post("/v1/problem/order")
+            .post(&format!("/v1/competition/{COMPETITION_ID}/problem/order"))
             .user(&user)
             .json(&json!({
                 "type": "SWAP",
@@ -1415,7 +1585,7 @@ mod order {
         let [id1, id2, id3, id4] = create_test_problem2(app, &user).await;
 
         let res = app
-            .post("/v1/problem/order")
+            .post(&format!("/v1/competition/{COMPETITION_ID}/problem/order"))
             .user(&user)
             .json(&json!({
                 "type": "SWAP",
@@ -1451,7 +1621,7 @@ mod order {
         let [id1, id2, id3, id4] = create_test_problem2(app, &user).await;
 
         let res = app
-            .post("/v1/problem/order")
+            .post(&format!("/v1/competition/{COMPETITION_ID}/problem/order"))
             .user(&user)
             .json(&json!({
                 "type": "SWAP",
@@ -1490,7 +1660,7 @@ mod order {
         tracing::debug!("original order: {order:?}");
 
         let res = app
-            .post("/v1/problem/order")
+            .post(&format!("/v1/competition/{COMPETITION_ID}/problem/order"))
             .user(&user)
             .json(&json!({
                 "type": "SWAP",
@@ -1521,7 +1691,7 @@ mod order {
         tracing::debug!("original order: {order:?}");
 
         let res = app
-            .post("/v1/problem/order")
+            .post(&format!("/v1/competition/{COMPETITION_ID}/problem/order"))
             .user(&user)
             .json(&json!({
                 "type": "SWAP",
@@ -1543,7 +1713,110 @@ mod order {
         let app = get_cached_app().await;
         let user = iam::register_user().await;
 
-        let res = app.delete("/v1/problem").user(&user).send().await;
+        let res = app
+            .delete(&format!("/v1/competition/{COMPETITION_ID}/problem"))
+            .user(&user)
+            .send()
+            .await;
+
+        assert_error!(res, error::NOT_ENOUGH_PERMISSIONS);
+    }
+}
+
+mod release {
+    use super::*;
+
+    #[tokio::test]
+    #[parallel]
+    async fn success() {
+        let app = get_cached_app().await;
+        let user = iam::register_user().await;
+        iam::make_admin(&user).await;
+
+        let mut ids = Vec::new();
+
+        for _ in 0..2 {
+            let res = app
+                .post(&format!("/v1/competition/{COMPETITION_ID}/problem"))
+                .user(&user)
+                .json(&json!({
+                    "body": "some body",
+                    "solution": 1,
+                }))
+                .send()
+                .await;
+
+            assert_eq!(res.status(), StatusCode::CREATED);
+
+            ids.push(res.json::<Value>().await["id"].clone());
+        }
+
+        let res = app
+            .post(&format!("/v1/competition/{COMPETITION_ID}/problem/release"))
+            .user(&user)
+            .json(&json!({
+                "problems": ids,
+                "release_minutes": 30,
+            }))
+            .send()
+            .await;
+
+        assert_eq!(res.status(), StatusCode::NO_CONTENT);
+
+        for id in ids {
+            let res = app
+                .get(&format!(
+                    "/v1/competition/{COMPETITION_ID}/problem/{}",
+                    id.as_str().unwrap()
+                ))
+                .user(&user)
+                .send()
+                .await;
+
+            assert_json_include!(
+                actual: res.json::<Value>().await,
+                expected: json!({
+                    "release_minutes": 30,
+                })
+            );
+        }
+    }
+
+    #[tokio::test]
+    #[parallel]
+    async fn not_found() {
+        let app = get_cached_app().await;
+        let user = iam::register_user().await;
+        iam::make_admin(&user).await;
+
+        let res = app
+            .post(&format!("/v1/competition/{COMPETITION_ID}/problem/release"))
+            .user(&user)
+            .json(&json!({
+                "problems": [uuid()],
+                "release_minutes": 30,
+            }))
+            .send()
+            .await;
+
+        assert_error!(res, error::PROBLEM_NOT_FOUND);
+    }
+
+    #[tokio::test]
+    #[parallel]
+    async fn not_admin() {
+        let app = get_cached_app().await;
+        let user = iam::register_user().await;
+
+        let res = app
+            .post(&format!("/v1/competition/{COMPETITION_ID}/problem/release"))
+            .user(&user)
+            .json(&json!({
+                "problems": [uuid()],
+                "release_minutes": 30,
+            }))
+            .send()
+            .await;
 
         assert_error!(res, error::NOT_ENOUGH_PERMISSIONS);
     }
@@ -0,0 +1,116 @@
+use test_utils::prelude::*;
+
+#[tokio::test]
+#[serial]
+async fn frozen_after_end() {
+    let app = get_cached_app().await;
+    app.clean_database().await;
+
+    let owner = app.register_user().await;
+    let team = app.create_team(&owner).await;
+
+    let mut socket = app
+        .socket(&format!("/v1/competition/{COMPETITION_ID}/scoreboard"))
+        .start()
+        .await;
+
+    let message = get_socket_message(socket.next().await);
+
+    assert_json_include!(
+        actual: message,
+        expected: json!({
+            "event": "UPDATE_SCOREBOARD",
+            "data": {
+                "frozen": true,
+                "scoreboard": [{
+                    "rank": 1,
+                    "name": team.get_name(),
+                    "points": 0,
+                    "solved": 0,
+                }],
+            },
+        })
+    );
+
+    socket.close(None).await.unwrap();
+}
+
+#[tokio::test]
+#[serial]
+async fn updates_on_solution() {
+    let app = get_cached_app().await;
+    app.clean_database().await;
+
+    let admin = iam::register_user().await;
+    iam::make_admin(&admin).await;
+    app.start_competition(&admin).await;
+
+    let res = app
+        .post(&format!("/v1/competition/{COMPETITION_ID}/problem"))
+        .user(&admin)
+        .json(&json!({
+            "body": "some body",
+            "solution": 23,
+            "points": 4,
+        }))
+        .send()
+        .await;
+
+    assert_eq!(res.status(), StatusCode::CREATED);
+    let id = res.json::<Value>().await["id"].clone();
+
+    let owner = app.register_user().await;
+    let team = app.create_team(&owner).await;
+
+    let mut socket = app
+        .socket(&format!("/v1/competition/{COMPETITION_ID}/scoreboard"))
+        .start()
+        .await;
+
+    let message = get_socket_message(socket.next().await);
+
+    assert_json_include!(
+        actual: message,
+        expected: json!({
+            "event": "UPDATE_SCOREBOARD",
+            "data": {
+                "frozen": false,
+                "scoreboard": [{
+                    "name": team.get_name(),
+                    "points": 0,
+                }],
+            },
+        })
+    );
+
+    let res = app
+        .post(&format!("/v1/competition/{COMPETITION_ID}/solution"))
+        .user(&owner)
+        .json(&json!({
+            "problem": id,
+            "solution": 23,
+        }))
+        .send()
+        .await;
+
+    assert_eq!(res.status(), StatusCode::NO_CONTENT);
+
+    let message = get_socket_message(socket.next().await);
+
+    assert_json_include!(
+        actual: message,
+        expected: json!({
+            "event": "UPDATE_SCOREBOARD",
+            "data": {
+                "frozen": false,
+                "scoreboard": [{
+                    "name": team.get_name(),
+                    "points": 4,
+                    "solved": 1,
+                }],
+            },
+        })
+    );
+
+    socket.close(None).await.unwrap();
+}
@@ -10,7 +10,10 @@ use uuid::Uuid;
 async fn timeout() {
     let app = get_cached_app().await;
 
-    let mut socket = app.socket("/v1/ws").start().await;
+    let mut socket = app
+        .socket(&format!("/v1/competition/{COMPETITION_ID}/ws"))
+        .start()
+        .await;
 
     assert_close_frame_error!(socket.next().await, error::WEBSOCKET_AUTH_TIMEOUT);
 }
@@ -20,7 +23,10 @@ async fn timeout() {
 async fn wrong_token() {
     let app = get_cached_app().await;
 
-    let mut socket = app.socket("/v1/ws").start().await;
+    let mut socket = app
+        .socket(&format!("/v1/competition/{COMPETITION_ID}/ws"))
+        .start()
+        .await;
 
     socket
         .send(Message::Text("some random invalid token".to_owned().into()))
@@ -34,7 +40,10 @@ async fn wrong_token() {
 async fn wrong_message_type() {
     let app = get_cached_app().await;
 
-    let mut socket = app.socket("/v1/ws").start().await;
+    let mut socket = app
+        .socket(&format!("/v1/competition/{COMPETITION_ID}/ws"))
+        .start()
+        .await;
 
     socket
         .send(Message::Binary(Bytes::from("asd".as_bytes())))
@@ -49,7 +58,10 @@ async fn user_not_registered() {
     let app = get_cached_app().await;
     let user = iam::register_user().await;
 
-    let mut socket = app.socket("/v1/ws").start().await;
+    let mut socket = app
+        .socket(&format!("/v1/competition/{COMPETITION_ID}/ws"))
+        .start()
+        .await;
 
     socket
         .send(Message::Text(
@@ -68,7 +80,10 @@ async fn no_team() {
     let app = get_cached_app().await;
     let user = app.register_user().await;
 
-    let mut socket = app.socket("/v1/ws").start().await;
+    let mut socket = app
+        .socket(&format!("/v1/competition/{COMPETITION_ID}/ws"))
+        .start()
+        .await;
 
     socket
         .send(Message::Text(
@@ -89,7 +104,10 @@ async fn team_info() {
 
     let team = app.create_team(&user).await;
 
-    let mut socket = app.socket("/v1/ws").start().await;
+    let mut socket = app
+        .socket(&format!("/v1/competition/{COMPETITION_ID}/ws"))
+        .start()
+        .await;
     socket
         .send(Message::Text(
             json!({"token": user.access_token().to_owned()})
@@ -134,7 +152,7 @@ async fn dont_send_problems_before_start() {
     iam::make_admin(&admin).await;
 
     let res = app
-        .post("/v1/problem")
+        .post(&format!("/v1/competition/{COMPETITION_ID}/problem"))
         .user(&admin)
         .json(&json!({
             "body": "some body",
@@ -149,7 +167,7 @@ async fn dont_send_problems_before_start() {
     let id = Uuid::parse_str(res.json::<Value>().await["id"].as_str().unwrap()).unwrap();
 
     let res = app
-        .post("/v1/problem/order")
+        .post(&format!("/v1/competition/{COMPETITION_ID}/problem/order"))
         .user(&admin)
         .json(&json!({
             "type": "INSERT",
@@ -164,7 +182,7 @@ async fn dont_send_problems_before_start() {
     let start = start - Duration::from_nanos(start.timestamp_subsec_nanos() as u64);
 
     let res = app
-        .patch("/v1/competition/time")
+        .patch(&format!("/v1/competition/{COMPETITION_ID}/time"))
         .user(&admin)
         .json(&json!({
             "start_time": start.timestamp(),
@@ -179,7 +197,10 @@ async fn dont_send_problems_before_start() {
     let team = app.create_team(&owner).await;
     team.lock().await;
 
-    let mut socket = app.socket("/v1/ws").start().await;
+    let mut socket = app
+        .socket(&format!("/v1/competition/{COMPETITION_ID}/ws"))
+        .start()
+        .await;
 
     socket
         .send(Message::Text(
@@ -8,7 +8,7 @@ async fn not_admin() {
     let user = app.register_user().await;
 
     let res = app
-        .post("/v1/stats")
+        .post(&format!("/v1/competition/{COMPETITION_ID}/stats"))
         .user(&user)
         .json(&json!({
             "timestamp": 12312312,
@@ -31,7 +31,7 @@ async fn empty() {
     let time = Utc::now().to_rfc3339();
 
     let res = app
-        .post("/v1/stats")
+        .post(&format!("/v1/competition/{COMPETITION_ID}/stats"))
         .user(&admin_user)
         .json(&json!({
             "timestamp": time,
@@ -49,6 +49,198 @@ async fn empty() {
     );
 }
 
+#[tokio::test]
+#[serial]
+async fn answer_kinds() {
+    let app = get_cached_app().await;
+    app.clean_database().await;
+
+    let admin_user = iam::register_user().await;
+    iam::make_admin(&admin_user).await;
+    app.start_competition(&admin_user).await;
+
+    let owner = app.register_user().await;
+    let team = app.create_team(&owner).await;
+    team.lock().await;
+
+    let problems = [
+        (
+            json!({"kind": "DECIMAL", "solution": "3.1416", "tolerance": 0.01}),
+            "3,14",
+        ),
+        (json!({"kind": "FRACTION", "solution": "2/4"}), "1/2"),
+        (json!({"kind": "CHOICE", "solution": "c"}), "C"),
+        (json!({"kind": "SET", "solution": "{3, 1}"}), "1;3;3"),
+    ];
+
+    let mut ids = Vec::new();
+
+    for (mut problem, answer) in problems {
+        problem["body"] = json!("some body");
+
+        let res = app
+            .post(&format!("/v1/competition/{COMPETITION_ID}/problem"))
+            .user(&admin_user)
+            .json(&problem)
+            .send()
+            .await;
+
+        assert_eq!(res.status(), StatusCode::CREATED);
+
+        let id = res.json::<Value>().await["id"].clone();
+
+        let res = app
+            .post(&format!("/v1/competition/{COMPETITION_ID}/solution"))
+            .user(&owner)
+            .json(&json!({
+                "problem": id,
+                "solution": answer,
+            }))
+            .send()
+            .await;
+
+        assert_eq!(res.status(), StatusCode::NO_CONTENT);
+
+        ids.push(id);
+    }
+
+    let res = app
+        .post(&format!("/v1/competition/{COMPETITION_ID}/solution"))
+        .user(&owner)
+        .json(&json!({
+            "problem": ids[0],
+            "solution": 3.2,
+        }))
+        .send()
+        .await;
+
+    assert_eq!(res.status(), StatusCode::NO_CONTENT);
+
+    let res = app
+        .post(&format!("/v1/competition/{COMPETITION_ID}/solution"))
+        .user(&owner)
+        .json(&json!({
+            "problem": ids[2],
+            "solution": "not a letter",
+        }))
+        .send()
+        .await;
+
+    assert_error!(res, error::INVALID_ANSWER);
+
+    let res = app
+        .post(&format!("/v1/competition/{COMPETITION_ID}/stats"))
+        .user(&admin_user)
+        .json(&json!({
+            "timestamp": Utc::now().to_rfc3339(),
+        }))
+        .send()
+        .await;
+
+    assert_eq!(res.status(), StatusCode::OK);
+
+    assert_json_include!(
+        actual: res.json::<Value>().await,
+        expected: json!([{
+            "correct": 3,
+            "wrong": 1,
+        }])
+    );
+}
+
+#[tokio::test]
+#[serial]
+async fn accepted_solutions() {
+    let app = get_cached_app().await;
+    app.clean_database().await;
+
+    let admin_user = iam::register_user().await;
+    iam::make_admin(&admin_user).await;
+    app.start_competition(&admin_user).await;
+
+    let res = app
+        .post(&format!("/v1/competition/{COMPETITION_ID}/problem"))
+        .user(&admin_user)
+        .json(&json!({
+            "body": "some body",
+            "solution": 1,
+        }))
+        .send()
+        .await;
+
+    assert_eq!(res.status(), StatusCode::CREATED);
+
+    let id = res.json::<Value>().await["id"].clone();
+
+    let owner = app.register_user().await;
+    let team = app.create_team(&owner).await;
+    team.lock().await;
+
+    let res = app
+        .post(&format!("/v1/competition/{COMPETITION_ID}/solution"))
+        .user(&owner)
+        .json(&json!({
+            "problem": id,
+            "solution": 2,
+        }))
+        .send()
+        .await;
+
+    assert_eq!(res.status(), StatusCode::NO_CONTENT);
+
+    let time = Utc::now().to_rfc3339();
+
+    let res = app
+        .post(&format!("/v1/competition/{COMPETITION_ID}/stats"))
+        .user(&admin_user)
+        .json(&json!({
+            "timestamp": time,
+        }))
+        .send()
+        .await;
+
+    assert_json_include!(
+        actual: res.json::<Value>().await,
+        expected: json!([{
+            "correct": 0,
+            "wrong": 1,
+        }])
+    );
+
+    let res = app
+        .patch(&format!(
+            "/v1/competition/{COMPETITION_ID}/problem/{}",
+            id.as_str().unwrap()
+        ))
+        .user(&admin_user)
+        .json(&json!({
+            "id": id,
+            "accepted_solutions": [2],
+        }))
+        .send()
+        .await;
+
+    assert_eq!(res.status(), StatusCode::NO_CONTENT);
+
+    // Already submitted answers are rescored too
+    let res = app
+        .post(&format!("/v1/competition/{COMPETITION_ID}/stats"))
+        .user(&admin_user)
+        .json(&json!({
+            "timestamp": time,
+        }))
+        .send()
+        .await;
+
+    assert_json_include!(
+        actual: res.json::<Value>().await,
+        expected: json!([{
+            "correct": 1,
+            "wrong": 0,
+        }])
+    );
+}
+
 #[tokio::test]
 #[serial]
 async fn works() {
@@ -57,10 +249,11 @@ async fn works() {
 
     let admin_user = iam::register_user().await;
     iam::make_admin(&admin_user).await;
+    app.start_competition(&admin_user).await;
 
     // Setup
     let res = app
-        .post("/v1/problem")
+        .post(&format!("/v1/competition/{COMPETITION_ID}/problem"))
         .user(&admin_user)
         .json(&json!({
             "body": "some body",
@@ -81,7 +274,7 @@ async fn works() {
     team.lock().await;
 
     let res = app
-        .post("/v1/competition/solution")
+        .post(&format!("/v1/competition/{COMPETITION_ID}/solution"))
         .user(&owner)
         .json(&json!({
             "problem": id,
@@ -95,7 +288,7 @@ async fn works() {
     let time = Utc::now().to_rfc3339();
 
     let res = app
-        .post("/v1/stats")
+        .post(&format!("/v1/competition/{COMPETITION_ID}/stats"))
         .user(&admin_user)
         .json(&json!({
             "timestamp": time,
@@ -124,10 +317,11 @@ async fn works_after_delete() {
 
     let admin_user = iam::register_user().await;
     iam::make_admin(&admin_user).await;
+    app.start_competition(&admin_user).await;
 
     // Setup
     let res = app
-        .post("/v1/problem")
+        .post(&format!("/v1/competition/{COMPETITION_ID}/problem"))
         .user(&admin_user)
         .json(&json!({
             "body": "some body",
@@ -148,7 +342,7 @@ async fn works_after_delete() {
     team.lock().await;
 
     let res = app
-        .post("/v1/competition/solution")
+        .post(&format!("/v1/competition/{COMPETITION_ID}/solution"))
         .user(&owner)
         .json(&json!({
             "problem": id,
@@ -162,7 +356,7 @@ async fn works_after_delete() {
     let time = Utc::now().to_rfc3339();
 
     let res = app
-        .post("/v1/competition/solution")
+        .post(&format!("/v1/competition/{COMPETITION_ID}/solution"))
         .user(&owner)
         .json(&json!({
             "problem": id,
@@ -176,7 +370,7 @@ async fn works_after_delete() {
     let time2 = Utc::now().to_rfc3339();
 
     let res = app
-        .post("/v1/stats")
+        .post(&format!("/v1/competition/{COMPETITION_ID}/stats"))
         .user(&admin_user)
         .json(&json!({
             "timestamp": time,
@@ -197,7 +391,7 @@ async fn works_after_delete() {
     );
 
     let res = app
-        .post("/v1/stats")
+        .post(&format!("/v1/competition/{COMPETITION_ID}/stats"))
         .user(&admin_user)
         .json(&json!({
             "timestamp": time2,
@@ -226,10 +420,11 @@ async fn works_with_wrong() {
 
     let admin_user = iam::register_user().await;
     iam::make_admin(&admin_user).await;
+    app.start_competition(&admin_user).await;
 
     // Setup
     let res = app
-        .post("/v1/problem")
+        .post(&format!("/v1/competition/{COMPETITION_ID}/problem"))
         .user(&admin_user)
         .json(&json!({
             "body": "some body",
@@ -250,7 +445,7 @@ async fn works_with_wrong() {
     team.lock().await;
 
     let res = app
-        .post("/v1/competition/solution")
+        .post(&format!("/v1/competition/{COMPETITION_ID}/solution"))
         .user(&owner)
         .json(&json!({
             "problem": id,
@@ -264,7 +459,7 @@ async fn works_with_wrong() {
     let time = Utc::now().to_rfc3339();
 
     let res = app
-        .post("/v1/stats")
+        .post(&format!("/v1/competition/{COMPETITION_ID}/stats"))
         .user(&admin_user)
         .json(&json!({
             "timestamp": time,
@@ -284,3 +479,1003 @@ async fn works_with_wrong() {
         }])
     );
 }
+
+mod scoreboard {
+    use super::*;
+
+    #[tokio::test]
+    #[parallel]
+    async fn not_admin() {
+        let app = get_cached_app().await;
+        let user = app.register_user().await;
+
+        let res = app
+            .post(&format!(
+                "/v1/competition/{COMPETITION_ID}/stats/scoreboard"
+            ))
+            .user(&user)
+            .json(&json!({}))
+            .send()
+            .await;
+
+        assert_error!(res, error::NOT_ENOUGH_PERMISSIONS);
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn works() {
+        let app = get_cached_app().await;
+        app.clean_database().await;
+
+        let admin_user = iam::register_user().await;
+        iam::make_admin(&admin_user).await;
+        app.start_competition(&admin_user).await;
+
+        let problem1 = app
+            .create_problem(&admin_user, json!({"points": 3, "wrong_points": -1}))
+            .await;
+        let problem2 = app
+            .create_problem(&admin_user, json!({"points": 2, "wrong_points": 0}))
+            .await;
+
+        let owner1 = app.register_user().await;
+        let team1 = app.create_team(&owner1).await;
+        let owner2 = app.register_user().await;
+        let team2 = app.create_team(&owner2).await;
+        let owner3 = app.register_user().await;
+        let team3 = app.create_team(&owner3).await;
+
+        for (owner, problem, solution) in [
+            (&owner1, &problem1, 23),
+            (&owner1, &problem2, 23),
+            (&owner2, &problem1, 22),
+        ] {
+            let res = app
+                .post(&format!("/v1/competition/{COMPETITION_ID}/solution"))
+                .user(owner)
+                .json(&json!({
+                    "problem": problem,
+                    "solution": solution,
+                }))
+                .send()
+                .await;
+
+            assert!(res.status().is_success());
+        }
+
+        let res = app
+            .post(&format!(
+                "/v1/competition/{COMPETITION_ID}/stats/scoreboard"
+            ))
+            .user(&admin_user)
+            .json(&json!({}))
+            .send()
+            .await;
+
+        assert_eq!(res.status(), StatusCode::OK);
+
+        let body: Value = res.json().await;
+
+        assert_json_include!(
+            actual: body,
+            expected: json!([
+                {
+                    "rank": 1,
+                    "name": team1.get_name(),
+                    "points": 5,
+                    "solved": 2,
+                },
+                {
+                    "rank": 2,
+                    "name": team3.get_name(),
+                    "points": 0,
+                    "solved": 0,
+                },
+                {
+                    "rank": 3,
+                    "name": team2.get_name(),
+                    "points": -1,
+                    "solved": 0,
+                },
+            ])
+        );
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn tie_breakers() {
+        let app = get_cached_app().await;
+        app.clean_database().await;
+
+        let admin_user = iam::register_user().await;
+        iam::make_admin(&admin_user).await;
+        app.start_competition(&admin_user).await;
+
+        let problem = app
+            .create_problem(&admin_user, json!({"points": 3, "wrong_points": 0}))
+            .await;
+
+        let owner1 = app.register_user().await;
+        let team1 = app.create_team(&owner1).await;
+        let owner2 = app.register_user().await;
+        let team2 = app.create_team(&owner2).await;
+
+        // The second team answers correctly first, but the first team made fewer mistakes
+        for (owner, solution) in [(&owner2, 22), (&owner2, 23), (&owner1, 23)] {
+            let res = app
+                .post(&format!("/v1/competition/{COMPETITION_ID}/solution"))
+                .user(owner)
+                .json(&json!({
+                    "problem": problem,
+                    "solution": solution,
+                }))
+                .send()
+                .await;
+
+            assert_eq!(res.status(), StatusCode::NO_CONTENT);
+        }
+
+        let res = app
+            .post(&format!(
+                "/v1/competition/{COMPETITION_ID}/stats/scoreboard"
+            ))
+            .user(&admin_user)
+            .json(&json!({}))
+            .send()
+            .await;
+
+        assert_eq!(res.status(), StatusCode::OK);
+
+        assert_json_include!(
+            actual: res.json::<Value>().await,
+            expected: json!([
+                {
+                    "rank": 1,
+                    "name": team2.get_name(),
+                    "points": 3,
+                    "wrong_answers": 1,
+                    "tie_break": null,
+                },
+                {
+                    "rank": 2,
+                    "name": team1.get_name(),
+                    "points": 3,
+                    "wrong_answers": 0,
+                    "tie_break": "LAST_CORRECT_TIME",
+                },
+            ])
+        );
+
+        let res = app
+            .patch(&format!("/v1/competition/{COMPETITION_ID}"))
+            .user(&admin_user)
+            .json(&json!({
+                "tie_breakers": ["WRONG_ANSWERS"],
+            }))
+            .send()
+            .await;
+
+        assert_eq!(res.status(), StatusCode::NO_CONTENT);
+
+        let res = app
+            .post(&format!(
+                "/v1/competition/{COMPETITION_ID}/stats/scoreboard"
+            ))
+            .user(&admin_user)
+            .json(&json!({}))
+            .send()
+            .await;
+
+        assert_eq!(res.status(), StatusCode::OK);
+
+        assert_json_include!(
+            actual: res.json::<Value>().await,
+            expected: json!([
+                {
+                    "rank": 1,
+                    "name": team1.get_name(),
+                    "tie_break": null,
+                },
+                {
+                    "rank": 2,
+                    "name": team2.get_name(),
+                    "tie_break": "WRONG_ANSWERS",
+                },
+            ])
+        );
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn divisions() {
+        let app = get_cached_app().await;
+        app.clean_database().await;
+
+        let admin_user = iam::register_user().await;
+        iam::make_admin(&admin_user).await;
+        app.start_competition(&admin_user).await;
+
+        let res = app
+            .patch(&format!("/v1/competition/{COMPETITION_ID}"))
+            .user(&admin_user)
+            .json(&json!({
+                "divisions": [
+                    { "name": "junior", "min_class": 9, "max_class": 10 },
+                    { "name": "senior", "min_class": 11, "max_class": 12 },
+                ],
+            }))
+            .send()
+            .await;
+
+        assert_eq!(res.status(), StatusCode::NO_CONTENT);
+
+        let problem = app
+            .create_problem(&admin_user, json!({"points": 3, "wrong_points": 0}))
+            .await;
+
+        let owner1 = app.register_user_in("Test School", 12).await;
+        let team1 = app.create_team(&owner1).await;
+        let owner2 = app.register_user_in("Test School", 9).await;
+        let team2 = app.create_team(&owner2).await;
+        let owner3 = app.register_user_in("Test School", 10).await;
+        let team3 = app.create_team(&owner3).await;
+
+        let res = app
+            .post(&format!("/v1/competition/{COMPETITION_ID}/solution"))
+            .user(&owner1)
+            .json(&json!({
+                "problem": problem,
+                "solution": 23,
+            }))
+            .send()
+            .await;
+
+        assert_eq!(res.status(), StatusCode::NO_CONTENT);
+
+        let res = app
+            .post(&format!(
+                "/v1/competition/{COMPETITION_ID}/stats/scoreboard"
+            ))
+            .user(&admin_user)
+            .json(&json!({}))
+            .send()
+            .await;
+
+        assert_eq!(res.status(), StatusCode::OK);
+
+        let body: Value = res.json().await;
+        let division = |name: String| {
+            body.as_array()
+                .unwrap()
+                .iter()
+                .find(|entry| entry["name"] == name)
+                .unwrap()["division"]
+                .clone()
+        };
+
+        assert_eq!(division(team1.get_name()), "senior");
+        assert_eq!(division(team2.get_name()), "junior");
+        assert_eq!(division(team3.get_name()), "junior");
+
+        let res = app
+            .post(&format!(
+                "/v1/competition/{COMPETITION_ID}/stats/scoreboard"
+            ))
+            .user(&admin_user)
+            .json(&json!({
+                "division": "junior",
+            }))
+            .send()
+            .await;
+
+        assert_eq!(res.status(), StatusCode::OK);
+
+        let body: Value = res.json().await;
+        assert_eq!(body.as_array().unwrap().len(), 2);
+        assert_eq!(body[0]["rank"], 1);
+        assert_eq!(body[0]["division"], "junior");
+
+        let res = app
+            .post(&format!(
+                "/v1/competition/{COMPETITION_ID}/stats/scoreboard"
+            ))
+            .user(&admin_user)
+            .json(&json!({
+                "division": "unknown",
+            }))
+            .send()
+            .await;
+
+        assert_error!(res, error::DIVISION_NOT_FOUND);
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn attempt_penalty() {
+        let app = get_cached_app().await;
+        app.clean_database().await;
+
+        let admin_user = iam::register_user().await;
+        iam::make_admin(&admin_user).await;
+        app.start_competition(&admin_user).await;
+
+        let res = app
+            .post(&format!("/v1/competition/{COMPETITION_ID}/problem"))
+            .user(&admin_user)
+            .json(&json!({
+                "body": "some body",
+                "solution": 23,
+                "points": 5,
+                "attempt_penalty": -1,
+            }))
+            .send()
+            .await;
+
+        assert_eq!(res.status(), StatusCode::CREATED);
+
+        let problem = res.json::<Value>().await["id"].clone();
+
+        let owner = app.register_user().await;
+        let team = app.create_team(&owner).await;
+
+        // Clearing the answer is not a wrong attempt
+        for solution in [json!(21), json!(null), json!(22), json!(23)] {
+            let res = app
+                .post(&format!("/v1/competition/{COMPETITION_ID}/solution"))
+                .user(&owner)
+                .json(&json!({
+                    "problem": problem,
+                    "solution": solution,
+                }))
+                .send()
+                .await;
+
+            assert_eq!(res.status(), StatusCode::NO_CONTENT);
+        }
+
+        let res = app
+            .post(&format!(
+                "/v1/competition/{COMPETITION_ID}/stats/scoreboard"
+            ))
+            .user(&admin_user)
+            .json(&json!({}))
+            .send()
+            .await;
+
+        assert_eq!(res.status(), StatusCode::OK);
+
+        assert_json_include!(
+            actual: res.json::<Value>().await,
+            expected: json!([
+                {
+                    "rank": 1,
+                    "name": team.get_name(),
+                    "points": 3,
+                    "solved": 1,
+                },
+            ])
+        );
+    }
+}
+
+mod problems {
+    use super::*;
+
+    #[tokio::test]
+    #[parallel]
+    async fn not_admin() {
+        let app = get_cached_app().await;
+        let user = app.register_user().await;
+
+        let res = app
+            .post(&format!("/v1/competition/{COMPETITION_ID}/stats/problems"))
+            .user(&user)
+            .json(&json!({}))
+            .send()
+            .await;
+
+        assert_error!(res, error::NOT_ENOUGH_PERMISSIONS);
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn works() {
+        let app = get_cached_app().await;
+        app.clean_database().await;
+
+        let admin_user = iam::register_user().await;
+        iam::make_admin(&admin_user).await;
+        app.start_competition(&admin_user).await;
+
+        let mut problems = Vec::new();
+
+        for _ in 0..2 {
+            let res = app
+                .post(&format!("/v1/competition/{COMPETITION_ID}/problem"))
+                .user(&admin_user)
+                .json(&json!({
+                    "body": "some body",
+                    "solution": 23,
+                }))
+                .send()
+                .await;
+
+            assert_eq!(res.status(), StatusCode::CREATED);
+
+            let id = res.json::<Value>().await["id"].clone();
+
+            let res = app
+                .post(&format!("/v1/competition/{COMPETITION_ID}/problem/order"))
+                .user(&admin_user)
+                .json(&json!({
+                    "type": "INSERT",
+                    "id": id,
+                }))
+                .send()
+                .await;
+
+            assert_eq!(res.status(), StatusCode::NO_CONTENT);
+
+            problems.push(id);
+        }
+
+        for solution in [23, 22] {
+            let owner = app.register_user().await;
+            let _ = app.create_team(&owner).await;
+
+            let res = app
+                .post(&format!("/v1/competition/{COMPETITION_ID}/solution"))
+                .user(&owner)
+                .json(&json!({
+                    "problem": problems[0],
+                    "solution": solution,
+                }))
+                .send()
+                .await;
+
+            assert_eq!(res.status(), StatusCode::NO_CONTENT);
+        }
+
+        let res = app
+            .post(&format!("/v1/competition/{COMPETITION_ID}/stats/problems"))
+            .user(&admin_user)
+            .json(&json!({}))
+            .send()
+            .await;
+
+        assert_eq!(res.status(), StatusCode::OK);
+
+        let body: Value = res.json().await;
+
+        assert_json_include!(
+            actual: &body,
+            expected: json!([
+                {
+                    "problem_id": problems[0],
+                    "answered": 2,
+                    "correct": 1,
+                    "success_rate": 0.5,
+                },
+                {
+                    "problem_id": problems[1],
+                    "answered": 0,
+                    "correct": 0,
+                    "success_rate": null,
+                    "median_solve_seconds": null,
+                },
+            ])
+        );
+
+        // The competition started an hour ago
+        let median = body[0]["median_solve_seconds"].as_f64().unwrap();
+        assert!((3600.0..3700.0).contains(&median));
+    }
+}
+
+mod timeline {
+    use super::*;
+
+    #[tokio::test]
+    #[parallel]
+    async fn not_admin() {
+        let app = get_cached_app().await;
+        let user = app.register_user().await;
+
+        let res = app
+            .post(&format!("/v1/competition/{COMPETITION_ID}/stats/timeline"))
+            .user(&user)
+            .json(&json!({
+                "start": Utc::now().to_rfc3339(),
+                "end": Utc::now().to_rfc3339(),
+                "bucket_seconds": 1,
+            }))
+            .send()
+            .await;
+
+        assert_error!(res, error::NOT_ENOUGH_PERMISSIONS);
+    }
+
+    #[tokio::test]
+    #[parallel]
+    async fn too_many_buckets() {
+        let app = get_cached_app().await;
+        let user = iam::register_user().await;
+        iam::make_admin(&user).await;
+
+        let start = Utc::now();
+
+        let res = app
+            .post(&format!("/v1/competition/{COMPETITION_ID}/stats/timeline"))
+            .user(&user)
+            .json(&json!({
+                "start": start.to_rfc3339(),
+                "end": (start + chrono::Duration::days(1)).to_rfc3339(),
+                "bucket_seconds": 1,
+            }))
+            .send()
+            .await;
+
+        assert_error!(res, error::JSON_VALIDATE_INVALID);
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn works() {
+        let app = get_cached_app().await;
+        app.clean_database().await;
+
+        let admin_user = iam::register_user().await;
+        iam::make_admin(&admin_user).await;
+        app.start_competition(&admin_user).await;
+
+        let res = app
+            .post(&format!("/v1/competition/{COMPETITION_ID}/problem"))
+            .user(&admin_user)
+            .json(&json!({
+                "body": "some body",
+                "solution": 23,
+            }))
+            .send()
+            .await;
+
+        assert_eq!(res.status(), StatusCode::CREATED);
+
+        let problem = res.json::<Value>().await["id"].clone();
+
+        let owner = app.register_user().await;
+        let _ = app.create_team(&owner).await;
+
+        let start = Utc::now();
+
+        for solution in [22, 23] {
+            let res = app
+                .post(&format!("/v1/competition/{COMPETITION_ID}/solution"))
+                .user(&owner)
+                .json(&json!({
+                    "problem": problem,
+                    "solution": solution,
+                }))
+                .send()
+                .await;
+
+            assert_eq!(res.status(), StatusCode::NO_CONTENT);
+
+            tokio::time::sleep(std::time::Duration::from_secs(2)).await;
+        }
+
+        let res = app
+            .post(&format!("/v1/competition/{COMPETITION_ID}/stats/timeline"))
+            .user(&admin_user)
+            .json(&json!({
+                "start": start.to_rfc3339(),
+                "end": (start + chrono::Duration::seconds(3)).to_rfc3339(),
+                "bucket_seconds": 1,
+            }))
+            .send()
+            .await;
+
+        assert_eq!(res.status(), StatusCode::OK);
+
+        let body: Value = res.json().await;
+        let counts = body
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|bucket| {
+                let team = &bucket["teams"][0];
+                (
+                    team["correct"].as_i64().unwrap(),
+                    team["wrong"].as_i64().unwrap(),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        // The correct answer is sent a bit more than 2 seconds after the start
+        assert_eq!(counts.len(), 4);
+        assert_eq!(counts[0], (0, 0));
+        assert_eq!(counts[1], (0, 1));
+        assert_eq!(counts[3], (1, 0));
+    }
+}
+
+mod groups {
+    use super::*;
+
+    #[tokio::test]
+    #[parallel]
+    async fn not_admin() {
+        let app = get_cached_app().await;
+        let user = app.register_user().await;
+
+        let res = app
+            .post(&format!("/v1/competition/{COMPETITION_ID}/stats/groups"))
+            .user(&user)
+            .json(&json!({}))
+            .send()
+            .await;
+
+        assert_error!(res, error::NOT_ENOUGH_PERMISSIONS);
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn works() {
+        let app = get_cached_app().await;
+        app.clean_database().await;
+
+        let admin_user = iam::register_user().await;
+        iam::make_admin(&admin_user).await;
+        app.start_competition(&admin_user).await;
+
+        let res = app
+            .post(&format!("/v1/competition/{COMPETITION_ID}/problem"))
+            .user(&admin_user)
+            .json(&json!({
+                "body": "some body",
+                "solution": 23,
+            }))
+            .send()
+            .await;
+
+        assert_eq!(res.status(), StatusCode::CREATED);
+
+        let problem = res.json::<Value>().await["id"].clone();
+
+        let owner1 = app.register_user_in("School A", 9).await;
+        let team1 = app.create_team(&owner1).await;
+        let owner2 = app.register_user_in("School B", 10).await;
+        let team2 = app.create_team(&owner2).await;
+        let member2 = app.register_user_in("School A", 10).await;
+        member2.join(&team2.get_code().await).await;
+
+        for (owner, solution) in [(&owner1, 23), (&owner2, 22)] {
+            let res = app
+                .post(&format!("/v1/competition/{COMPETITION_ID}/solution"))
+                .user(owner)
+                .json(&json!({
+                    "problem": problem,
+                    "solution": solution,
+                }))
+                .send()
+                .await;
+
+            assert_eq!(res.status(), StatusCode::NO_CONTENT);
+        }
+
+        let res = app
+            .post(&format!("/v1/competition/{COMPETITION_ID}/stats/groups"))
+            .user(&admin_user)
+            .json(&json!({}))
+            .send()
+            .await;
+
+        assert_eq!(res.status(), StatusCode::OK);
+
+        assert_json_include!(
+            actual: res.json::<Value>().await,
+            expected: json!({
+                "schools": [
+                    {
+                        "school": "School A",
+                        "teams": 2,
+                        "students": 2,
+                        "average_correct": 0.5,
+                        "best_team": { "name": team1.get_name() },
+                    },
+                    {
+                        "school": "School B",
+                        "teams": 1,
+                        "students": 1,
+                        "average_correct": 0.0,
+                        "best_team": { "name": team2.get_name() },
+                    },
+                ],
+                "classes": [
+                    {
+                        "class": 9,
+                        "teams": 1,
+                        "students": 1,
+                        "average_correct": 1.0,
+                        "best_team": { "name": team1.get_name() },
+                    },
+                    {
+                        "class": 10,
+                        "teams": 1,
+                        "students": 2,
+                        "average_correct": 0.0,
+                        "best_team": { "name": team2.get_name() },
+                    },
+                ],
+            })
+        );
+    }
+}
+
+mod report {
+    use super::*;
+
+    #[tokio::test]
+    #[parallel]
+    async fn not_admin() {
+        let app = get_cached_app().await;
+        let user = app.register_user().await;
+
+        let res = app
+            .get(&format!("/v1/competition/{COMPETITION_ID}/stats/report"))
+            .user(&user)
+            .send()
+            .await;
+
+        assert_error!(res, error::NOT_ENOUGH_PERMISSIONS);
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn works() {
+        let app = get_cached_app().await;
+        app.clean_database().await;
+
+        let admin_user = iam::register_user().await;
+        iam::make_admin(&admin_user).await;
+        app.start_competition(&admin_user).await;
+
+        let res = app
+            .post(&format!("/v1/competition/{COMPETITION_ID}/problem"))
+            .user(&admin_user)
+            .json(&json!({
+                "body": "some body",
+                "solution": 23,
+            }))
+            .send()
+            .await;
+
+        assert_eq!(res.status(), StatusCode::CREATED);
+
+        let problem = res.json::<Value>().await["id"].clone();
+
+        let owner = app.register_user_in("Report School", 10).await;
+        let team = app.create_team(&owner).await;
+
+        let res = app
+            .post(&format!("/v1/competition/{COMPETITION_ID}/solution"))
+            .user(&owner)
+            .json(&json!({
+                "problem": problem,
+                "solution": 23,
+            }))
+            .send()
+            .await;
+
+        assert_eq!(res.status(), StatusCode::NO_CONTENT);
+
+        let res = app
+            .get(&format!("/v1/competition/{COMPETITION_ID}/stats/report"))
+            .user(&admin_user)
+            .send()
+            .await;
+
+        assert_eq!(res.status(), StatusCode::OK);
+        assert!(
+            res.headers()[header::CONTENT_TYPE]
+                .to_str()
+                .unwrap()
+                .starts_with("text/html")
+        );
+
+        let body = res.text().await;
+
+        assert!(body.starts_with("<!DOCTYPE html>"));
+        assert!(body.contains(&format!("<td>{}</td>", team.get_name())));
+        assert!(body.contains("<td>Report School</td>"));
+        assert!(body.contains(&format!("<td>{}</td>", problem.as_str().unwrap())));
+        assert!(body.contains("<td class=\"number\">100%</td>"));
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn division() {
+        let app = get_cached_app().await;
+        app.clean_database().await;
+
+        let admin_user = iam::register_user().await;
+        iam::make_admin(&admin_user).await;
+
+        let res = app
+            .patch(&format!("/v1/competition/{COMPETITION_ID}"))
+            .user(&admin_user)
+            .json(&json!({
+                "divisions": [
+                    { "name": "junior", "min_class": 9, "max_class": 10 },
+                    { "name": "senior", "min_class": 11, "max_class": 12 },
+                ],
+            }))
+            .send()
+            .await;
+
+        assert_eq!(res.status(), StatusCode::NO_CONTENT);
+
+        let senior = app.register_user_in("Senior School", 12).await;
+        app.create_team(&senior).await;
+        let junior = app.register_user_in("Junior School", 9).await;
+        let junior_team = app.create_team(&junior).await;
+
+        let res = app
+            .get(&format!(
+                "/v1/competition/{COMPETITION_ID}/stats/report?division=junior"
+            ))
+            .user(&admin_user)
+            .send()
+            .await;
+
+        assert_eq!(res.status(), StatusCode::OK);
+
+        let body = res.text().await;
+
+        assert!(body.contains(&format!("<td>{}</td>", junior_team.get_name())));
+        assert!(body.contains("<td>Junior School</td>"));
+        assert!(!body.contains("Senior School"));
+        assert!(!body.contains("<td>senior</td>"));
+    }
+}
+
+mod history {
+    use super::*;
+
+    #[tokio::test]
+    #[parallel]
+    async fn not_admin() {
+        let app = get_cached_app().await;
+        let user = app.register_user().await;
+
+        let res = app
+            .post(&format!("/v1/competition/{COMPETITION_ID}/stats/history"))
+            .user(&user)
+            .json(&json!({}))
+            .send()
+            .await;
+
+        assert_error!(res, error::NOT_ENOUGH_PERMISSIONS);
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn works() {
+        let app = get_cached_app().await;
+        app.clean_database().await;
+
+        let admin_user = iam::register_user().await;
+        iam::make_admin(&admin_user).await;
+        app.start_competition(&admin_user).await;
+
+        let res = app
+            .post(&format!("/v1/competition/{COMPETITION_ID}/problem"))
+            .user(&admin_user)
+            .json(&json!({
+                "body": "some body",
+                "solution": 23,
+                "points": 3,
+            }))
+            .send()
+            .await;
+
+        assert_eq!(res.status(), StatusCode::CREATED);
+
+        let problem = res.json::<Value>().await["id"].clone();
+
+        let owner1 = app.register_user().await;
+        let team1 = app.create_team(&owner1).await;
+        let owner2 = app.register_user().await;
+        let team2 = app.create_team(&owner2).await;
+
+        for (owner, solution) in [(&owner1, 22), (&owner2, 21), (&owner1, 23)] {
+            let res = app
+                .post(&format!("/v1/competition/{COMPETITION_ID}/solution"))
+                .user(owner)
+                .json(&json!({
+                    "problem": problem,
+                    "solution": solution,
+                }))
+                .send()
+                .await;
+
+            assert_eq!(res.status(), StatusCode::NO_CONTENT);
+        }
+
+        let team1_id = team1.get_id(&admin_user).await;
+
+        let res = app
+            .post(&format!("/v1/competition/{COMPETITION_ID}/stats/history"))
+            .user(&admin_user)
+            .json(&json!({
+                "team": team1_id,
+            }))
+            .send()
+            .await;
+
+        assert_eq!(res.status(), StatusCode::OK);
+
+        assert_json_include!(
+            actual: res.json::<Value>().await,
+            expected: json!({
+                "total": 2,
+                "entries": [
+                    {
+                        "team_id": team1_id,
+                        "team_name": team1.get_name(),
+                        "problem": problem,
+                        "user": owner1.id.strip_prefix("UserID-").unwrap(),
+                        "solution": "23",
+                    },
+                    {
+                        "solution": "22",
+                    },
+                ],
+            })
+        );
+
+        let res = app
+            .post(&format!("/v1/competition/{COMPETITION_ID}/stats/history"))
+            .user(&admin_user)
+            .json(&json!({
+                "user": owner2.id.strip_prefix("UserID-").unwrap(),
+            }))
+            .send()
+            .await;
+
+        assert_eq!(res.status(), StatusCode::OK);
+
+        let body: Value = res.json().await;
+        assert_eq!(body["total"], 1);
+        assert_eq!(body["entries"][0]["team_name"], team2.get_name());
+        assert_eq!(body["entries"][0]["solution"], "21");
+
+        let res = app
+            .post(&format!("/v1/competition/{COMPETITION_ID}/stats/history"))
+            .user(&admin_user)
+            .json(&json!({
+                "order": "ASC",
+                "page": 1,
+                "per_page": 2,
+            }))
+            .send()
+            .await;
+
+        assert_eq!(res.status(), StatusCode::OK);
+
+        let body: Value = res.json().await;
+        assert_eq!(body["total"], 3);
+        assert_eq!(body["entries"].as_array().unwrap().len(), 1);
+        assert_eq!(body["entries"][0]["solution"], "23");
+
+        let res = app
+            .post(&format!("/v1/competition/{COMPETITION_ID}/stats/history"))
+            .user(&admin_user)
+            .json(&json!({
+                "per_page": 0,
+            }))
+            .send()
+            .await;
+
+        assert_error!(res, error::JSON_VALIDATE_INVALID);
+    }
+}
@@ -1,5 +1,15 @@
 use test_utils::prelude::*;
 
+async fn join(app: &App, user: &impl UserLike, code: &str) -> TestResponse {
+    app.post(&format!("/v1/competition/{COMPETITION_ID}/team/join"))
+        .user(user)
+        .json(&json!({
+            "code": code,
+        }))
+        .send()
+        .await
+}
+
 mod create {
     use super::*;
 
@@ -10,7 +20,7 @@ mod create {
         let user = app.register_user().await;
 
         let res = app
-            .post("/v1/team/create")
+            .post(&format!("/v1/competition/{COMPETITION_ID}/team/create"))
             .user(&user)
             .json(&json!({
                 "name": uuid(),
@@ -28,7 +38,7 @@ mod create {
         let user = app.register_user().await;
 
         let res = app
-            .post("/v1/team/create/")
+            .post(&format!("/v1/competition/{COMPETITION_ID}/team/create/"))
             .user(&user)
             .json(&json!({
                 "name": uuid(),
@@ -46,7 +56,7 @@ mod create {
         let user = app.register_user().await;
 
         let res = app
-            .post("/v1/team/create")
+            .post(&format!("/v1/competition/{COMPETITION_ID}/team/create"))
             .user(&user)
             .json(&json!({
                 "name": "Test Team",
@@ -59,7 +69,7 @@ mod create {
         let user2 = app.register_user().await;
 
         let res = app
-            .post("/v1/team/create")
+            .post(&format!("/v1/competition/{COMPETITION_ID}/team/create"))
             .user(&user2)
             .json(&json!({
                 "name": "Test Team",
@@ -77,7 +87,7 @@ mod create {
         let user = app.register_user().await;
 
         let res = app
-            .post("/v1/team/create")
+            .post(&format!("/v1/competition/{COMPETITION_ID}/team/create"))
             .user(&user)
             .json(&json!({
                 "name": uuid(),
@@ -88,7 +98,7 @@ mod create {
         assert_eq!(res.status(), StatusCode::CREATED);
 
         let res = app
-            .post("/v1/team/create")
+            .post(&format!("/v1/competition/{COMPETITION_ID}/team/create"))
             .user(&user)
             .json(&json!({
                 "name": uuid(),
@@ -106,7 +116,7 @@ mod create {
         let user = iam::register_user().await;
 
         let res = app
-            .post("/v1/team/create")
+            .post(&format!("/v1/competition/{COMPETITION_ID}/team/create"))
             .user(&user)
             .json(&json!({
                 "name": "Test Team",
@@ -128,14 +138,17 @@ mod join {
         let owner = app.register_user().await;
         let team = app.create_team(&owner).await;
 
-        let mut socket = app.socket("/v1/ws").start().await;
+        let mut socket = app
+            .socket(&format!("/v1/competition/{COMPETITION_ID}/ws"))
+            .start()
+            .await;
         assert_team_info!(socket, owner);
 
         let join_code = team.get_code().await;
         let user = app.register_user().await;
 
         let res = app
-            .post("/v1/team/join")
+            .post(&format!("/v1/competition/{COMPETITION_ID}/team/join"))
             .user(&user)
             .json(&json!({
                 "code": join_code,
@@ -170,7 +183,7 @@ mod join {
         let user = app.register_user().await;
 
         let res = app
-            .post("/v1/team/join")
+            .post(&format!("/v1/competition/{COMPETITION_ID}/team/join"))
             .user(&user)
             .json(&json!({
                 "code": "AAAAAA",
@@ -192,7 +205,7 @@ mod join {
         let team2 = app.create_team(&user2).await;
 
         let res = app
-            .post("/v1/team/join")
+            .post(&format!("/v1/competition/{COMPETITION_ID}/team/join"))
             .user(&user1)
             .json(&json!({
                 "code": team2.get_code().await,
@@ -215,7 +228,7 @@ mod join {
         let user = app.register_user().await;
 
         let res = app
-            .post("/v1/team/join")
+            .post(&format!("/v1/competition/{COMPETITION_ID}/team/join"))
             .user(&user)
             .json(&json!({
                 "code": team.get_code().await,
@@ -245,7 +258,7 @@ mod join {
         let user = app.register_user().await;
 
         let res = app
-            .post("/v1/team/join")
+            .post(&format!("/v1/competition/{COMPETITION_ID}/team/join"))
             .user(&user)
             .json(&json!({
                 "code": join_code,
@@ -270,10 +283,17 @@ mod leave {
         let member = app.register_user().await;
         member.join(&team.get_code().await).await;
 
-        let mut socket = app.socket("/v1/ws").start().await;
+        let mut socket = app
+            .socket(&format!("/v1/competition/{COMPETITION_ID}/ws"))
+            .start()
+            .await;
         assert_team_info!(socket, owner);
 
-        let res = app.post("/v1/team/leave").user(&member).send().await;
+        let res = app
+            .post(&format!("/v1/competition/{COMPETITION_ID}/team/leave"))
+            .user(&member)
+            .send()
+            .await;
         assert_eq!(res.status(), StatusCode::OK);
 
         let message = get_socket_message(socket.next().await);
@@ -300,7 +320,11 @@ mod leave {
 
         let user = app.register_user().await;
 
-        let res = app.post("/v1/team/leave").user(&user).send().await;
+        let res = app
+            .post(&format!("/v1/competition/{COMPETITION_ID}/team/leave"))
+            .user(&user)
+            .send()
+            .await;
 
         assert_error!(res, error::USER_NOT_IN_TEAM);
     }
@@ -317,7 +341,11 @@ mod leave {
 
         team.lock().await;
 
-        let res = app.post("/v1/team/leave").user(&member).send().await;
+        let res = app
+            .post(&format!("/v1/competition/{COMPETITION_ID}/team/leave"))
+            .user(&member)
+            .send()
+            .await;
         assert_error!(res, error::LOCKED_TEAM);
     }
 
@@ -328,7 +356,11 @@ mod leave {
         let owner = app.register_user().await;
         let _team = app.create_team(&owner).await;
 
-        let res = app.post("/v1/team/leave").user(&owner).send().await;
+        let res = app
+            .post(&format!("/v1/competition/{COMPETITION_ID}/team/leave"))
+            .user(&owner)
+            .send()
+            .await;
 
         assert_error!(res, error::OWNER_CANNOT_LEAVE);
     }
@@ -345,7 +377,7 @@ mod update {
         let _team = app.create_team(&user).await;
 
         let res = app
-            .patch("/v1/team")
+            .patch(&format!("/v1/competition/{COMPETITION_ID}/team"))
             .user(&user)
             .json(&json!({}))
             .send()
@@ -365,7 +397,7 @@ mod update {
         member.join(&team.get_code().await).await;
 
         let res = app
-            .patch("/v1/team")
+            .patch(&format!("/v1/competition/{COMPETITION_ID}/team"))
             .user(&member)
             .json(&json!({
                 "owner": member.id.strip_prefix("UserID-").unwrap(),
@@ -376,7 +408,7 @@ mod update {
         assert_error!(res, error::USER_NOT_OWNER);
 
         let res = app
-            .patch("/v1/team")
+            .patch(&format!("/v1/competition/{COMPETITION_ID}/team"))
             .user(&member)
             .json(&json!({
                 "coowner": member.id,
@@ -395,7 +427,7 @@ mod update {
         let _team = app.create_team(&owner).await;
 
         let res = app
-            .patch("/v1/team")
+            .patch(&format!("/v1/competition/{COMPETITION_ID}/team"))
             .user(&owner)
             .json(&json!({
                 "co_owner": uuid::Uuid::nil(),
@@ -406,7 +438,7 @@ mod update {
         assert_error!(res, error::NO_SUCH_MEMBER);
 
         let res = app
-            .patch("/v1/team")
+            .patch(&format!("/v1/competition/{COMPETITION_ID}/team"))
             .user(&owner)
             .json(&json!({
                 "owner": uuid::Uuid::nil(),
@@ -427,7 +459,7 @@ mod update {
         let user = app.register_user().await;
 
         let res = app
-            .patch("/v1/team")
+            .patch(&format!("/v1/competition/{COMPETITION_ID}/team"))
             .user(&owner)
             .json(&json!({
                 "co_owner": user.id.strip_prefix("UserID-").unwrap(),
@@ -438,7 +470,7 @@ mod update {
         assert_error!(res, error::NO_SUCH_MEMBER);
 
         let res = app
-            .patch("/v1/team")
+            .patch(&format!("/v1/competition/{COMPETITION_ID}/team"))
             .user(&owner)
             .json(&json!({
                 "owner": user.id.strip_prefix("UserID-").unwrap(),
@@ -456,7 +488,7 @@ mod update {
         let user = app.register_user().await;
 
         let res = app
-            .patch("/v1/team")
+            .patch(&format!("/v1/competition/{COMPETITION_ID}/team"))
             .user(&user)
             .json(&json!({
                 "name": "some cool team name",
@@ -475,7 +507,7 @@ mod update {
         let _team = app.create_team(&user).await;
 
         let res = app
-            .patch("/v1/team")
+            .patch(&format!("/v1/competition/{COMPETITION_ID}/team"))
             .user(&user)
             .json(&json!({
                 "name": "best team name ever",
@@ -495,7 +527,7 @@ mod update {
         let _team = app.create_team(&user).await;
 
         let res = app
-            .patch("/v1/team")
+            .patch(&format!("/v1/competition/{COMPETITION_ID}/team"))
             .user(&user)
             .json(&json!({
                 "locked": true,
@@ -506,7 +538,7 @@ mod update {
         assert_eq!(res.status(), StatusCode::NO_CONTENT);
 
         let res = app
-            .patch("/v1/team")
+            .patch(&format!("/v1/competition/{COMPETITION_ID}/team"))
             .user(&user)
             .json(&json!({
                 "name": "the worst team name ever",
@@ -525,7 +557,7 @@ mod update {
         let _team = app.create_team(&user).await;
 
         let res = app
-            .patch("/v1/team")
+            .patch(&format!("/v1/competition/{COMPETITION_ID}/team"))
             .user(&user)
             .json(&json!({
                 "locked": true,
@@ -536,7 +568,7 @@ mod update {
         assert_eq!(res.status(), StatusCode::NO_CONTENT);
 
         let res = app
-            .patch("/v1/team")
+            .patch(&format!("/v1/competition/{COMPETITION_ID}/team"))
             .user(&user)
             .json(&json!({
                 "name": "the worst team name ever",
@@ -555,11 +587,14 @@ mod update {
         let user = app.register_user().await;
         let _team = app.create_team(&user).await;
 
-        let mut socket = app.socket("/v1/ws").start().await;
+        let mut socket = app
+            .socket(&format!("/v1/competition/{COMPETITION_ID}/ws"))
+            .start()
+            .await;
         assert_team_info!(socket, user);
 
         let res = app
-            .patch("/v1/team")
+            .patch(&format!("/v1/competition/{COMPETITION_ID}/team"))
             .user(&user)
             .json(&json!({
                 "name": "new name",
@@ -594,13 +629,16 @@ mod update {
         let member = app.register_user().await;
         member.join(&team.get_code().await).await;
 
-        let mut socket = app.socket("/v1/ws").start().await;
+        let mut socket = app
+            .socket(&format!("/v1/competition/{COMPETITION_ID}/ws"))
+            .start()
+            .await;
         assert_team_info!(socket, owner);
 
         let member_uuid = member.id.strip_prefix("UserID-").unwrap();
 
         let res = app
-            .patch("/v1/team")
+            .patch(&format!("/v1/competition/{COMPETITION_ID}/team"))
             .user(&owner)
             .json(&json!({
                 "owner": member_uuid,
@@ -635,13 +673,16 @@ mod update {
         let member = app.register_user().await;
         member.join(&team.get_code().await).await;
 
-        let mut socket = app.socket("/v1/ws").start().await;
+        let mut socket = app
+            .socket(&format!("/v1/competition/{COMPETITION_ID}/ws"))
+            .start()
+            .await;
         assert_team_info!(socket, owner);
 
         let member_id = member.id.strip_prefix("UserID-").unwrap();
 
         let res = app
-            .patch("/v1/team")
+            .patch(&format!("/v1/competition/{COMPETITION_ID}/team"))
             .user(&owner)
             .json(&json!({
                 "co_owner": member_id,
@@ -674,11 +715,14 @@ mod update {
         let owner = app.register_user().await;
         let _team = app.create_team(&owner).await;
 
-        let mut socket = app.socket("/v1/ws").start().await;
+        let mut socket = app
+            .socket(&format!("/v1/competition/{COMPETITION_ID}/ws"))
+            .start()
+            .await;
         assert_team_info!(socket, owner);
 
         let res = app
-            .patch("/v1/team")
+            .patch(&format!("/v1/competition/{COMPETITION_ID}/team"))
             .user(&owner)
             .json(&json!({ "co_owner": null }))
             .send()
@@ -711,7 +755,11 @@ mod disband {
         let app = get_cached_app().await;
         let user = app.register_user().await;
 
-        let res = app.post("/v1/team/disband").user(&user).send().await;
+        let res = app
+            .post(&format!("/v1/competition/{COMPETITION_ID}/team/disband"))
+            .user(&user)
+            .send()
+            .await;
 
         assert_error!(res, error::USER_NOT_IN_TEAM);
     }
@@ -726,7 +774,11 @@ mod disband {
         let member = app.register_user().await;
         member.join(&team.get_code().await).await;
 
-        let res = app.post("/v1/team/disband").user(&member).send().await;
+        let res = app
+            .post(&format!("/v1/competition/{COMPETITION_ID}/team/disband"))
+            .user(&member)
+            .send()
+            .await;
 
         assert_error!(res, error::USER_NOT_OWNER);
     }
@@ -740,7 +792,11 @@ mod disband {
 
         team.lock().await;
 
-        let res = app.post("/v1/team/disband").user(&owner).send().await;
+        let res = app
+            .post(&format!("/v1/competition/{COMPETITION_ID}/team/disband"))
+            .user(&owner)
+            .send()
+            .await;
 
         assert_error!(res, error::LOCKED_TEAM);
     }
@@ -758,17 +814,34 @@ mod disband {
         let member2 = app.register_user().await;
         member2.join(&team.get_code().await).await;
 
-        let mut socket1 = app.socket("/v1/ws").start().await;
+        let mut socket1 = app
+            .socket(&format!("/v1/competition/{COMPETITION_ID}/ws"))
+            .start()
+            .await;
         assert_team_info!(socket1, owner);
-        let mut socket2 = app.socket("/v1/ws").start().await;
+        let mut socket2 = app
+            .socket(&format!("/v1/competition/{COMPETITION_ID}/ws"))
+            .start()
+            .await;
         assert_team_info!(socket2, member1);
-        let mut socket3 = app.socket("/v1/ws").start().await;
+        let mut socket3 = app
+            .socket(&format!("/v1/competition/{COMPETITION_ID}/ws"))
+            .start()
+            .await;
         assert_team_info!(socket3, member2);
 
-        let res = app.post("/v1/team/disband").user(&owner).send().await;
+        let res = app
+            .post(&format!("/v1/competition/{COMPETITION_ID}/team/disband"))
+            .user(&owner)
+            .send()
+            .await;
         assert_eq!(res.status(), StatusCode::NO_CONTENT);
 
-        let res = app.post("/v1/team/leave").user(&owner).send().await;
+        let res = app
+            .post(&format!("/v1/competition/{COMPETITION_ID}/team/leave"))
+            .user(&owner)
+            .send()
+            .await;
         assert_error!(res, error::USER_NOT_IN_TEAM);
         let message = socket1.next().await;
         assert_close_frame!(
@@ -779,7 +852,11 @@ mod disband {
             },
         );
 
-        let res = app.post("/v1/team/leave").user(&member1).send().await;
+        let res = app
+            .post(&format!("/v1/competition/{COMPETITION_ID}/team/leave"))
+            .user(&member1)
+            .send()
+            .await;
         assert_error!(res, error::USER_NOT_IN_TEAM);
         let message = socket2.next().await;
         assert_close_frame!(
@@ -790,7 +867,11 @@ mod disband {
             },
         );
 
-        let res = app.post("/v1/team/leave").user(&member2).send().await;
+        let res = app
+            .post(&format!("/v1/competition/{COMPETITION_ID}/team/leave"))
+            .user(&member2)
+            .send()
+            .await;
         assert_error!(res, error::USER_NOT_IN_TEAM);
         let message = socket3.next().await;
         assert_close_frame!(
@@ -820,7 +901,7 @@ mod kick {
         member2.join(&team.get_code().await).await;
 
         let res = app
-            .post("/v1/team/kick")
+            .post(&format!("/v1/competition/{COMPETITION_ID}/team/kick"))
             .user(&member1)
             .json(&json!({
                 "user": member2.id.strip_prefix("UserID-").unwrap(),
@@ -844,7 +925,7 @@ mod kick {
         team.lock().await;
 
         let res = app
-            .post("/v1/team/kick")
+            .post(&format!("/v1/competition/{COMPETITION_ID}/team/kick"))
             .user(&owner)
             .json(&json!({
                 "user": member.id.strip_prefix("UserID-").unwrap(),
@@ -866,7 +947,7 @@ mod kick {
         member.join(&team.get_code().await).await;
 
         let res = app
-            .patch("/v1/team")
+            .patch(&format!("/v1/competition/{COMPETITION_ID}/team"))
             .user(&owner)
             .json(&json!({
                 "co_owner": member.id.strip_prefix("UserID-").unwrap(),
@@ -877,7 +958,7 @@ mod kick {
         assert_eq!(res.status(), StatusCode::NO_CONTENT);
 
         let res = app
-            .post("/v1/team/kick")
+            .post(&format!("/v1/competition/{COMPETITION_ID}/team/kick"))
             .user(&member)
             .json(&json!({
                 "user": owner.id.strip_prefix("UserID-").unwrap(),
@@ -899,7 +980,7 @@ mod kick {
         member.join(&team.get_code().await).await;
 
         let res = app
-            .patch("/v1/team")
+            .patch(&format!("/v1/competition/{COMPETITION_ID}/team"))
             .user(&owner)
             .json(&json!({
                 "co_owner": member.id.strip_prefix("UserID-").unwrap(),
@@ -910,7 +991,7 @@ mod kick {
         assert_eq!(res.status(), StatusCode::NO_CONTENT);
 
         let res = app
-            .post("/v1/team/kick")
+            .post(&format!("/v1/competition/{COMPETITION_ID}/team/kick"))
             .user(&member)
             .json(&json!({
                 "user": member.id.strip_prefix("UserID-").unwrap(),
@@ -931,7 +1012,7 @@ mod kick {
         let member = app.register_user().await;
 
         let res = app
-            .post("/v1/team/kick")
+            .post(&format!("/v1/competition/{COMPETITION_ID}/team/kick"))
             .user(&owner)
             .json(&json!({
                 "user": member.id.strip_prefix("UserID-").unwrap(),
@@ -950,7 +1031,7 @@ mod kick {
         let _team = app.create_team(&owner).await;
 
         let res = app
-            .post("/v1/team/kick")
+            .post(&format!("/v1/competition/{COMPETITION_ID}/team/kick"))
             .user(&owner)
             .json(&json!({
                 "user": uuid::Uuid::nil(),
@@ -971,13 +1052,19 @@ mod kick {
         let member = app.register_user().await;
         member.join(&team.get_code().await).await;
 
-        let mut socket1 = app.socket("/v1/ws").start().await;
+        let mut socket1 = app
+            .socket(&format!("/v1/competition/{COMPETITION_ID}/ws"))
+            .start()
+            .await;
         assert_team_info!(socket1, owner);
-        let mut socket2 = app.socket("/v1/ws").start().await;
+        let mut socket2 = app
+            .socket(&format!("/v1/competition/{COMPETITION_ID}/ws"))
+            .start()
+            .await;
         assert_team_info!(socket2, member);
 
         let res = app
-            .post("/v1/team/kick")
+            .post(&format!("/v1/competition/{COMPETITION_ID}/team/kick"))
             .user(&owner)
             .json(&json!({
                 "user": member.id.strip_prefix("UserID-").unwrap(),
@@ -1022,7 +1109,7 @@ mod kick {
         member.join(&team.get_code().await).await;
 
         let res = app
-            .patch("/v1/team")
+            .patch(&format!("/v1/competition/{COMPETITION_ID}/team"))
             .user(&owner)
             .json(&json!({
                 "co_owner": member.id.strip_prefix("UserID-").unwrap(),
@@ -1032,13 +1119,19 @@ mod kick {
 
         assert_eq!(res.status(), StatusCode::NO_CONTENT);
 
-        let mut socket1 = app.socket("/v1/ws").start().await;
+        let mut socket1 = app
+            .socket(&format!("/v1/competition/{COMPETITION_ID}/ws"))
+            .start()
+            .await;
         assert_team_info!(socket1, owner);
-        let mut socket2 = app.socket("/v1/ws").start().await;
+        let mut socket2 = app
+            .socket(&format!("/v1/competition/{COMPETITION_ID}/ws"))
+            .start()
+            .await;
         assert_team_info!(socket2, member);
 
         let res = app
-            .post("/v1/team/kick")
+            .post(&format!("/v1/competition/{COMPETITION_ID}/team/kick"))
             .user(&owner)
             .json(&json!({
                 "user": member.id.strip_prefix("UserID-").unwrap(),
@@ -1086,7 +1179,7 @@ mod kick {
         member.join(&team.get_code().await).await;
 
         let res = app
-            .patch("/v1/team")
+            .patch(&format!("/v1/competition/{COMPETITION_ID}/team"))
             .user(&owner)
             .json(&json!({
                 "co_owner": coowner.id.strip_prefix("UserID-").unwrap(),
@@ -1096,15 +1189,24 @@ mod kick {
 
         assert_eq!(res.status(), StatusCode::NO_CONTENT);
 
-        let mut socket1 = app.socket("/v1/ws").start().await;
+        let mut socket1 = app
+            .socket(&format!("/v1/competition/{COMPETITION_ID}/ws"))
+            .start()
+            .await;
         assert_team_info!(socket1, owner);
-        let mut socket2 = app.socket("/v1/ws").start().await;
+        let mut socket2 = app
+            .socket(&format!("/v1/competition/{COMPETITION_ID}/ws"))
+            .start()
+            .await;
         assert_team_info!(socket2, coowner);
-        let mut socket3 = app.socket("/v1/ws").start().await;
+        let mut socket3 = app
+            .socket(&format!("/v1/competition/{COMPETITION_ID}/ws"))
+            .start()
+            .await;
         assert_team_info!(socket3, member);
 
         let res = app
-            .post("/v1/team/kick")
+            .post(&format!("/v1/competition/{COMPETITION_ID}/team/kick"))
             .user(&coowner)
             .json(&json!({
                 "user": member.id.strip_prefix("UserID-").unwrap(),
@@ -1163,7 +1265,11 @@ mod code {
         let member = app.register_user().await;
         member.join(&team.get_code().await).await;
 
-        let res = app.post("/v1/team/code").user(&member).send().await;
+        let res = app
+            .post(&format!("/v1/competition/{COMPETITION_ID}/team/code"))
+            .user(&member)
+            .send()
+            .await;
 
         assert_error!(res, error::USER_NOT_COOWNER);
     }
@@ -1177,7 +1283,11 @@ mod code {
 
         team.lock().await;
 
-        let res = app.post("/v1/team/code").user(&owner).send().await;
+        let res = app
+            .post(&format!("/v1/competition/{COMPETITION_ID}/team/code"))
+            .user(&owner)
+            .send()
+            .await;
 
         assert_error!(res, error::LOCKED_TEAM);
     }
@@ -1189,10 +1299,17 @@ mod code {
         let owner = app.register_user().await;
         let _team = app.create_team(&owner).await;
 
-        let mut socket = app.socket("/v1/ws").start().await;
+        let mut socket = app
+            .socket(&format!("/v1/competition/{COMPETITION_ID}/ws"))
+            .start()
+            .await;
         assert_team_info!(socket, owner);
 
-        let res = app.post("/v1/team/code").user(&owner).send().await;
+        let res = app
+            .post(&format!("/v1/competition/{COMPETITION_ID}/team/code"))
+            .user(&owner)
+            .send()
+            .await;
 
         assert_eq!(res.status(), StatusCode::NO_CONTENT);
 
@@ -1210,6 +1327,62 @@ mod code {
     async fn clash() {
         // TODO: test join code clash
     }
+
+    #[tokio::test]
+    #[parallel]
+    async fn qr_not_in_team() {
+        let app = get_cached_app().await;
+        let user = app.register_user().await;
+
+        let res = app
+            .get(&format!("/v1/competition/{COMPETITION_ID}/team/code/qr"))
+            .user(&user)
+            .send()
+            .await;
+
+        assert_error!(res, error::USER_NOT_IN_TEAM);
+    }
+
+    #[tokio::test]
+    #[parallel]
+    async fn qr_svg() {
+        let app = get_cached_app().await;
+        let owner = app.register_user().await;
+        let team = app.create_team(&owner).await;
+
+        let member = app.register_user().await;
+        member.join(&team.get_code().await).await;
+
+        let res = app
+            .get(&format!("/v1/competition/{COMPETITION_ID}/team/code/qr"))
+            .user(&member)
+            .send()
+            .await;
+
+        assert_eq!(res.status(), StatusCode::OK);
+        assert_eq!(res.headers()["content-type"], "image/svg+xml");
+        assert!(res.text().await.contains("<svg"));
+    }
+
+    #[tokio::test]
+    #[parallel]
+    async fn qr_png() {
+        let app = get_cached_app().await;
+        let owner = app.register_user().await;
+        let _team = app.create_team(&owner).await;
+
+        let res = app
+            .get(&format!(
+                "/v1/competition/{COMPETITION_ID}/team/code/qr?format=png"
+            ))
+            .user(&owner)
+            .send()
+            .await;
+
+        assert_eq!(res.status(), StatusCode::OK);
+        assert_eq!(res.headers()["content-type"], "image/png");
+        assert!(res.bytes().await.starts_with(b"\x89PNG"));
+    }
 }
 
 mod get {
@@ -1221,7 +1394,11 @@ mod get {
         let app = get_cached_app().await;
         let user = app.register_user().await;
 
-        let res = app.get("/v1/team").user(&user).send().await;
+        let res = app
+            .get(&format!("/v1/competition/{COMPETITION_ID}/team"))
+            .user(&user)
+            .send()
+            .await;
 
         assert_error!(res, error::NOT_ENOUGH_PERMISSIONS);
     }
@@ -1238,7 +1415,11 @@ mod get {
         let admin = iam::register_user().await;
         iam::make_admin(&admin).await;
 
-        let res = app.get("/v1/team").user(&admin).send().await;
+        let res = app
+            .get(&format!("/v1/competition/{COMPETITION_ID}/team"))
+            .user(&admin)
+            .send()
+            .await;
 
         assert!(res.status().is_success());
 
@@ -1273,3 +1454,1360 @@ mod get {
         )
     }
 }
+
+mod time {
+    use super::*;
+
+    #[tokio::test]
+    #[parallel]
+    async fn not_admin() {
+        let app = get_cached_app().await;
+        let user = iam::register_user().await;
+
+        let res = app
+            .put(&format!(
+                "/v1/competition/{COMPETITION_ID}/team/{}/time",
+                uuid()
+            ))
+            .user(&user)
+            .json(&json!({
+                "extra_minutes": 10,
+            }))
+            .send()
+            .await;
+
+        assert_error!(res, error::NOT_ENOUGH_PERMISSIONS);
+    }
+
+    #[tokio::test]
+    #[parallel]
+    async fn not_found() {
+        let app = get_cached_app().await;
+
+        let admin = iam::register_user().await;
+        iam::make_admin(&admin).await;
+
+        let res = app
+            .put(&format!(
+                "/v1/competition/{COMPETITION_ID}/team/{}/time",
+                uuid()
+            ))
+            .user(&admin)
+            .json(&json!({
+                "extra_minutes": 10,
+            }))
+            .send()
+            .await;
+
+        assert_error!(res, error::TEAM_NOT_FOUND);
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn socket_gets_effective_times() {
+        let app = get_cached_app().await;
+
+        let admin = iam::register_user().await;
+        iam::make_admin(&admin).await;
+
+        app.set_time(&admin, 1234, 4321).await;
+
+        let owner = app.register_user().await;
+        let team = app.create_team(&owner).await;
+        let team_id = team.get_id(&admin).await;
+
+        let mut socket = app
+            .socket(&format!("/v1/competition/{COMPETITION_ID}/ws"))
+            .start()
+            .await;
+        assert_team_info!(socket, owner);
+
+        let res = app
+            .put(&format!(
+                "/v1/competition/{COMPETITION_ID}/team/{team_id}/time"
+            ))
+            .user(&admin)
+            .json(&json!({
+                "start_time": 2000,
+                "extra_minutes": 10,
+            }))
+            .send()
+            .await;
+
+        assert_eq!(res.status(), StatusCode::NO_CONTENT);
+
+        let message = get_socket_message(socket.next().await);
+
+        assert_json_eq!(
+            message,
+            json!({
+                "event": "UPDATE_TIME",
+                "data": {
+                    "start_time": "1970-01-01T00:33:20Z",
+                    "end_time": "1970-01-01T01:22:01Z",
+                }
+            })
+        );
+
+        let res = app
+            .delete(&format!(
+                "/v1/competition/{COMPETITION_ID}/team/{team_id}/time"
+            ))
+            .user(&admin)
+            .send()
+            .await;
+
+        assert_eq!(res.status(), StatusCode::NO_CONTENT);
+
+        let message = get_socket_message(socket.next().await);
+
+        assert_json_eq!(
+            message,
+            json!({
+                "event": "UPDATE_TIME",
+                "data": {
+                    "start_time": "1970-01-01T00:20:34Z",
+                    "end_time": "1970-01-01T01:12:01Z",
+                }
+            })
+        );
+
+        socket.close(None).await.unwrap();
+    }
+}
+
+mod exclusion {
+    use super::*;
+
+    #[tokio::test]
+    #[parallel]
+    async fn not_admin() {
+        let app = get_cached_app().await;
+        let user = iam::register_user().await;
+
+        let res = app
+            .put(&format!(
+                "/v1/competition/{COMPETITION_ID}/team/{}/exclusion",
+                uuid()
+            ))
+            .user(&user)
+            .json(&json!({
+                "kind": "DISQUALIFIED",
+                "reason": "cheating",
+            }))
+            .send()
+            .await;
+
+        assert_error!(res, error::NOT_ENOUGH_PERMISSIONS);
+    }
+
+    #[tokio::test]
+    #[parallel]
+    async fn not_found() {
+        let app = get_cached_app().await;
+
+        let admin = iam::register_user().await;
+        iam::make_admin(&admin).await;
+
+        let res = app
+            .put(&format!(
+                "/v1/competition/{COMPETITION_ID}/team/{}/exclusion",
+                uuid()
+            ))
+            .user(&admin)
+            .json(&json!({
+                "kind": "HORS_CONCOURS",
+                "reason": "guest team",
+            }))
+            .send()
+            .await;
+
+        assert_error!(res, error::TEAM_NOT_FOUND);
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn excluded_from_scoreboard() {
+        let app = get_cached_app().await;
+        app.clean_database().await;
+
+        let admin = iam::register_user().await;
+        iam::make_admin(&admin).await;
+
+        let owner1 = app.register_user().await;
+        let team1 = app.create_team(&owner1).await;
+        let team1_id = team1.get_id(&admin).await;
+        let owner2 = app.register_user().await;
+        let team2 = app.create_team(&owner2).await;
+
+        let mut socket = app
+            .socket(&format!("/v1/competition/{COMPETITION_ID}/ws"))
+            .start()
+            .await;
+        assert_team_info!(socket, owner1);
+
+        let res = app
+            .put(&format!(
+                "/v1/competition/{COMPETITION_ID}/team/{team1_id}/exclusion"
+            ))
+            .user(&admin)
+            .json(&json!({
+                "kind": "DISQUALIFIED",
+                "reason": "cheating",
+            }))
+            .send()
+            .await;
+
+        assert_eq!(res.status(), StatusCode::NO_CONTENT);
+
+        let message = get_socket_message(socket.next().await);
+
+        assert_json_eq!(
+            message,
+            json!({
+                "event": "UPDATE_EXCLUSION",
+                "data": {
+                    "team": team1_id,
+                    "exclusion": "DISQUALIFIED",
+                    "reason": "cheating",
+                }
+            })
+        );
+
+        let res = app
+            .post(&format!(
+                "/v1/competition/{COMPETITION_ID}/stats/scoreboard"
+            ))
+            .user(&admin)
+            .json(&json!({}))
+            .send()
+            .await;
+
+        assert_eq!(res.status(), StatusCode::OK);
+
+        let scoreboard: Value = res.json().await;
+        assert_eq!(scoreboard.as_array().unwrap().len(), 1);
+        assert_eq!(scoreboard[0]["name"], json!(team2.get_name()));
+
+        let res = app
+            .delete(&format!(
+                "/v1/competition/{COMPETITION_ID}/team/{team1_id}/exclusion"
+            ))
+            .user(&admin)
+            .send()
+            .await;
+
+        assert_eq!(res.status(), StatusCode::NO_CONTENT);
+
+        let message = get_socket_message(socket.next().await);
+
+        assert_json_eq!(
+            message,
+            json!({
+                "event": "UPDATE_EXCLUSION",
+                "data": {
+                    "team": team1_id,
+                }
+            })
+        );
+
+        let res = app
+            .post(&format!(
+                "/v1/competition/{COMPETITION_ID}/stats/scoreboard"
+            ))
+            .user(&admin)
+            .json(&json!({}))
+            .send()
+            .await;
+
+        assert_eq!(res.json::<Value>().await.as_array().unwrap().len(), 2);
+
+        socket.close(None).await.unwrap();
+    }
+}
+
+mod solution {
+    use super::*;
+
+    #[tokio::test]
+    #[parallel]
+    async fn not_admin() {
+        let app = get_cached_app().await;
+        let user = iam::register_user().await;
+
+        let res = app
+            .put(&format!(
+                "/v1/competition/{COMPETITION_ID}/team/{}/solution",
+                uuid()
+            ))
+            .user(&user)
+            .json(&json!({
+                "problem": uuid(),
+                "solution": 23,
+            }))
+            .send()
+            .await;
+
+        assert_error!(res, error::NOT_ENOUGH_PERMISSIONS);
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn problem_not_found() {
+        let app = get_cached_app().await;
+        app.clean_database().await;
+
+        let admin = iam::register_user().await;
+        iam::make_admin(&admin).await;
+
+        let owner = app.register_user().await;
+        let team = app.create_team(&owner).await;
+        let team_id = team.get_id(&admin).await;
+
+        let res = app
+            .put(&format!(
+                "/v1/competition/{COMPETITION_ID}/team/{team_id}/solution"
+            ))
+            .user(&admin)
+            .json(&json!({
+                "problem": uuid(),
+                "solution": 23,
+            }))
+            .send()
+            .await;
+
+        assert_error!(res, error::PROBLEM_NOT_FOUND);
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn works() {
+        let app = get_cached_app().await;
+        app.clean_database().await;
+
+        let admin = iam::register_user().await;
+        iam::make_admin(&admin).await;
+        app.start_competition(&admin).await;
+
+        let res = app
+            .post(&format!("/v1/competition/{COMPETITION_ID}/problem"))
+            .user(&admin)
+            .json(&json!({
+                "body": "some body",
+                "solution": 23,
+                "points": 3,
+                "max_attempts": 1,
+            }))
+            .send()
+            .await;
+
+        assert_eq!(res.status(), StatusCode::CREATED);
+
+        let problem = res.json::<Value>().await["id"].clone();
+
+        let owner = app.register_user().await;
+        let team = app.create_team(&owner).await;
+        let team_id = team.get_id(&admin).await;
+
+        let res = app
+            .put(&format!(
+                "/v1/competition/{COMPETITION_ID}/team/{team_id}/solution"
+            ))
+            .user(&admin)
+            .json(&json!({
+                "problem": problem,
+                "solution": 23,
+            }))
+            .send()
+            .await;
+
+        assert_eq!(res.status(), StatusCode::NO_CONTENT);
+
+        let res = app
+            .post(&format!(
+                "/v1/competition/{COMPETITION_ID}/stats/scoreboard"
+            ))
+            .user(&admin)
+            .json(&json!({}))
+            .send()
+            .await;
+
+        assert_eq!(res.status(), StatusCode::OK);
+
+        let scoreboard: Value = res.json().await;
+        assert_eq!(scoreboard[0]["points"], 3);
+
+        let res = app
+            .post(&format!("/v1/competition/{COMPETITION_ID}/stats/history"))
+            .user(&admin)
+            .json(&json!({
+                "team": team_id,
+            }))
+            .send()
+            .await;
+
+        assert_eq!(res.status(), StatusCode::OK);
+
+        assert_json_include!(
+            actual: res.json::<Value>().await,
+            expected: json!({
+                "total": 1,
+                "entries": [
+                    {
+                        "user": null,
+                        "admin": admin.id().strip_prefix("UserID-").unwrap(),
+                        "solution": "23",
+                    },
+                ],
+            })
+        );
+
+        // The answer of the admin is not an attempt of the team
+        let res = app
+            .post(&format!("/v1/competition/{COMPETITION_ID}/solution"))
+            .user(&owner)
+            .json(&json!({
+                "problem": problem,
+                "solution": null,
+            }))
+            .send()
+            .await;
+
+        assert_eq!(res.status(), StatusCode::NO_CONTENT);
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn not_an_attempt() {
+        let app = get_cached_app().await;
+        app.clean_database().await;
+
+        let admin = iam::register_user().await;
+        iam::make_admin(&admin).await;
+        app.start_competition(&admin).await;
+
+        let res = app
+            .post(&format!("/v1/competition/{COMPETITION_ID}/problem"))
+            .user(&admin)
+            .json(&json!({
+                "body": "some body",
+                "solution": 23,
+                "max_attempts": 2,
+                "attempt_penalty": -1,
+            }))
+            .send()
+            .await;
+
+        assert_eq!(res.status(), StatusCode::CREATED);
+
+        let problem = res.json::<Value>().await["id"].clone();
+
+        let owner = app.register_user().await;
+        let team = app.create_team(&owner).await;
+        let team_id = team.get_id(&admin).await;
+
+        let set_solution = |solution: Value| {
+            app.put(&format!(
+                "/v1/competition/{COMPETITION_ID}/team/{team_id}/solution"
+            ))
+            .user(&admin)
+            .json(&json!({
+                "problem": problem,
+                "solution": solution,
+            }))
+            .send()
+        };
+
+        let res = set_solution(json!(5)).await;
+        assert_eq!(res.status(), StatusCode::NO_CONTENT);
+
+        let mut socket = app
+            .socket(&format!("/v1/competition/{COMPETITION_ID}/ws"))
+            .start()
+            .await;
+        assert_team_info!(socket, owner);
+
+        let mut next_remaining_attempts = async || loop {
+            let message = get_socket_message(socket.next().await);
+
+            if message["event"] == "REMAINING_ATTEMPTS" {
+                break message["data"]["remaining"].clone();
+            }
+        };
+
+        assert_eq!(next_remaining_attempts().await, 2);
+
+        let res = set_solution(Value::Null).await;
+        assert_eq!(res.status(), StatusCode::NO_CONTENT);
+
+        assert_eq!(next_remaining_attempts().await, 2);
+
+        let res = app
+            .post(&format!(
+                "/v1/competition/{COMPETITION_ID}/stats/scoreboard"
+            ))
+            .user(&admin)
+            .json(&json!({}))
+            .send()
+            .await;
+
+        assert_eq!(res.status(), StatusCode::OK);
+
+        let scoreboard: Value = res.json().await;
+        assert_eq!(scoreboard[0]["points"], 0);
+        assert_eq!(scoreboard[0]["wrong_answers"], 0);
+    }
+}
+
+mod invitation {
+    use super::*;
+
+    async fn invite(app: &App, from: &impl UserLike, user: &impl UserLike) -> TestResponse {
+        app.post(&format!("/v1/competition/{COMPETITION_ID}/team/invite"))
+            .user(from)
+            .json(&json!({
+                "user": user.id().strip_prefix("UserID-").unwrap(),
+            }))
+            .send()
+            .await
+    }
+
+    async fn list(app: &App, user: &impl UserLike) -> Value {
+        let res = app
+            .get(&format!(
+                "/v1/competition/{COMPETITION_ID}/team/invitations"
+            ))
+            .user(user)
+            .send()
+            .await;
+
+        assert_eq!(res.status(), StatusCode::OK);
+
+        res.json().await
+    }
+
+    #[tokio::test]
+    #[parallel]
+    async fn invite_and_accept() {
+        let app = get_cached_app().await;
+
+        let owner = app.register_user().await;
+        let team = app.create_team(&owner).await;
+        let user = app.register_user().await;
+        let user_id = user.id.strip_prefix("UserID-").unwrap();
+
+        let mut socket = app
+            .socket(&format!("/v1/competition/{COMPETITION_ID}/ws"))
+            .start()
+            .await;
+        let info = assert_team_info!(socket, owner);
+        assert_eq!(info["data"]["invitations"], json!([]));
+
+        let res = invite(app, &owner, &user).await;
+        assert_eq!(res.status(), StatusCode::NO_CONTENT);
+
+        let message = get_socket_message(socket.next().await);
+        assert_event_type!(message, "INVITE_USER");
+        assert_eq!(message["data"]["user"], user_id);
+        assert!(message["data"]["expires_at"].is_string());
+
+        let invitations = list(app, &user).await;
+        assert_eq!(invitations.as_array().unwrap().len(), 1);
+        assert_eq!(invitations[0]["team_name"], team.get_name());
+        assert_eq!(
+            invitations[0]["invited_by"],
+            owner.id.strip_prefix("UserID-").unwrap()
+        );
+
+        let invitation_id = invitations[0]["id"].as_str().unwrap();
+
+        let res = app
+            .post(&format!(
+                "/v1/competition/{COMPETITION_ID}/team/invitations/{invitation_id}/accept"
+            ))
+            .user(&user)
+            .send()
+            .await;
+
+        assert_eq!(res.status(), StatusCode::NO_CONTENT);
+
+        let message = get_socket_message(socket.next().await);
+        assert_event_type!(message, "JOIN_TEAM");
+        assert_eq!(message["data"]["user"], user_id);
+
+        let message = get_socket_message(socket.next().await);
+        assert_json_eq!(
+            message,
+            json!({
+                "event": "DELETE_INVITATION",
+                "data": {
+                    "user": user_id,
+                }
+            })
+        );
+
+        assert_eq!(list(app, &user).await, json!([]));
+
+        socket.close(None).await.unwrap();
+    }
+
+    #[tokio::test]
+    #[parallel]
+    async fn pushed_to_invitee() {
+        use tokio_tungstenite::tungstenite::Message;
+
+        let app = get_cached_app().await;
+
+        let owner1 = app.register_user().await;
+        let team1 = app.create_team(&owner1).await;
+        let owner2 = app.register_user().await;
+        let team2 = app.create_team(&owner2).await;
+        let user = app.register_user().await;
+
+        let res = invite(app, &owner1, &user).await;
+        assert_eq!(res.status(), StatusCode::NO_CONTENT);
+
+        let mut socket = app
+            .socket(&format!(
+                "/v1/competition/{COMPETITION_ID}/team/invitations/ws"
+            ))
+            .start()
+            .await;
+
+        socket
+            .send(Message::Text(
+                json!({"token": user.access_token().to_owned()})
+                    .to_string()
+                    .into(),
+            ))
+            .await
+            .unwrap();
+
+        let message = get_socket_message(socket.next().await);
+        assert_event_type!(message, "RECEIVE_INVITATION");
+        assert_eq!(message["data"]["team_name"], team1.get_name());
+        assert_eq!(
+            message["data"]["invited_by"],
+            owner1.id.strip_prefix("UserID-").unwrap()
+        );
+        let invitation1 = message["data"]["id"].clone();
+
+        let res = invite(app, &owner2, &user).await;
+        assert_eq!(res.status(), StatusCode::NO_CONTENT);
+
+        let message = get_socket_message(socket.next().await);
+        assert_event_type!(message, "RECEIVE_INVITATION");
+        assert_eq!(message["data"]["team_name"], team2.get_name());
+        let invitation2 = message["data"]["id"].clone();
+
+        let res = app
+            .post(&format!(
+                "/v1/competition/{COMPETITION_ID}/team/invite/cancel"
+            ))
+            .user(&owner2)
+            .json(&json!({
+                "user": user.id.strip_prefix("UserID-").unwrap(),
+            }))
+            .send()
+            .await;
+
+        assert_eq!(res.status(), StatusCode::NO_CONTENT);
+
+        let message = get_socket_message(socket.next().await);
+        assert_json_eq!(
+            message,
+            json!({
+                "event": "REMOVE_INVITATION",
+                "data": {
+                    "id": invitation2,
+                }
+            })
+        );
+
+        let res = app
+            .post(&format!(
+                "/v1/competition/{COMPETITION_ID}/team/invitations/{}/accept",
+                invitation1.as_str().unwrap()
+            ))
+            .user(&user)
+            .send()
+            .await;
+
+        assert_eq!(res.status(), StatusCode::NO_CONTENT);
+
+        let message = get_socket_message(socket.next().await);
+        assert_json_eq!(
+            message,
+            json!({
+                "event": "REMOVE_INVITATION",
+                "data": {
+                    "id": invitation1,
+                }
+            })
+        );
+
+        socket.close(None).await.unwrap();
+    }
+
+    #[tokio::test]
+    #[parallel]
+    async fn removed_on_disband() {
+        use tokio_tungstenite::tungstenite::Message;
+
+        let app = get_cached_app().await;
+
+        let owner = app.register_user().await;
+        app.create_team(&owner).await;
+        let user = app.register_user().await;
+
+        let res = invite(app, &owner, &user).await;
+        assert_eq!(res.status(), StatusCode::NO_CONTENT);
+
+        let mut socket = app
+            .socket(&format!(
+                "/v1/competition/{COMPETITION_ID}/team/invitations/ws"
+            ))
+            .start()
+            .await;
+
+        socket
+            .send(Message::Text(
+                json!({"token": user.access_token().to_owned()})
+                    .to_string()
+                    .into(),
+            ))
+            .await
+            .unwrap();
+
+        let message = get_socket_message(socket.next().await);
+        assert_event_type!(message, "RECEIVE_INVITATION");
+        let invitation = message["data"]["id"].clone();
+
+        let res = app
+            .post(&format!("/v1/competition/{COMPETITION_ID}/team/disband"))
+            .user(&owner)
+            .send()
+            .await;
+
+        assert_eq!(res.status(), StatusCode::NO_CONTENT);
+
+        let message = get_socket_message(socket.next().await);
+        assert_json_eq!(
+            message,
+            json!({
+                "event": "REMOVE_INVITATION",
+                "data": {
+                    "id": invitation,
+                }
+            })
+        );
+
+        assert_eq!(list(app, &user).await, json!([]));
+
+        socket.close(None).await.unwrap();
+    }
+
+    #[tokio::test]
+    #[parallel]
+    async fn decline() {
+        let app = get_cached_app().await;
+
+        let owner = app.register_user().await;
+        app.create_team(&owner).await;
+        let user = app.register_user().await;
+
+        let res = invite(app, &owner, &user).await;
+        assert_eq!(res.status(), StatusCode::NO_CONTENT);
+
+        let invitations = list(app, &user).await;
+        let invitation_id = invitations[0]["id"].as_str().unwrap();
+
+        let res = app
+            .post(&format!(
+                "/v1/competition/{COMPETITION_ID}/team/invitations/{invitation_id}/decline"
+            ))
+            .user(&user)
+            .send()
+            .await;
+
+        assert_eq!(res.status(), StatusCode::NO_CONTENT);
+        assert_eq!(list(app, &user).await, json!([]));
+
+        let res = app
+            .post(&format!(
+                "/v1/competition/{COMPETITION_ID}/team/invitations/{invitation_id}/accept"
+            ))
+            .user(&user)
+            .send()
+            .await;
+
+        assert_error!(res, error::INVITATION_NOT_FOUND);
+    }
+
+    #[tokio::test]
+    #[parallel]
+    async fn cancel() {
+        let app = get_cached_app().await;
+
+        let owner = app.register_user().await;
+        app.create_team(&owner).await;
+        let user = app.register_user().await;
+
+        let res = invite(app, &owner, &user).await;
+        assert_eq!(res.status(), StatusCode::NO_CONTENT);
+
+        let cancel = || {
+            app.post(&format!(
+                "/v1/competition/{COMPETITION_ID}/team/invite/cancel"
+            ))
+            .user(&owner)
+            .json(&json!({
+                "user": user.id.strip_prefix("UserID-").unwrap(),
+            }))
+            .send()
+        };
+
+        let res = cancel().await;
+        assert_eq!(res.status(), StatusCode::NO_CONTENT);
+        assert_eq!(list(app, &user).await, json!([]));
+
+        let res = cancel().await;
+        assert_error!(res, error::INVITATION_NOT_FOUND);
+    }
+
+    #[tokio::test]
+    #[parallel]
+    async fn not_coowner() {
+        let app = get_cached_app().await;
+
+        let owner = app.register_user().await;
+        let team = app.create_team(&owner).await;
+        let member = app.register_user().await;
+        let user = app.register_user().await;
+
+        let res = app
+            .post(&format!("/v1/competition/{COMPETITION_ID}/team/join"))
+            .user(&member)
+            .json(&json!({
+                "code": team.get_code().await,
+            }))
+            .send()
+            .await;
+
+        assert_eq!(res.status(), StatusCode::OK);
+
+        let res = invite(app, &member, &user).await;
+        assert_error!(res, error::USER_NOT_COOWNER);
+    }
+
+    #[tokio::test]
+    #[parallel]
+    async fn already_in_team() {
+        let app = get_cached_app().await;
+
+        let owner1 = app.register_user().await;
+        app.create_team(&owner1).await;
+        let owner2 = app.register_user().await;
+        app.create_team(&owner2).await;
+
+        let res = invite(app, &owner1, &owner2).await;
+        assert_error!(res, error::ALREADY_IN_TEAM);
+    }
+
+    #[tokio::test]
+    #[parallel]
+    async fn locked_team() {
+        let app = get_cached_app().await;
+
+        let owner = app.register_user().await;
+        let team = app.create_team(&owner).await;
+        let user = app.register_user().await;
+
+        let res = invite(app, &owner, &user).await;
+        assert_eq!(res.status(), StatusCode::NO_CONTENT);
+
+        team.lock().await;
+
+        let invitations = list(app, &user).await;
+        let invitation_id = invitations[0]["id"].as_str().unwrap();
+
+        let res = app
+            .post(&format!(
+                "/v1/competition/{COMPETITION_ID}/team/invitations/{invitation_id}/accept"
+            ))
+            .user(&user)
+            .send()
+            .await;
+
+        assert_error!(res, error::LOCKED_TEAM);
+
+        let other = app.register_user().await;
+        let res = invite(app, &owner, &other).await;
+        assert_error!(res, error::LOCKED_TEAM);
+    }
+}
+
+mod join_request {
+    use super::*;
+
+    async fn require_approval(app: &App, owner: &impl UserLike) {
+        let res = app
+            .patch(&format!("/v1/competition/{COMPETITION_ID}/team"))
+            .user(owner)
+            .json(&json!({
+                "require_approval": true,
+            }))
+            .send()
+            .await;
+
+        assert_eq!(res.status(), StatusCode::NO_CONTENT);
+    }
+
+    async fn decide(
+        app: &App,
+        owner: &impl UserLike,
+        decision: &str,
+        user: &impl UserLike,
+    ) -> TestResponse {
+        app.post(&format!(
+            "/v1/competition/{COMPETITION_ID}/team/requests/{decision}"
+        ))
+        .user(owner)
+        .json(&json!({
+            "user": user.id().strip_prefix("UserID-").unwrap(),
+        }))
+        .send()
+        .await
+    }
+
+    #[tokio::test]
+    #[parallel]
+    async fn approve() {
+        let app = get_cached_app().await;
+
+        let owner = app.register_user().await;
+        let team = app.create_team(&owner).await;
+        let code = team.get_code().await;
+        require_approval(app, &owner).await;
+
+        let user = app.register_user().await;
+        let user_id = user.id.strip_prefix("UserID-").unwrap();
+
+        let mut socket = app
+            .socket(&format!("/v1/competition/{COMPETITION_ID}/ws"))
+            .start()
+            .await;
+        let info = assert_team_info!(socket, owner);
+        assert_eq!(info["data"]["require_approval"], true);
+        assert_eq!(info["data"]["join_requests"], json!([]));
+
+        let res = join(app, &user, &code).await;
+        assert_eq!(res.status(), StatusCode::ACCEPTED);
+
+        let message = get_socket_message(socket.next().await);
+        assert_event_type!(message, "REQUEST_JOIN");
+        assert_eq!(message["data"]["user"], user_id);
+
+        // The user is not in the team yet
+        let res = app
+            .post(&format!("/v1/competition/{COMPETITION_ID}/team/leave"))
+            .user(&user)
+            .send()
+            .await;
+
+        assert_error!(res, error::USER_NOT_IN_TEAM);
+
+        let res = decide(app, &owner, "approve", &user).await;
+        assert_eq!(res.status(), StatusCode::NO_CONTENT);
+
+        let message = get_socket_message(socket.next().await);
+        assert_event_type!(message, "JOIN_TEAM");
+        assert_eq!(message["data"]["user"], user_id);
+
+        let message = get_socket_message(socket.next().await);
+        assert_json_eq!(
+            message,
+            json!({
+                "event": "DELETE_JOIN_REQUEST",
+                "data": {
+                    "user": user_id,
+                }
+            })
+        );
+
+        socket.close(None).await.unwrap();
+    }
+
+    #[tokio::test]
+    #[parallel]
+    async fn reject() {
+        let app = get_cached_app().await;
+
+        let owner = app.register_user().await;
+        let team = app.create_team(&owner).await;
+        let code = team.get_code().await;
+        require_approval(app, &owner).await;
+
+        let user = app.register_user().await;
+
+        let res = join(app, &user, &code).await;
+        assert_eq!(res.status(), StatusCode::ACCEPTED);
+
+        let res = decide(app, &owner, "reject", &user).await;
+        assert_eq!(res.status(), StatusCode::NO_CONTENT);
+
+        let res = decide(app, &owner, "reject", &user).await;
+        assert_error!(res, error::JOIN_REQUEST_NOT_FOUND);
+
+        let res = decide(app, &owner, "approve", &user).await;
+        assert_error!(res, error::JOIN_REQUEST_NOT_FOUND);
+    }
+
+    #[tokio::test]
+    #[parallel]
+    async fn not_coowner() {
+        let app = get_cached_app().await;
+
+        let owner = app.register_user().await;
+        let team = app.create_team(&owner).await;
+        let code = team.get_code().await;
+
+        let member = app.register_user().await;
+        let res = join(app, &member, &code).await;
+        assert_eq!(res.status(), StatusCode::OK);
+
+        require_approval(app, &owner).await;
+
+        let user = app.register_user().await;
+        let res = join(app, &user, &code).await;
+        assert_eq!(res.status(), StatusCode::ACCEPTED);
+
+        let res = decide(app, &member, "approve", &user).await;
+        assert_error!(res, error::USER_NOT_COOWNER);
+    }
+
+    #[tokio::test]
+    #[parallel]
+    async fn join_code_used_on_approval() {
+        let app = get_cached_app().await;
+
+        let owner = app.register_user().await;
+        let _team = app.create_team(&owner).await;
+        require_approval(app, &owner).await;
+
+        let res = app
+            .post(&format!("/v1/competition/{COMPETITION_ID}/team/codes"))
+            .user(&owner)
+            .json(&json!({ "max_uses": 1 }))
+            .send()
+            .await;
+        assert_eq!(res.status(), StatusCode::CREATED);
+
+        let body = res.json::<Value>().await;
+        let code = body["code"].as_str().unwrap();
+
+        // Neither the repeated nor the rejected request uses the code
+        let user = app.register_user().await;
+        let res = join(app, &user, code).await;
+        assert_eq!(res.status(), StatusCode::ACCEPTED);
+        let res = join(app, &user, code).await;
+        assert_eq!(res.status(), StatusCode::ACCEPTED);
+
+        let rejected = app.register_user().await;
+        let res = join(app, &rejected, code).await;
+        assert_eq!(res.status(), StatusCode::ACCEPTED);
+
+        let res = decide(app, &owner, "reject", &rejected).await;
+        assert_eq!(res.status(), StatusCode::NO_CONTENT);
+
+        let res = decide(app, &owner, "approve", &user).await;
+        assert_eq!(res.status(), StatusCode::NO_CONTENT);
+
+        let res = app
+            .get(&format!("/v1/competition/{COMPETITION_ID}/team/codes"))
+            .user(&owner)
+            .send()
+            .await;
+        assert_eq!(res.status(), StatusCode::OK);
+
+        let codes = res.json::<Value>().await;
+        assert_eq!(codes[0]["uses"], 1);
+
+        let other = app.register_user().await;
+        let res = join(app, &other, code).await;
+        assert_error!(res, error::JOIN_CODE_EXPIRED);
+    }
+}
+
+mod join_codes {
+    use super::*;
+
+    async fn create_code(app: &App, owner: &impl UserLike, body: Value) -> TestResponse {
+        app.post(&format!("/v1/competition/{COMPETITION_ID}/team/codes"))
+            .user(owner)
+            .json(&body)
+            .send()
+            .await
+    }
+
+    #[tokio::test]
+    #[parallel]
+    async fn not_coowner() {
+        let app = get_cached_app().await;
+        let owner = app.register_user().await;
+        let team = app.create_team(&owner).await;
+
+        let member = app.register_user().await;
+        member.join(&team.get_code().await).await;
+
+        let res = create_code(app, &member, json!({ "max_uses": 1 })).await;
+
+        assert_error!(res, error::USER_NOT_COOWNER);
+    }
+
+    #[tokio::test]
+    #[parallel]
+    async fn no_limit() {
+        let app = get_cached_app().await;
+        let owner = app.register_user().await;
+        let _team = app.create_team(&owner).await;
+
+        let res = create_code(app, &owner, json!({})).await;
+
+        assert_eq!(res.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    #[parallel]
+    async fn single_use() {
+        let app = get_cached_app().await;
+        let owner = app.register_user().await;
+        let _team = app.create_team(&owner).await;
+
+        let res = create_code(app, &owner, json!({ "max_uses": 1 })).await;
+        assert_eq!(res.status(), StatusCode::CREATED);
+
+        let body = res.json::<Value>().await;
+        let code = body["code"].as_str().unwrap();
+        assert_eq!(body["uses"], 0);
+
+        let user = app.register_user().await;
+        let res = join(app, &user, code).await;
+        assert_eq!(res.status(), StatusCode::OK);
+
+        let other = app.register_user().await;
+        let res = join(app, &other, code).await;
+        assert_error!(res, error::JOIN_CODE_EXPIRED);
+
+        let res = app
+            .get(&format!("/v1/competition/{COMPETITION_ID}/team/codes"))
+            .user(&owner)
+            .send()
+            .await;
+        assert_eq!(res.status(), StatusCode::OK);
+
+        let codes = res.json::<Value>().await;
+        assert_eq!(codes[0]["code"], code);
+        assert_eq!(codes[0]["uses"], 1);
+    }
+
+    #[tokio::test]
+    #[parallel]
+    async fn expired() {
+        let app = get_cached_app().await;
+        let owner = app.register_user().await;
+        let _team = app.create_team(&owner).await;
+
+        let res = create_code(app, &owner, json!({ "expires_at": "2000-01-01T00:00:00Z" })).await;
+
+        assert_eq!(res.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    #[parallel]
+    async fn revoke() {
+        let app = get_cached_app().await;
+        let owner = app.register_user().await;
+        let _team = app.create_team(&owner).await;
+
+        let res = create_code(app, &owner, json!({ "expires_at": "2100-01-01T00:00:00Z" })).await;
+        assert_eq!(res.status(), StatusCode::CREATED);
+
+        let body = res.json::<Value>().await;
+        let code = body["code"].as_str().unwrap();
+
+        let res = app
+            .delete(&format!(
+                "/v1/competition/{COMPETITION_ID}/team/codes/{code}"
+            ))
+            .user(&owner)
+            .send()
+            .await;
+        assert_eq!(res.status(), StatusCode::NO_CONTENT);
+
+        let user = app.register_user().await;
+        let res = join(app, &user, code).await;
+        assert_error!(res, error::JOIN_CODE_NOT_FOUND);
+    }
+}
+
+mod size {
+    use super::*;
+
+    async fn set_team_size(app: &App, admin: &impl UserLike, min: i32, max: i32) {
+        let res = app
+            .patch(&format!("/v1/competition/{COMPETITION_ID}"))
+            .user(admin)
+            .json(&json!({
+                "min_team_size": min,
+                "max_team_size": max,
+            }))
+            .send()
+            .await;
+
+        assert_eq!(res.status(), StatusCode::NO_CONTENT);
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn team_full() {
+        let app = get_cached_app().await;
+        app.clean_database().await;
+
+        let admin = iam::register_user().await;
+        iam::make_admin(&admin).await;
+        set_team_size(app, &admin, 1, 2).await;
+
+        let owner = app.register_user().await;
+        let team = app.create_team(&owner).await;
+        let code = team.get_code().await;
+
+        let member = app.register_user().await;
+        member.join(&code).await;
+
+        let user = app.register_user().await;
+        let res = app
+            .post(&format!("/v1/competition/{COMPETITION_ID}/team/join"))
+            .user(&user)
+            .json(&json!({
+                "code": code,
+            }))
+            .send()
+            .await;
+
+        assert_error!(res, error::TEAM_FULL);
+
+        set_team_size(app, &admin, 1, 8).await;
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn lock_too_small() {
+        let app = get_cached_app().await;
+        app.clean_database().await;
+
+        let admin = iam::register_user().await;
+        iam::make_admin(&admin).await;
+        set_team_size(app, &admin, 2, 8).await;
+
+        let owner = app.register_user().await;
+        let team = app.create_team(&owner).await;
+
+        let lock = || async {
+            app.patch(&format!("/v1/competition/{COMPETITION_ID}/team"))
+                .user(&owner)
+                .json(&json!({
+                    "locked": true,
+                }))
+                .send()
+                .await
+        };
+
+        assert_error!(lock().await, error::TEAM_TOO_SMALL);
+
+        let member = app.register_user().await;
+        member.join(&team.get_code().await).await;
+
+        assert_eq!(lock().await.status(), StatusCode::NO_CONTENT);
+
+        set_team_size(app, &admin, 1, 8).await;
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn invalid_limits() {
+        let app = get_cached_app().await;
+        app.clean_database().await;
+
+        let admin = iam::register_user().await;
+        iam::make_admin(&admin).await;
+
+        let res = app
+            .patch(&format!("/v1/competition/{COMPETITION_ID}"))
+            .user(&admin)
+            .json(&json!({
+                "min_team_size": 9,
+            }))
+            .send()
+            .await;
+
+        assert_error!(res, error::INVALID_TEAM_SIZE);
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn report() {
+        let app = get_cached_app().await;
+        app.clean_database().await;
+
+        let admin = iam::register_user().await;
+        iam::make_admin(&admin).await;
+
+        let owner1 = app.register_user().await;
+        let team1 = app.create_team(&owner1).await;
+
+        let owner2 = app.register_user().await;
+        let team2 = app.create_team(&owner2).await;
+        let code = team2.get_code().await;
+
+        for _ in 0..3 {
+            let member = app.register_user().await;
+            member.join(&code).await;
+        }
+
+        let owner3 = app.register_user().await;
+        let team3 = app.create_team(&owner3).await;
+        let member = app.register_user().await;
+        member.join(&team3.get_code().await).await;
+
+        set_team_size(app, &admin, 2, 3).await;
+
+        let res = app
+            .get(&format!("/v1/competition/{COMPETITION_ID}/team/size"))
+            .user(&admin)
+            .send()
+            .await;
+
+        assert_eq!(res.status(), StatusCode::OK);
+
+        let body: Value = res.json().await;
+        assert_eq!(body["min_team_size"], 2);
+        assert_eq!(body["max_team_size"], 3);
+
+        let teams = body["teams"].as_array().unwrap();
+        assert_eq!(teams.len(), 2);
+
+        let violation = |name: String| {
+            teams
+                .iter()
+                .find(|team| team["name"] == name)
+                .map(|team| (team["members"].as_i64().unwrap(), team["violation"].clone()))
+        };
+
+        assert_eq!(violation(team1.get_name()), Some((1, json!("TOO_SMALL"))));
+        assert_eq!(violation(team2.get_name()), Some((4, json!("TOO_LARGE"))));
+        assert_eq!(violation(team3.get_name()), None);
+
+        set_team_size(app, &admin, 1, 8).await;
+    }
+}
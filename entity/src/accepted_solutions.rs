@@ -0,0 +1,37 @@
+use super::problems;
+use sea_orm::entity::prelude::*;
+use uuid::Uuid;
+
+pub mod constraints {
+    pub const PK_ACCEPTED_SOLUTIONS: &str = "PK_accepted_solutions";
+    pub const FK_ACCEPTED_SOLUTIONS_PROBLEM: &str = "FK_accepted_solutions_problem";
+}
+
+/// Answers accepted for a problem besides its main solution
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+#[sea_orm(table_name = "accepted_solutions")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub problem: Uuid,
+    /// Normalized like [`problems::Model::solution`]
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub solution: String,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter)]
+pub enum Relation {
+    Problem,
+}
+
+impl RelationTrait for Relation {
+    fn def(&self) -> RelationDef {
+        match self {
+            Self::Problem => Entity::belongs_to(problems::Entity)
+                .from(Column::Problem)
+                .to(problems::Column::Id)
+                .into(),
+        }
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
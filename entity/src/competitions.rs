@@ -0,0 +1,93 @@
+use crate::users::Class;
+use sea_orm::{FromJsonQueryResult, entity::prelude::*};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+pub mod constraints {
+    pub const PK_COMPETITIONS: &str = "PK_competitions";
+    pub const UC_COMPETITIONS_NAME: &str = "UC_competitions_name";
+}
+
+pub mod constants {
+    pub const DEFAULT_MIN_TEAM_SIZE: i32 = 1;
+    pub const DEFAULT_MAX_TEAM_SIZE: i32 = 8;
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+#[sea_orm(table_name = "competitions")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub id: Uuid,
+    #[sea_orm(unique)]
+    pub name: String,
+    #[sea_orm(column_type = "JsonBinary")]
+    pub tie_breakers: TieBreakers,
+    #[sea_orm(column_type = "JsonBinary")]
+    pub divisions: Divisions,
+    pub division_rule: DivisionRule,
+    /// Teams can't be locked with fewer members
+    pub min_team_size: i32,
+    /// Nobody can join or be invited to a team with this many members
+    pub max_team_size: i32,
+}
+
+/// Decides the order of the teams with the same amount of points, in order of precedence.
+/// Teams that are equal by every tie-breaker share the same rank.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, FromJsonQueryResult)]
+pub struct TieBreakers(pub Vec<TieBreaker>);
+
+impl Default for TieBreakers {
+    /// The official ranking
+    fn default() -> Self {
+        Self(vec![TieBreaker::LastCorrectTime, TieBreaker::WrongAnswers])
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum TieBreaker {
+    /// Earlier time of the last answer change that made a problem correct
+    LastCorrectTime,
+    /// Fewer wrong answers submitted
+    WrongAnswers,
+}
+
+/// Separate leaderboards by the classes of the students, empty if everyone is ranked together
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, FromJsonQueryResult)]
+pub struct Divisions(pub Vec<Division>);
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Division {
+    pub name: String,
+    pub min_class: Class,
+    pub max_class: Class,
+}
+
+impl Division {
+    pub fn contains(&self, class: &Class) -> bool {
+        &self.min_class <= class && class <= &self.max_class
+    }
+}
+
+/// Decides which class counts for the division of a team with members from several classes
+#[derive(
+    EnumIter, DeriveActiveEnum, Copy, Clone, Debug, PartialEq, Eq, Default, Serialize, Deserialize,
+)]
+#[sea_orm(rs_type = "String", db_type = "String(StringLen::N(16))")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum DivisionRule {
+    /// The highest class in the team, so nobody competes with younger students
+    #[default]
+    #[sea_orm(string_value = "highest_class")]
+    HighestClass,
+    #[sea_orm(string_value = "lowest_class")]
+    LowestClass,
+    /// The class of the most members, the higher one on a tie
+    #[sea_orm(string_value = "majority")]
+    Majority,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -1,7 +1,14 @@
+pub mod accepted_solutions;
+pub mod competitions;
+pub mod pauses;
 pub mod problems;
 pub mod problems_order;
 pub mod solutions_history;
+pub mod team_invitations;
+pub mod team_join_codes;
+pub mod team_join_requests;
 pub mod team_members;
+pub mod team_times;
 pub mod teams;
 pub mod times;
 pub mod users;
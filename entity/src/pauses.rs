@@ -0,0 +1,33 @@
+use sea_orm::entity::prelude::*;
+use uuid::Uuid;
+
+pub mod constraints {
+    pub const PK_PAUSES: &str = "PK_pauses";
+    pub const FK_PAUSES_COMPETITION: &str = "FK_pauses_competition";
+}
+
+/// An interval while the competition was paused, `end_time` is null while it is still paused
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+#[sea_orm(table_name = "pauses")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub id: Uuid,
+    pub competition: Uuid,
+    pub start_time: DateTimeUtc,
+    pub end_time: Option<DateTimeUtc>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+impl Entity {
+    /// The pause that has not ended yet
+    #[inline]
+    pub fn find_ongoing(competition_id: Uuid) -> Select<Entity> {
+        Self::find()
+            .filter(Column::Competition.eq(competition_id))
+            .filter(Column::EndTime.is_null())
+    }
+}
@@ -1,18 +1,65 @@
 use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
 pub mod constraints {
     pub const PK_PROBLEMS: &str = "PK_problems";
+    pub const FK_PROBLEMS_COMPETITION: &str = "FK_problems_competition";
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+pub mod constants {
+    pub const DEFAULT_POINTS: i32 = 1;
+    pub const DEFAULT_WRONG_POINTS: i32 = 0;
+    pub const DEFAULT_ATTEMPT_PENALTY: i32 = 0;
+}
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
 #[sea_orm(table_name = "problems")]
 pub struct Model {
     #[sea_orm(primary_key, auto_increment = false)]
     pub id: Uuid,
+    pub competition: Uuid,
     pub body: String,
-    pub solution: i64,
+    pub kind: AnswerKind,
+    /// The normalized answer, see [`AnswerKind`]
+    pub solution: String,
+    /// Largest accepted difference for [`AnswerKind::Decimal`] answers
+    pub tolerance: Option<f64>,
     pub image: Option<String>,
+    pub points: i32,
+    /// Points given for a wrong answer, zero or negative
+    pub wrong_points: i32,
+    /// How many times a team can change its answer, unlimited if not set
+    pub max_attempts: Option<i32>,
+    /// Points given for every wrong answer a team submitted, zero or negative
+    pub attempt_penalty: i32,
+    /// Minutes after the start time of the team when the problem is released
+    pub release_minutes: i32,
+}
+
+/// How answers of a problem are normalized and compared
+#[derive(
+    EnumIter, DeriveActiveEnum, Copy, Clone, Debug, PartialEq, Eq, Default, Serialize, Deserialize,
+)]
+#[sea_orm(rs_type = "String", db_type = "String(StringLen::N(16))")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum AnswerKind {
+    /// An integer, e.g. `-12`
+    #[default]
+    #[sea_orm(string_value = "integer")]
+    Integer,
+    /// A reduced fraction with a positive denominator, e.g. `-3/4` or `2`
+    #[sea_orm(string_value = "fraction")]
+    Fraction,
+    /// A decimal number compared with the tolerance of the problem, e.g. `3.14`
+    #[sea_orm(string_value = "decimal")]
+    Decimal,
+    /// A single upper case letter, e.g. `B`
+    #[sea_orm(string_value = "choice")]
+    Choice,
+    /// A sorted list of distinct integers, e.g. `1,4,9`
+    #[sea_orm(string_value = "set")]
+    Set,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
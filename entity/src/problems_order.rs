@@ -6,6 +6,7 @@ pub mod constraints {
     pub const UC_PROBLEMS_ORDER_NEXT: &str = "UC_problems_order_next";
     pub const FK_PROBLEMS_ORDER_ID: &str = "FK_problems_order_id";
     pub const FK_PROBLEMS_ORDER_NEXT: &str = "FK_problems_order_next";
+    pub const FK_PROBLEMS_ORDER_COMPETITION: &str = "FK_problems_order_competition";
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
@@ -13,7 +14,7 @@ pub mod constraints {
 pub struct Model {
     #[sea_orm(primary_key, auto_increment = false)]
     pub id: Uuid,
-    #[sea_orm(unique)]
+    pub competition: Uuid,
     pub next: Option<Uuid>,
 }
 
@@ -15,8 +15,12 @@ pub struct Model {
     pub id: Uuid,
     pub team: Uuid,
     pub problem: Uuid,
-    pub user: Uuid,
-    pub solution: Option<i64>,
+    /// The member who submitted the answer, `None` if an admin set it
+    pub user: Option<Uuid>,
+    /// The admin who set the answer for the team
+    pub admin: Option<Uuid>,
+    /// The normalized answer, `None` if the team cleared its answer
+    pub solution: Option<String>,
     pub created_at: DateTime,
 }
 
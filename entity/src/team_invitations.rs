@@ -0,0 +1,68 @@
+use super::{teams, users};
+use sea_orm::entity::prelude::*;
+use uuid::Uuid;
+
+pub mod constraints {
+    pub const PK_TEAM_INVITATIONS: &str = "PK_team_invitations";
+    pub const UC_TEAM_INVITATIONS_TEAM_USER: &str = "UC_team_invitations_team_user";
+    pub const FK_TEAM_INVITATIONS_TEAM: &str = "FK_team_invitations_team";
+    pub const FK_TEAM_INVITATIONS_USER: &str = "FK_team_invitations_user";
+}
+
+/// A pending invitation of a user into a team, deleted when it is accepted or declined
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+#[sea_orm(table_name = "team_invitations")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub id: Uuid,
+    pub team: Uuid,
+    pub user: Uuid,
+    /// The owner or the co-owner who sent the invitation
+    pub invited_by: Uuid,
+    pub expires_at: DateTimeUtc,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter)]
+pub enum Relation {
+    Team,
+    User,
+}
+
+impl RelationTrait for Relation {
+    fn def(&self) -> RelationDef {
+        match self {
+            Self::Team => Entity::belongs_to(teams::Entity)
+                .from(Column::Team)
+                .to(teams::Column::Id)
+                .into(),
+            Self::User => Entity::belongs_to(users::Entity)
+                .from(Column::User)
+                .to(users::Column::Id)
+                .into(),
+        }
+    }
+}
+
+impl Related<teams::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Team.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+impl Entity {
+    /// The invitations of a user in a competition that did not expire yet
+    #[inline]
+    pub fn find_pending_for_user(
+        competition_id: &Uuid,
+        user_id: &Uuid,
+        now: DateTimeUtc,
+    ) -> Select<Entity> {
+        Self::find()
+            .inner_join(teams::Entity)
+            .filter(Column::User.eq(*user_id))
+            .filter(teams::Column::Competition.eq(*competition_id))
+            .filter(Column::ExpiresAt.gt(now))
+    }
+}
@@ -0,0 +1,55 @@
+use super::teams;
+use sea_orm::entity::prelude::*;
+use uuid::Uuid;
+
+pub mod constraints {
+    pub const PK_TEAM_JOIN_CODES: &str = "PK_team_join_codes";
+    pub const FK_TEAM_JOIN_CODES_TEAM: &str = "FK_team_join_codes_team";
+}
+
+/// A join code besides the permanent `join_code` of the team, that expires or can only be used a
+/// few times
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+#[sea_orm(table_name = "team_join_codes")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub code: String,
+    pub team: Uuid,
+    pub created_at: DateTimeUtc,
+    /// The code can't be used after this, it never expires if `None`
+    pub expires_at: Option<DateTimeUtc>,
+    /// How many times the code can be used, unlimited if `None`
+    pub max_uses: Option<i32>,
+    pub uses: i32,
+}
+
+impl Model {
+    pub fn is_usable(&self, now: DateTimeUtc) -> bool {
+        self.expires_at.is_none_or(|expires_at| now < expires_at)
+            && self.max_uses.is_none_or(|max_uses| self.uses < max_uses)
+    }
+}
+
+#[derive(Copy, Clone, Debug, EnumIter)]
+pub enum Relation {
+    Team,
+}
+
+impl RelationTrait for Relation {
+    fn def(&self) -> RelationDef {
+        match self {
+            Self::Team => Entity::belongs_to(teams::Entity)
+                .from(Column::Team)
+                .to(teams::Column::Id)
+                .into(),
+        }
+    }
+}
+
+impl Related<teams::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Team.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,52 @@
+use super::{teams, users};
+use sea_orm::entity::prelude::*;
+use uuid::Uuid;
+
+pub mod constraints {
+    pub const PK_TEAM_JOIN_REQUESTS: &str = "PK_team_join_requests";
+    pub const FK_TEAM_JOIN_REQUESTS_TEAM: &str = "FK_team_join_requests_team";
+    pub const FK_TEAM_JOIN_REQUESTS_USER: &str = "FK_team_join_requests_user";
+    pub const FK_TEAM_JOIN_REQUESTS_JOIN_CODE: &str = "FK_team_join_requests_join_code";
+}
+
+/// A user who joined with the code of a team that needs approval, deleted when it is decided
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+#[sea_orm(table_name = "team_join_requests")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub team: Uuid,
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub user: Uuid,
+    pub created_at: DateTimeUtc,
+    /// The extra join code the request was made with, its use is counted when it is approved
+    pub join_code: Option<String>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter)]
+pub enum Relation {
+    Team,
+    User,
+}
+
+impl RelationTrait for Relation {
+    fn def(&self) -> RelationDef {
+        match self {
+            Self::Team => Entity::belongs_to(teams::Entity)
+                .from(Column::Team)
+                .to(teams::Column::Id)
+                .into(),
+            Self::User => Entity::belongs_to(users::Entity)
+                .from(Column::User)
+                .to(users::Column::Id)
+                .into(),
+        }
+    }
+}
+
+impl Related<teams::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Team.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -7,6 +7,7 @@ pub mod constraints {
     pub const UC_TEAM_MEMBERS_USER_ID: &str = "UC_team_members_user_id";
     pub const FK_TEAM_MEMBERS_USER_ID: &str = "FK_team_members_user_id";
     pub const FK_TEAM_MEMBERS_TEAM_ID: &str = "FK_team_members_team_id";
+    pub const FK_TEAM_MEMBERS_COMPETITION: &str = "FK_team_members_competition";
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
@@ -16,6 +17,8 @@ pub struct Model {
     pub user_id: Uuid,
     #[sea_orm(primary_key, auto_increment = false)]
     pub team_id: Uuid,
+    /// Denormalized from the team, so a user can only be in one team per competition
+    pub competition: Uuid,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter)]
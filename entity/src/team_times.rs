@@ -0,0 +1,40 @@
+use super::teams;
+use sea_orm::entity::prelude::*;
+use uuid::Uuid;
+
+pub mod constraints {
+    pub const PK_TEAM_TIMES: &str = "PK_team_times";
+    pub const FK_TEAM_TIMES_TEAM: &str = "FK_team_times_team";
+}
+
+/// Per-team override of the competition times
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+#[sea_orm(table_name = "team_times")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub team: Uuid,
+    /// Replaces the `start_time` of the competition if set
+    pub start_time: Option<DateTimeUtc>,
+    /// Replaces the `end_time` of the competition if set
+    pub end_time: Option<DateTimeUtc>,
+    /// Added to the (overridden) end time
+    pub extra_minutes: i32,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter)]
+pub enum Relation {
+    Team,
+}
+
+impl RelationTrait for Relation {
+    fn def(&self) -> RelationDef {
+        match self {
+            Self::Team => Entity::belongs_to(teams::Entity)
+                .from(Column::Team)
+                .to(teams::Column::Id)
+                .into(),
+        }
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -2,6 +2,7 @@ use crate::team_members;
 
 use super::users;
 use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
 pub mod constrains {
@@ -10,6 +11,7 @@ pub mod constrains {
     pub const UC_TEAMS_JOIN_CODE: &str = "UC_teams_join_code";
     pub const FK_TEAMS_OWNER: &str = "FK_teams_owner";
     pub const FK_TEAMS_CO_OWNER: &str = "FK_teams_co_owner";
+    pub const FK_TEAMS_COMPETITION: &str = "FK_teams_competition";
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
@@ -17,13 +19,30 @@ pub mod constrains {
 pub struct Model {
     #[sea_orm(primary_key, auto_increment = false)]
     pub id: Uuid,
-    #[sea_orm(unique)]
+    pub competition: Uuid,
     pub name: String,
     pub owner: Uuid,
     pub co_owner: Option<Uuid>,
     pub locked: bool,
     #[sea_orm(unique)]
     pub join_code: String,
+    /// Joining with the code only creates a join request, that the owner or the co-owner approves
+    pub require_approval: bool,
+    /// Excluded teams are left out of the scoreboard, the stats and the exports
+    pub exclusion: Option<Exclusion>,
+    pub exclusion_reason: Option<String>,
+}
+
+/// Why a team is left out of the rankings
+#[derive(EnumIter, DeriveActiveEnum, Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[sea_orm(rs_type = "String", db_type = "String(StringLen::N(16))")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum Exclusion {
+    #[sea_orm(string_value = "disqualified")]
+    Disqualified,
+    /// The team competes, but it is not ranked
+    #[sea_orm(string_value = "hors_concours")]
+    HorsConcours,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter)]
@@ -61,12 +80,19 @@ impl Related<users::Entity> for Entity {
 
 impl Entity {
     #[inline]
-    pub fn find_by_join_code(code: &str) -> Select<Entity> {
-        Self::find().filter(Column::JoinCode.eq(code))
+    pub fn find_in_competition(competition_id: &Uuid) -> Select<Entity> {
+        Self::find().filter(Column::Competition.eq(*competition_id))
+    }
+
+    #[inline]
+    pub fn find_by_join_code(competition_id: &Uuid, code: &str) -> Select<Entity> {
+        Self::find_in_competition(competition_id).filter(Column::JoinCode.eq(code))
     }
 
     #[inline]
-    pub fn find_from_member(user_id: &Uuid) -> Select<Entity> {
-        users::Entity::find_related().filter(users::Column::Id.eq(*user_id))
+    pub fn find_from_member(competition_id: &Uuid, user_id: &Uuid) -> Select<Entity> {
+        users::Entity::find_related()
+            .filter(users::Column::Id.eq(*user_id))
+            .filter(Column::Competition.eq(*competition_id))
     }
 }
@@ -2,6 +2,7 @@ use sea_orm::entity::prelude::*;
 
 pub mod constrains {
     pub const PK_TIMES: &str = "PK_times";
+    pub const FK_TIMES_COMPETITION: &str = "FK_times_competition";
 }
 
 pub mod constants {
@@ -12,6 +13,8 @@ pub mod constants {
 #[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
 #[sea_orm(table_name = "times")]
 pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub competition: Uuid,
     #[sea_orm(primary_key, auto_increment = false)]
     pub name: String,
     pub time: DateTimeUtc,
@@ -23,11 +26,11 @@ pub enum Relation {}
 impl ActiveModelBehavior for ActiveModel {}
 
 impl Entity {
-    pub fn find_start_time() -> Select<Entity> {
-        Entity::find_by_id(constants::START_TIME)
+    pub fn find_start_time(competition_id: Uuid) -> Select<Entity> {
+        Entity::find_by_id((competition_id, constants::START_TIME.to_owned()))
     }
 
-    pub fn find_end_time() -> Select<Entity> {
-        Entity::find_by_id(constants::END_TIME)
+    pub fn find_end_time(competition_id: Uuid) -> Select<Entity> {
+        Entity::find_by_id((competition_id, constants::END_TIME.to_owned()))
     }
 }
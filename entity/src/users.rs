@@ -17,7 +17,16 @@ pub struct Model {
 }
 
 #[derive(
-    EnumIter, DeriveActiveEnum, PartialEq, Eq, Clone, Debug, Serialize_repr, Deserialize_repr,
+    EnumIter,
+    DeriveActiveEnum,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Clone,
+    Debug,
+    Serialize_repr,
+    Deserialize_repr,
 )]
 #[repr(i16)]
 #[sea_orm(rs_type = "i16", db_type = "SmallInteger")]
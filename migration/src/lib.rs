@@ -5,6 +5,24 @@ mod m20221208_194301_create_problems_table;
 mod m20230107_191407_create_problems_order_table;
 mod m20230304_232047_create_times_table;
 mod m20230312_051936_create_solutions_history;
+mod m20261018_090000_add_points_to_problems;
+mod m20261018_100000_create_competitions_table;
+mod m20261018_110000_create_team_times_table;
+mod m20261018_120000_create_pauses_table;
+mod m20261018_130000_add_answer_kinds;
+mod m20261018_140000_create_accepted_solutions_table;
+mod m20261018_150000_add_attempt_limits_to_problems;
+mod m20261018_160000_add_release_minutes_to_problems;
+mod m20261018_170000_add_tie_breakers_to_competitions;
+mod m20261018_180000_add_exclusion_to_teams;
+mod m20261018_190000_add_divisions_to_competitions;
+mod m20261018_200000_add_admin_to_solutions_history;
+mod m20261018_210000_create_team_invitations_table;
+mod m20261018_220000_create_team_join_requests_table;
+mod m20261018_230000_create_team_join_codes_table;
+mod m20261018_233000_add_team_size_to_competitions;
+mod m20261018_234000_add_join_code_to_team_join_requests;
+mod sql;
 
 pub use sea_orm_migration::MigratorTrait;
 use sea_orm_migration::prelude::*;
@@ -22,6 +40,23 @@ impl MigratorTrait for Migrator {
             Box::new(m20230107_191407_create_problems_order_table::Migration),
             Box::new(m20230304_232047_create_times_table::Migration),
             Box::new(m20230312_051936_create_solutions_history::Migration),
+            Box::new(m20261018_090000_add_points_to_problems::Migration),
+            Box::new(m20261018_100000_create_competitions_table::Migration),
+            Box::new(m20261018_110000_create_team_times_table::Migration),
+            Box::new(m20261018_120000_create_pauses_table::Migration),
+            Box::new(m20261018_130000_add_answer_kinds::Migration),
+            Box::new(m20261018_140000_create_accepted_solutions_table::Migration),
+            Box::new(m20261018_150000_add_attempt_limits_to_problems::Migration),
+            Box::new(m20261018_160000_add_release_minutes_to_problems::Migration),
+            Box::new(m20261018_170000_add_tie_breakers_to_competitions::Migration),
+            Box::new(m20261018_180000_add_exclusion_to_teams::Migration),
+            Box::new(m20261018_190000_add_divisions_to_competitions::Migration),
+            Box::new(m20261018_200000_add_admin_to_solutions_history::Migration),
+            Box::new(m20261018_210000_create_team_invitations_table::Migration),
+            Box::new(m20261018_220000_create_team_join_requests_table::Migration),
+            Box::new(m20261018_230000_create_team_join_codes_table::Migration),
+            Box::new(m20261018_233000_add_team_size_to_competitions::Migration),
+            Box::new(m20261018_234000_add_join_code_to_team_join_requests::Migration),
         ]
     }
 }
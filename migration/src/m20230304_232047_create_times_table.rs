@@ -36,11 +36,13 @@ impl MigrationTrait for Migration {
         let start_time = times::ActiveModel {
             name: Set("start_time".to_owned()),
             time: Set(DateTimeUtc::from(UNIX_EPOCH)),
+            ..Default::default()
         };
 
         let end_time = times::ActiveModel {
             name: Set("end_time".to_owned()),
             time: Set(DateTimeUtc::from(UNIX_EPOCH)),
+            ..Default::default()
         };
 
         times::Entity::insert_many([start_time, end_time])
@@ -0,0 +1,42 @@
+use entity::problems::{self, constants::*};
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(problems::Entity)
+                    .add_column(
+                        ColumnDef::new(problems::Column::Points)
+                            .integer()
+                            .not_null()
+                            .default(DEFAULT_POINTS),
+                    )
+                    .add_column(
+                        ColumnDef::new(problems::Column::WrongPoints)
+                            .integer()
+                            .not_null()
+                            .default(DEFAULT_WRONG_POINTS),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(problems::Entity)
+                    .drop_column(problems::Column::Points)
+                    .drop_column(problems::Column::WrongPoints)
+                    .to_owned(),
+            )
+            .await
+    }
+}
@@ -0,0 +1,164 @@
+use crate::sql::execute;
+use const_format::formatcp;
+use entity::{
+    competitions::{self, constraints::*},
+    problems::constraints::FK_PROBLEMS_COMPETITION,
+    problems_order::constraints::{FK_PROBLEMS_ORDER_COMPETITION, UC_PROBLEMS_ORDER_NEXT},
+    team_members::constraints::{FK_TEAM_MEMBERS_COMPETITION, UC_TEAM_MEMBERS_USER_ID},
+    teams::constrains::{FK_TEAMS_COMPETITION, UC_TEAMS_NAME},
+    times::constrains::{FK_TIMES_COMPETITION, PK_TIMES},
+};
+use sea_orm_migration::prelude::*;
+
+/// Name of the competition that existing rows are moved into
+const DEFAULT_COMPETITION: &str = "default";
+
+/// Tables that get a `competition` column, with the name of its foreign key
+const SCOPED_TABLES: [(&str, &str); 5] = [
+    ("times", FK_TIMES_COMPETITION),
+    ("problems", FK_PROBLEMS_COMPETITION),
+    ("problems_order", FK_PROBLEMS_ORDER_COMPETITION),
+    ("teams", FK_TEAMS_COMPETITION),
+    ("team_members", FK_TEAM_MEMBERS_COMPETITION),
+];
+
+const UP_CONSTRAINTS: [&str; 8] = [
+    formatcp!(r#"ALTER TABLE "times" DROP CONSTRAINT "{PK_TIMES}""#),
+    formatcp!(
+        r#"ALTER TABLE "times" ADD CONSTRAINT "{PK_TIMES}" PRIMARY KEY ("competition", "name")"#
+    ),
+    formatcp!(r#"ALTER TABLE "problems_order" DROP CONSTRAINT "{UC_PROBLEMS_ORDER_NEXT}""#),
+    formatcp!(
+        r#"ALTER TABLE "problems_order" ADD CONSTRAINT "{UC_PROBLEMS_ORDER_NEXT}"
+            UNIQUE NULLS NOT DISTINCT ("competition", "next")
+            DEFERRABLE INITIALLY IMMEDIATE"#
+    ),
+    formatcp!(r#"ALTER TABLE "teams" DROP CONSTRAINT "{UC_TEAMS_NAME}""#),
+    formatcp!(
+        r#"ALTER TABLE "teams" ADD CONSTRAINT "{UC_TEAMS_NAME}" UNIQUE ("competition", "name")"#
+    ),
+    formatcp!(r#"ALTER TABLE "team_members" DROP CONSTRAINT "{UC_TEAM_MEMBERS_USER_ID}""#),
+    formatcp!(
+        r#"ALTER TABLE "team_members" ADD CONSTRAINT "{UC_TEAM_MEMBERS_USER_ID}" UNIQUE ("user_id", "competition")"#
+    ),
+];
+
+const DOWN_CONSTRAINTS: [&str; 8] = [
+    formatcp!(r#"ALTER TABLE "times" DROP CONSTRAINT "{PK_TIMES}""#),
+    formatcp!(r#"ALTER TABLE "times" ADD CONSTRAINT "{PK_TIMES}" PRIMARY KEY ("name")"#),
+    formatcp!(r#"ALTER TABLE "problems_order" DROP CONSTRAINT "{UC_PROBLEMS_ORDER_NEXT}""#),
+    formatcp!(
+        r#"ALTER TABLE "problems_order" ADD CONSTRAINT "{UC_PROBLEMS_ORDER_NEXT}"
+            UNIQUE NULLS NOT DISTINCT ("next")
+            DEFERRABLE INITIALLY IMMEDIATE"#
+    ),
+    formatcp!(r#"ALTER TABLE "teams" DROP CONSTRAINT "{UC_TEAMS_NAME}""#),
+    formatcp!(r#"ALTER TABLE "teams" ADD CONSTRAINT "{UC_TEAMS_NAME}" UNIQUE ("name")"#),
+    formatcp!(r#"ALTER TABLE "team_members" DROP CONSTRAINT "{UC_TEAM_MEMBERS_USER_ID}""#),
+    formatcp!(
+        r#"ALTER TABLE "team_members" ADD CONSTRAINT "{UC_TEAM_MEMBERS_USER_ID}" UNIQUE ("user_id")"#
+    ),
+];
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(competitions::Entity)
+                    .if_not_exists()
+                    .col(ColumnDef::new(competitions::Column::Id).uuid().not_null())
+                    .col(
+                        ColumnDef::new(competitions::Column::Name)
+                            .string()
+                            .not_null(),
+                    )
+                    .primary_key(
+                        Index::create()
+                            .name(PK_COMPETITIONS)
+                            .col(competitions::Column::Id),
+                    )
+                    .index(
+                        Index::create()
+                            .name(UC_COMPETITIONS_NAME)
+                            .col(competitions::Column::Name)
+                            .unique(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        execute(
+            manager,
+            formatcp!(
+                r#"INSERT INTO "competitions" ("id", "name")
+                    VALUES (gen_random_uuid(), '{DEFAULT_COMPETITION}')"#
+            ),
+        )
+        .await?;
+
+        for (table, foreign_key) in SCOPED_TABLES {
+            execute(
+                manager,
+                format!(r#"ALTER TABLE "{table}" ADD COLUMN "competition" uuid NULL"#),
+            )
+            .await?;
+            execute(
+                manager,
+                format!(
+                    r#"UPDATE "{table}" SET "competition" = (
+                        SELECT "id" FROM "competitions" WHERE "name" = '{DEFAULT_COMPETITION}'
+                    )"#
+                ),
+            )
+            .await?;
+            execute(
+                manager,
+                format!(
+                    r#"ALTER TABLE "{table}"
+                        ALTER COLUMN "competition" SET NOT NULL,
+                        ADD CONSTRAINT "{foreign_key}"
+                            FOREIGN KEY ("competition")
+                            REFERENCES "competitions" ("id")
+                            ON DELETE CASCADE"#
+                ),
+            )
+            .await?;
+        }
+
+        for sql in UP_CONSTRAINTS {
+            execute(manager, sql).await?;
+        }
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Only the default competition can be represented without the column
+        execute(
+            manager,
+            formatcp!(r#"DELETE FROM "competitions" WHERE "name" <> '{DEFAULT_COMPETITION}'"#),
+        )
+        .await?;
+
+        for sql in DOWN_CONSTRAINTS {
+            execute(manager, sql).await?;
+        }
+
+        for (table, _) in SCOPED_TABLES {
+            execute(
+                manager,
+                format!(r#"ALTER TABLE "{table}" DROP COLUMN "competition""#),
+            )
+            .await?;
+        }
+
+        manager
+            .drop_table(Table::drop().table(competitions::Entity).to_owned())
+            .await
+    }
+}
@@ -0,0 +1,57 @@
+use entity::{
+    team_times::{self, constraints::*},
+    teams,
+};
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(team_times::Entity)
+                    .if_not_exists()
+                    .col(ColumnDef::new(team_times::Column::Team).uuid().not_null())
+                    .col(
+                        ColumnDef::new(team_times::Column::StartTime)
+                            .timestamp_with_time_zone()
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(team_times::Column::EndTime)
+                            .timestamp_with_time_zone()
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(team_times::Column::ExtraMinutes)
+                            .integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .primary_key(
+                        Index::create()
+                            .name(PK_TEAM_TIMES)
+                            .col(team_times::Column::Team),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name(FK_TEAM_TIMES_TEAM)
+                            .from(team_times::Entity, team_times::Column::Team)
+                            .to(teams::Entity, teams::Column::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(team_times::Entity).to_owned())
+            .await
+    }
+}
@@ -0,0 +1,52 @@
+use entity::{
+    competitions,
+    pauses::{self, constraints::*},
+};
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(pauses::Entity)
+                    .if_not_exists()
+                    .col(ColumnDef::new(pauses::Column::Id).uuid().not_null())
+                    .col(
+                        ColumnDef::new(pauses::Column::Competition)
+                            .uuid()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(pauses::Column::StartTime)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(pauses::Column::EndTime)
+                            .timestamp_with_time_zone()
+                            .null(),
+                    )
+                    .primary_key(Index::create().name(PK_PAUSES).col(pauses::Column::Id))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name(FK_PAUSES_COMPETITION)
+                            .from(pauses::Entity, pauses::Column::Competition)
+                            .to(competitions::Entity, competitions::Column::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(pauses::Entity).to_owned())
+            .await
+    }
+}
@@ -0,0 +1,36 @@
+use crate::sql::execute_all;
+use sea_orm_migration::prelude::*;
+
+const UP: [&str; 3] = [
+    r#"ALTER TABLE "problems"
+        ALTER COLUMN "solution" TYPE varchar USING "solution"::varchar,
+        ADD COLUMN "kind" varchar(16) NOT NULL DEFAULT 'integer',
+        ADD COLUMN "tolerance" double precision NULL"#,
+    r#"ALTER TABLE "problems" ALTER COLUMN "kind" DROP DEFAULT"#,
+    r#"ALTER TABLE "solutions_history"
+        ALTER COLUMN "solution" TYPE varchar USING "solution"::varchar"#,
+];
+
+// Only works while every answer is an integer
+const DOWN: [&str; 2] = [
+    r#"ALTER TABLE "problems"
+        ALTER COLUMN "solution" TYPE bigint USING "solution"::bigint,
+        DROP COLUMN "kind",
+        DROP COLUMN "tolerance""#,
+    r#"ALTER TABLE "solutions_history"
+        ALTER COLUMN "solution" TYPE bigint USING "solution"::bigint"#,
+];
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        execute_all(manager, &UP).await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        execute_all(manager, &DOWN).await
+    }
+}
@@ -0,0 +1,54 @@
+use entity::{
+    accepted_solutions::{self, constraints::*},
+    problems,
+};
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(accepted_solutions::Entity)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(accepted_solutions::Column::Problem)
+                            .uuid()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(accepted_solutions::Column::Solution)
+                            .string()
+                            .not_null(),
+                    )
+                    .primary_key(
+                        Index::create()
+                            .name(PK_ACCEPTED_SOLUTIONS)
+                            .col(accepted_solutions::Column::Problem)
+                            .col(accepted_solutions::Column::Solution),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name(FK_ACCEPTED_SOLUTIONS_PROBLEM)
+                            .from(
+                                accepted_solutions::Entity,
+                                accepted_solutions::Column::Problem,
+                            )
+                            .to(problems::Entity, problems::Column::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(accepted_solutions::Entity).to_owned())
+            .await
+    }
+}
@@ -0,0 +1,41 @@
+use entity::problems::{self, constants::*};
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(problems::Entity)
+                    .add_column(
+                        ColumnDef::new(problems::Column::MaxAttempts)
+                            .integer()
+                            .null(),
+                    )
+                    .add_column(
+                        ColumnDef::new(problems::Column::AttemptPenalty)
+                            .integer()
+                            .not_null()
+                            .default(DEFAULT_ATTEMPT_PENALTY),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(problems::Entity)
+                    .drop_column(problems::Column::MaxAttempts)
+                    .drop_column(problems::Column::AttemptPenalty)
+                    .to_owned(),
+            )
+            .await
+    }
+}
@@ -0,0 +1,35 @@
+use entity::problems;
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(problems::Entity)
+                    .add_column(
+                        ColumnDef::new(problems::Column::ReleaseMinutes)
+                            .integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(problems::Entity)
+                    .drop_column(problems::Column::ReleaseMinutes)
+                    .to_owned(),
+            )
+            .await
+    }
+}
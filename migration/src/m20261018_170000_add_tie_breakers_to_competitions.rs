@@ -0,0 +1,35 @@
+use entity::competitions;
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(competitions::Entity)
+                    .add_column(
+                        ColumnDef::new(competitions::Column::TieBreakers)
+                            .json_binary()
+                            .not_null()
+                            .default(r#"["LAST_CORRECT_TIME", "WRONG_ANSWERS"]"#),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(competitions::Entity)
+                    .drop_column(competitions::Column::TieBreakers)
+                    .to_owned(),
+            )
+            .await
+    }
+}
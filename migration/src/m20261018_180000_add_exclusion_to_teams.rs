@@ -0,0 +1,36 @@
+use entity::teams;
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(teams::Entity)
+                    .add_column(
+                        ColumnDef::new(teams::Column::Exclusion)
+                            .string_len(16)
+                            .null(),
+                    )
+                    .add_column(ColumnDef::new(teams::Column::ExclusionReason).text().null())
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(teams::Entity)
+                    .drop_column(teams::Column::Exclusion)
+                    .drop_column(teams::Column::ExclusionReason)
+                    .to_owned(),
+            )
+            .await
+    }
+}
@@ -0,0 +1,42 @@
+use entity::competitions;
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(competitions::Entity)
+                    .add_column(
+                        ColumnDef::new(competitions::Column::Divisions)
+                            .json_binary()
+                            .not_null()
+                            .default("[]"),
+                    )
+                    .add_column(
+                        ColumnDef::new(competitions::Column::DivisionRule)
+                            .string_len(16)
+                            .not_null()
+                            .default("highest_class"),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(competitions::Entity)
+                    .drop_column(competitions::Column::Divisions)
+                    .drop_column(competitions::Column::DivisionRule)
+                    .to_owned(),
+            )
+            .await
+    }
+}
@@ -0,0 +1,29 @@
+use crate::sql::execute_all;
+use sea_orm_migration::prelude::*;
+
+// Answers set by an admin have no member, the admin might not be a user of the competition
+const UP: [&str; 1] = [r#"ALTER TABLE "solutions_history"
+        ALTER COLUMN "user" DROP NOT NULL,
+        ADD COLUMN "admin" uuid NULL"#];
+
+// The answers set by admins are lost
+const DOWN: [&str; 2] = [
+    r#"DELETE FROM "solutions_history" WHERE "user" IS NULL"#,
+    r#"ALTER TABLE "solutions_history"
+        ALTER COLUMN "user" SET NOT NULL,
+        DROP COLUMN "admin""#,
+];
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        execute_all(manager, &UP).await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        execute_all(manager, &DOWN).await
+    }
+}
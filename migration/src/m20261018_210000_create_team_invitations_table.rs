@@ -0,0 +1,79 @@
+use entity::{
+    team_invitations::{self, constraints::*},
+    teams, users,
+};
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(team_invitations::Entity)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(team_invitations::Column::Id)
+                            .uuid()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(team_invitations::Column::Team)
+                            .uuid()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(team_invitations::Column::User)
+                            .uuid()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(team_invitations::Column::InvitedBy)
+                            .uuid()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(team_invitations::Column::ExpiresAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .primary_key(
+                        Index::create()
+                            .name(PK_TEAM_INVITATIONS)
+                            .col(team_invitations::Column::Id),
+                    )
+                    .index(
+                        Index::create()
+                            .name(UC_TEAM_INVITATIONS_TEAM_USER)
+                            .col(team_invitations::Column::Team)
+                            .col(team_invitations::Column::User)
+                            .unique(),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name(FK_TEAM_INVITATIONS_TEAM)
+                            .from(team_invitations::Entity, team_invitations::Column::Team)
+                            .to(teams::Entity, teams::Column::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name(FK_TEAM_INVITATIONS_USER)
+                            .from(team_invitations::Entity, team_invitations::Column::User)
+                            .to(users::Entity, users::Column::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(team_invitations::Entity).to_owned())
+            .await
+    }
+}
@@ -0,0 +1,87 @@
+use entity::{
+    team_join_requests::{self, constraints::*},
+    teams, users,
+};
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(teams::Entity)
+                    .add_column(
+                        ColumnDef::new(teams::Column::RequireApproval)
+                            .boolean()
+                            .not_null()
+                            .default(false),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(team_join_requests::Entity)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(team_join_requests::Column::Team)
+                            .uuid()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(team_join_requests::Column::User)
+                            .uuid()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(team_join_requests::Column::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .extra("DEFAULT CURRENT_TIMESTAMP"),
+                    )
+                    .primary_key(
+                        Index::create()
+                            .name(PK_TEAM_JOIN_REQUESTS)
+                            .col(team_join_requests::Column::Team)
+                            .col(team_join_requests::Column::User),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name(FK_TEAM_JOIN_REQUESTS_TEAM)
+                            .from(team_join_requests::Entity, team_join_requests::Column::Team)
+                            .to(teams::Entity, teams::Column::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name(FK_TEAM_JOIN_REQUESTS_USER)
+                            .from(team_join_requests::Entity, team_join_requests::Column::User)
+                            .to(users::Entity, users::Column::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(team_join_requests::Entity).to_owned())
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(teams::Entity)
+                    .drop_column(teams::Column::RequireApproval)
+                    .to_owned(),
+            )
+            .await
+    }
+}
@@ -0,0 +1,72 @@
+use entity::{
+    team_join_codes::{self, constraints::*},
+    teams,
+};
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(team_join_codes::Entity)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(team_join_codes::Column::Code)
+                            .string_len(6)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(team_join_codes::Column::Team)
+                            .uuid()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(team_join_codes::Column::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .extra("DEFAULT CURRENT_TIMESTAMP"),
+                    )
+                    .col(
+                        ColumnDef::new(team_join_codes::Column::ExpiresAt)
+                            .timestamp_with_time_zone()
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(team_join_codes::Column::MaxUses)
+                            .integer()
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(team_join_codes::Column::Uses)
+                            .integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .primary_key(
+                        Index::create()
+                            .name(PK_TEAM_JOIN_CODES)
+                            .col(team_join_codes::Column::Code),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name(FK_TEAM_JOIN_CODES_TEAM)
+                            .from(team_join_codes::Entity, team_join_codes::Column::Team)
+                            .to(teams::Entity, teams::Column::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(team_join_codes::Entity).to_owned())
+            .await
+    }
+}
@@ -0,0 +1,42 @@
+use entity::competitions::{self, constants::*};
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(competitions::Entity)
+                    .add_column(
+                        ColumnDef::new(competitions::Column::MinTeamSize)
+                            .integer()
+                            .not_null()
+                            .default(DEFAULT_MIN_TEAM_SIZE),
+                    )
+                    .add_column(
+                        ColumnDef::new(competitions::Column::MaxTeamSize)
+                            .integer()
+                            .not_null()
+                            .default(DEFAULT_MAX_TEAM_SIZE),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(competitions::Entity)
+                    .drop_column(competitions::Column::MinTeamSize)
+                    .drop_column(competitions::Column::MaxTeamSize)
+                    .to_owned(),
+            )
+            .await
+    }
+}
@@ -0,0 +1,48 @@
+use entity::{
+    team_join_codes,
+    team_join_requests::{self, constraints::*},
+};
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(team_join_requests::Entity)
+                    .add_column(ColumnDef::new(team_join_requests::Column::JoinCode).string())
+                    .to_owned(),
+            )
+            .await?;
+
+        // The request stays if the code is deleted, only its use is not counted
+        manager
+            .create_foreign_key(
+                ForeignKey::create()
+                    .name(FK_TEAM_JOIN_REQUESTS_JOIN_CODE)
+                    .from(
+                        team_join_requests::Entity,
+                        team_join_requests::Column::JoinCode,
+                    )
+                    .to(team_join_codes::Entity, team_join_codes::Column::Code)
+                    .on_delete(ForeignKeyAction::SetNull)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(team_join_requests::Entity)
+                    .drop_column(team_join_requests::Column::JoinCode)
+                    .to_owned(),
+            )
+            .await
+    }
+}
@@ -0,0 +1,22 @@
+use sea_orm_migration::{
+    prelude::*,
+    sea_orm::{ConnectionTrait, Statement},
+};
+
+/// Runs raw SQL, for the changes that are easier to write by hand than with the schema builder
+pub async fn execute(manager: &SchemaManager<'_>, sql: impl Into<String>) -> Result<(), DbErr> {
+    manager
+        .get_connection()
+        .execute(Statement::from_string(manager.get_database_backend(), sql))
+        .await
+        .map(|_| ())
+}
+
+/// Runs the statements in order, stops at the first error
+pub async fn execute_all(manager: &SchemaManager<'_>, statements: &[&str]) -> Result<(), DbErr> {
+    for sql in statements {
+        execute(manager, *sql).await?;
+    }
+
+    Ok(())
+}
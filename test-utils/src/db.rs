@@ -1,5 +1,6 @@
+use crate::COMPETITION_ID;
 use migration::MigratorTrait;
-use sea_orm::{ConnectOptions, DbConn};
+use sea_orm::{ConnectOptions, ConnectionTrait, DbConn};
 use tokio::sync::{mpsc, oneshot};
 use tracing::log::LevelFilter;
 
@@ -37,6 +38,17 @@ impl Database {
                         .await
                         .expect("failed to apply migrations");
 
+                    conn.execute_unprepared(&format!(
+                        r#"
+                        insert into competitions (id, name) values ('{COMPETITION_ID}', 'test');
+                        insert into times (competition, name, time) values
+                          ('{COMPETITION_ID}', 'start_time', to_timestamp(0)),
+                          ('{COMPETITION_ID}', 'end_time', to_timestamp(0));
+                        "#
+                    ))
+                    .await
+                    .expect("failed to create the test competition");
+
                     tx.send(()).unwrap();
                 }
             })
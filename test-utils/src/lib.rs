@@ -22,6 +22,7 @@ use std::{
         Arc,
         atomic::{AtomicU64, Ordering},
     },
+    time::{SystemTime, UNIX_EPOCH},
 };
 use team::Team;
 use tokio::{
@@ -32,6 +33,9 @@ use tokio_tungstenite::tungstenite::Message;
 use user::*;
 use uuid::Uuid;
 
+/// The competition that is created for every test after the database is cleaned
+pub const COMPETITION_ID: Uuid = Uuid::from_u128(0x0190_0000_0000_7000_8000_0000_0000_0001);
+
 #[derive(Debug)]
 pub struct AppInner {
     addr: SocketAddr,
@@ -112,14 +116,18 @@ impl App {
     }
 
     pub async fn register_user(&self) -> User {
+        self.register_user_in("Test School", 9).await
+    }
+
+    pub async fn register_user_in(&self, school: &str, class: i16) -> User {
         let user = iam::register_user().await;
 
         let res = self
             .post("/v1/register")
             .user(&user)
             .json(&json!({
-                "school": "Test School",
-                "class": 9,
+                "school": school,
+                "class": class,
             }))
             .send()
             .await;
@@ -136,7 +144,7 @@ impl App {
         let number = TEAM_COUNT.fetch_add(1, Ordering::Relaxed);
 
         let res = self
-            .post("/v1/team/create")
+            .post(&format!("/v1/competition/{COMPETITION_ID}/team/create"))
             .user(owner)
             .json(&json!({
                 "name": format!("Team-{}", number)
@@ -149,6 +157,54 @@ impl App {
         Team::new(owner.clone(), self.clone(), number)
     }
 
+    /// Creates a problem and returns its id, `problem` can override every field of the request.
+    /// Without overrides the problem has a body and 23 as the solution.
+    pub async fn create_problem(&self, admin: &impl UserLike, problem: Value) -> Value {
+        let mut body = json!({
+            "body": "some body",
+            "solution": 23,
+        });
+
+        if let (Some(body), Some(problem)) = (body.as_object_mut(), problem.as_object()) {
+            body.extend(problem.clone());
+        }
+
+        let res = self
+            .post(&format!("/v1/competition/{COMPETITION_ID}/problem"))
+            .user(admin)
+            .json(&body)
+            .send()
+            .await;
+
+        assert_eq!(res.status(), StatusCode::CREATED);
+
+        res.json::<Value>().await["id"].clone()
+    }
+
+    pub async fn set_time(&self, admin: &impl UserLike, start_time: i64, end_time: i64) {
+        let res = self
+            .put(&format!("/v1/competition/{COMPETITION_ID}/time"))
+            .user(admin)
+            .json(&json!({
+                "start_time": start_time,
+                "end_time": end_time,
+            }))
+            .send()
+            .await;
+
+        assert_eq!(res.status(), StatusCode::NO_CONTENT);
+    }
+
+    /// Sets the competition time so it is running for an hour in both directions
+    pub async fn start_competition(&self, admin: &impl UserLike) {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs() as i64;
+
+        self.set_time(admin, now - 3600, now + 3600).await;
+    }
+
     pub fn get(&self, url: &str) -> RequestBuilder {
         RequestBuilder::new(Client::new().get(format!("http://{}{}", self.inner.addr, url)))
     }
@@ -1,7 +1,7 @@
 pub use crate::{
-    App, assert_close_frame, assert_close_frame_error, assert_error, assert_team_info,
-    enable_logging, get_cached_app, get_socket_message, iam, request::*, response::*,
-    user::UserLike, uuid,
+    App, COMPETITION_ID, assert_close_frame, assert_close_frame_error, assert_error,
+    assert_event_type, assert_team_info, enable_logging, get_cached_app, get_socket_message, iam,
+    request::*, response::*, user::UserLike, uuid,
 };
 pub use assert_json_diff::{assert_json_eq, assert_json_include};
 pub use futures::{SinkExt, StreamExt};
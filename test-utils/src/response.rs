@@ -1,4 +1,4 @@
-use http::StatusCode;
+use http::{HeaderMap, StatusCode};
 use serde::de::DeserializeOwned;
 
 #[derive(Debug)]
@@ -18,7 +18,23 @@ impl TestResponse {
             .expect("failed to deserialize to json")
     }
 
+    pub async fn text(self) -> String {
+        self.response.text().await.expect("failed to read the body")
+    }
+
+    pub async fn bytes(self) -> Vec<u8> {
+        self.response
+            .bytes()
+            .await
+            .expect("failed to read the body")
+            .into()
+    }
+
     pub fn status(&self) -> StatusCode {
         self.response.status()
     }
+
+    pub fn headers(&self) -> &HeaderMap {
+        self.response.headers()
+    }
 }
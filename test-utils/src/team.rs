@@ -12,7 +12,11 @@ impl Team {
     }
 
     pub async fn get_code(&self) -> String {
-        let mut socket = self.app.socket("/v1/ws").start().await;
+        let mut socket = self
+            .app
+            .socket(&format!("/v1/competition/{COMPETITION_ID}/ws"))
+            .start()
+            .await;
         let message = assert_team_info!(socket, self.owner);
 
         let code = message["data"]["code"]
@@ -28,7 +32,7 @@ impl Team {
     pub async fn lock(&self) {
         let res = self
             .app
-            .patch("/v1/team")
+            .patch(&format!("/v1/competition/{COMPETITION_ID}/team"))
             .user(&self.owner)
             .json(&json!({
                 "locked": true,
@@ -39,6 +43,31 @@ impl Team {
         assert_eq!(res.status(), StatusCode::NO_CONTENT);
     }
 
+    /// Looks up the id of the team in the admin team list
+    pub async fn get_id(&self, admin: &impl UserLike) -> String {
+        let res = self
+            .app
+            .get(&format!("/v1/competition/{COMPETITION_ID}/team"))
+            .user(admin)
+            .send()
+            .await;
+
+        assert_eq!(res.status(), StatusCode::OK);
+
+        let teams: Value = res.json().await;
+        let name = self.get_name();
+
+        teams
+            .as_array()
+            .expect("not an array")
+            .iter()
+            .find(|team| team["name"] == name)
+            .expect("team not found")["id"]
+            .as_str()
+            .expect("no id")
+            .to_owned()
+    }
+
     pub fn get_name(&self) -> String {
         format!("Team-{}", self.number)
     }
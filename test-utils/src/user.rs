@@ -21,7 +21,7 @@ impl User {
     pub async fn join(&self, code: &str) {
         let res = self
             .app
-            .post("/v1/team/join")
+            .post(&format!("/v1/competition/{COMPETITION_ID}/team/join"))
             .user(self)
             .json(&json!({
                 "code": code,